@@ -0,0 +1,250 @@
+//! Бенчмарки с отслеживанием регрессий.
+//!
+//! Запуск: `cargo bench`. Каждый бенчмарк прогревается, затем снимается серия
+//! замеров, за результат берётся медиана времени одной итерации.
+//!
+//! Переменные окружения:
+//!
+//! * `BENCH_BASELINE` — путь к JSON с базовыми результатами
+//!   (по умолчанию `bench/baseline.json` в `CARGO_TARGET_DIR`, без него — в `target`)
+//! * `BENCH_SAVE_BASELINE=1` — сохранить текущие результаты как базовые
+//! * `BENCH_THRESHOLD` — допустимое замедление в процентах (по умолчанию 20)
+//!
+//! Текущие результаты всегда пишутся в `latest.json` рядом с базовым по умолчанию. Если базовый файл
+//! существует и какой-то бенчмарк медленнее порога, процесс завершается с кодом 1.
+//! Аргументы командной строки, не начинающиеся с `-`, фильтруют бенчмарки по подстроке.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use fibonacci_stable::app::run;
+use fibonacci_stable::parallel::fibonacci_big_parallel;
+use fibonacci_stable::search::fibonacci_search;
+use fibonacci_stable::{Logger, Options, fibonacci, fibonacci_big, parse_input};
+
+const WARM_UP: Duration = Duration::from_millis(200);
+const SAMPLE_TIME: Duration = Duration::from_millis(20);
+const SAMPLES: usize = 25;
+const DEFAULT_THRESHOLD_PERCENT: f64 = 20.0;
+
+/// Размеры срезов для сравнения поиска Фибоначчи с `binary_search`.
+const SEARCH_LENS: [usize; 2] = [1_000, 1_000_000];
+
+/// Сценарий интерактивной сессии: ошибки ввода, переполнение, корректное число.
+const SCRIPT: &str = "abc\n-5\n3.14\n1 2\n187\n42\n";
+
+fn main() {
+    let filters: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with('-'))
+        .collect();
+    let mut bencher = Bencher {
+        filters,
+        results: BTreeMap::new(),
+    };
+
+    bencher.bench("fibonacci/10", || fibonacci(black_box(10)));
+    bencher.bench("fibonacci/186", || fibonacci(black_box(186)));
+    bencher.bench("fibonacci/overflow_187", || fibonacci(black_box(187)));
+    bencher.bench("fibonacci_big/10000", || fibonacci_big(black_box(10_000)));
+    bencher.bench("fibonacci_big/200000", || fibonacci_big(black_box(200_000)));
+    bencher.bench("fibonacci_big_parallel/200000", || {
+        fibonacci_big_parallel(black_box(200_000), 4)
+    });
+    for len in SEARCH_LENS {
+        // Чётные числа: половина ключей найдётся, половина — нет.
+        let sorted: Vec<u64> = (0..len as u64).map(|i| 2 * i).collect();
+        bencher.bench(&format!("search/fibonacci/{}", len), {
+            let (sorted, mut keys) = (sorted.clone(), Keys::new(2 * len as u64));
+            move || fibonacci_search(&sorted, &keys.next())
+        });
+        bencher.bench(&format!("search/binary/{}", len), {
+            let mut keys = Keys::new(2 * len as u64);
+            move || sorted.binary_search(&keys.next())
+        });
+    }
+    bencher.bench("parse_input/valid", || {
+        parse_input(black_box("  1234567890  "))
+    });
+    bencher.bench("parse_input/invalid", || parse_input(black_box("12abc")));
+    bencher.bench("parse_input/too_large", || {
+        parse_input(black_box("999999999999999999999999999999999999999999"))
+    });
+    // Новый Logger на каждую итерацию: общий рос бы между замерами.
+    bencher.bench("logger/log", || {
+        let mut logger = Logger::new();
+        logger.log(black_box("Ввод от пользователя: '42'"));
+        logger
+    });
+    bencher.bench("interactive/script", || {
+        let mut output = Vec::with_capacity(4096);
+        let mut logger = Logger::new();
+        run(
+            &Options::default(),
+            black_box(SCRIPT.as_bytes()),
+            &mut output,
+            &mut logger,
+        )
+        .expect("запись в Vec не падает");
+        output
+    });
+
+    let dir = bench_dir();
+    fs::create_dir_all(&dir)
+        .unwrap_or_else(|e| panic!("не удалось создать {}: {}", dir.display(), e));
+    write_json(&dir.join("latest.json"), &bencher.results);
+
+    let baseline_path = std::env::var_os("BENCH_BASELINE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| dir.join("baseline.json"));
+
+    if std::env::var_os("BENCH_SAVE_BASELINE").is_some_and(|v| v == "1") {
+        write_json(&baseline_path, &bencher.results);
+        println!("Базовые результаты сохранены: {}", baseline_path.display());
+        return;
+    }
+
+    let Ok(baseline) = fs::read_to_string(&baseline_path) else {
+        println!(
+            "Базовый файл {} не найден; сохраните его через BENCH_SAVE_BASELINE=1",
+            baseline_path.display()
+        );
+        return;
+    };
+
+    let threshold = std::env::var("BENCH_THRESHOLD")
+        .ok()
+        .and_then(|v| v.parse::<f64>().ok())
+        .unwrap_or(DEFAULT_THRESHOLD_PERCENT);
+    let regressions = compare(&parse_json(&baseline), &bencher.results, threshold);
+    if !regressions.is_empty() {
+        eprintln!("Регрессии производительности (порог {}%):", threshold);
+        for line in regressions {
+            eprintln!("  {}", line);
+        }
+        std::process::exit(1);
+    }
+    println!("Регрессий нет (порог {}%)", threshold);
+}
+
+/// Каталог результатов: `bench` внутри `CARGO_TARGET_DIR` или `target`.
+/// Относительный путь отсчитывается от корня крейта.
+fn bench_dir() -> PathBuf {
+    let target = std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("target"));
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(target)
+        .join("bench")
+}
+
+/// Псевдослучайные ключи поиска из `0..bound`: одна и та же последовательность
+/// для обоих алгоритмов.
+struct Keys {
+    state: u64,
+    bound: u64,
+}
+
+impl Keys {
+    fn new(bound: u64) -> Self {
+        Keys { state: 1, bound }
+    }
+
+    /// Следующий ключ (xorshift64).
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state % self.bound
+    }
+}
+
+struct Bencher {
+    filters: Vec<String>,
+    /// Имя бенчмарка → медиана наносекунд на итерацию.
+    results: BTreeMap<String, f64>,
+}
+
+impl Bencher {
+    fn bench<T>(&mut self, name: &str, mut f: impl FnMut() -> T) {
+        if !self.filters.is_empty() && !self.filters.iter().any(|p| name.contains(p.as_str())) {
+            return;
+        }
+
+        // Прогрев заодно подбирает число итераций на один замер.
+        let started = Instant::now();
+        let mut warm_up_iterations = 0u64;
+        while started.elapsed() < WARM_UP {
+            black_box(f());
+            warm_up_iterations += 1;
+        }
+        let per_iteration = started.elapsed().as_secs_f64() / warm_up_iterations as f64;
+        let iterations = ((SAMPLE_TIME.as_secs_f64() / per_iteration) as u64).max(1);
+
+        let mut samples: Vec<f64> = (0..SAMPLES)
+            .map(|_| {
+                let started = Instant::now();
+                for _ in 0..iterations {
+                    black_box(f());
+                }
+                started.elapsed().as_nanos() as f64 / iterations as f64
+            })
+            .collect();
+        samples.sort_by(f64::total_cmp);
+        let median = samples[SAMPLES / 2];
+
+        println!(
+            "{:<32} {:>14.1} нс/итер  [{:.1} .. {:.1}]",
+            name,
+            median,
+            samples[0],
+            samples[SAMPLES - 1]
+        );
+        self.results.insert(name.to_string(), median);
+    }
+}
+
+/// Сравнивает результаты с базовыми; возвращает описания регрессий.
+fn compare(
+    baseline: &BTreeMap<String, f64>,
+    current: &BTreeMap<String, f64>,
+    threshold_percent: f64,
+) -> Vec<String> {
+    current
+        .iter()
+        .filter_map(|(name, &now)| {
+            let &before = baseline.get(name)?;
+            let change = (now / before - 1.0) * 100.0;
+            (change > threshold_percent).then(|| {
+                format!(
+                    "{}: {:.1} → {:.1} нс/итер (+{:.1}%)",
+                    name, before, now, change
+                )
+            })
+        })
+        .collect()
+}
+
+/// Пишет плоский JSON-объект `{"имя": наносекунды, ...}`.
+fn write_json(path: &Path, results: &BTreeMap<String, f64>) {
+    let body: Vec<String> = results
+        .iter()
+        .map(|(name, ns)| format!("  \"{}\": {:.3}", name, ns))
+        .collect();
+    fs::write(path, format!("{{\n{}\n}}\n", body.join(",\n")))
+        .unwrap_or_else(|e| panic!("не удалось записать {}: {}", path.display(), e));
+}
+
+/// Читает JSON, записанный [`write_json`]. Имена бенчмарков не содержат кавычек.
+fn parse_json(text: &str) -> BTreeMap<String, f64> {
+    text.lines()
+        .filter_map(|line| {
+            let (name, value) = line.trim().trim_end_matches(',').split_once(':')?;
+            let name = name.trim().strip_prefix('"')?.strip_suffix('"')?;
+            Some((name.to_string(), value.trim().parse().ok()?))
+        })
+        .collect()
+}
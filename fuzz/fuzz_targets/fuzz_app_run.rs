@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Основной цикл программы на произвольном stdin в разных режимах
+fuzz_target!(|data: &[u8]| fibonacci_stable_fuzz::app_run(data));
@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// fibonacci и fibonacci_big против независимых реализаций сложением
+fuzz_target!(|data: &[u8]| fibonacci_stable_fuzz::fibonacci_differential(data));
@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Display и code всех значений ошибок
+fuzz_target!(|data: &[u8]| fibonacci_stable_fuzz::error_display(data));
@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Logger сохраняет произвольный UTF-8 без изменений
+fuzz_target!(|data: &[u8]| fibonacci_stable_fuzz::logger_utf8(data));
@@ -1,11 +1,6 @@
 #![no_main]
 
 use libfuzzer_sys::fuzz_target;
-use fibonacci_stable::parse_input;
 
-fuzz_target!(|data: &[u8]| {
-    if let Ok(s) = std::str::from_utf8(data) {
-        // parse_input не должна паниковать ни на каком вводе
-        let _ = parse_input(s);
-    }
-});
+// parse_input не должна паниковать ни на каком вводе
+fuzz_target!(|data: &[u8]| fibonacci_stable_fuzz::parse_input_str(data));
@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Интерактивный цикл чтения на произвольном потоке байт
+fuzz_target!(|data: &[u8]| fibonacci_stable_fuzz::read_input_stream(data));
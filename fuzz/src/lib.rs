@@ -0,0 +1,276 @@
+//! Проверки фазз-таргетов.
+//!
+//! Каждая функция принимает произвольные байты и паникует, только если нашла
+//! ошибку. Таргеты в `fuzz_targets/` лишь передают в них данные libFuzzer, а
+//! `tests/fuzz_corpus.rs` основного крейта прогоняет через них сохранённый
+//! корпус на stable Rust, поэтому этот файл зависит только от `fibonacci_stable`.
+
+use std::fmt::Display;
+use std::num::NonZeroUsize;
+use std::time::Duration;
+
+use fibonacci_stable::cli::Language;
+use fibonacci_stable::io::read_input_from;
+use fibonacci_stable::{
+    BudgetError, ComputationError, InputError, Logger, Mode, Options, OverflowPolicy, app,
+    fibonacci, fibonacci_big, fibonacci_with_policy, parse_input,
+};
+
+/// Наибольший индекс, для которого `fibonacci_big` сверяется с эталоном.
+const MAX_BIG_INDEX: u128 = 2048;
+/// Наибольший индекс, для которого политика `Wrapping` сверяется с эталоном.
+const MAX_WRAPPING_INDEX: u128 = 4096;
+
+/// Читает из фазз-данных числа фиксированной ширины; недостающие байты — нули.
+struct Bytes<'a>(&'a [u8]);
+
+impl Bytes<'_> {
+    fn u8(&mut self) -> u8 {
+        let (&first, rest) = self.0.split_first().unwrap_or((&0, &[]));
+        self.0 = rest;
+        first
+    }
+
+    fn u128(&mut self) -> u128 {
+        let mut bytes = [0u8; 16];
+        let len = self.0.len().min(16);
+        bytes[..len].copy_from_slice(&self.0[..len]);
+        self.0 = &self.0[len..];
+        u128::from_le_bytes(bytes)
+    }
+}
+
+/// `parse_input` не паникует ни на какой строке.
+pub fn parse_input_str(data: &[u8]) {
+    if let Ok(s) = std::str::from_utf8(data) {
+        let _ = parse_input(s);
+    }
+}
+
+/// `fibonacci` не паникует ни при каком `u128`.
+pub fn fibonacci_u128(data: &[u8]) {
+    if data.len() >= 16 {
+        let n = u128::from_le_bytes(data[..16].try_into().unwrap());
+        let _ = fibonacci(n);
+    }
+}
+
+/// Интерактивный цикл `read_input_from` на произвольном потоке байт:
+/// каждое прочитанное число — результат `parse_input` одной из строк, а цикл
+/// заканчивается, когда поток исчерпан.
+pub fn read_input_stream(data: &[u8]) {
+    let mut input = data;
+    let mut output = Vec::new();
+    let mut logger = Logger::new();
+
+    while let Ok(Some(n)) = read_input_from(&mut input, &mut output, &mut logger) {
+        let accepted = data
+            .split(|&b| b == b'\n')
+            .filter_map(|line| std::str::from_utf8(line).ok())
+            .any(|line| parse_input(line) == Ok(n));
+        assert!(
+            accepted,
+            "число {} не соответствует ни одной строке ввода",
+            n
+        );
+    }
+}
+
+/// Основной цикл программы (`app::run`) на произвольном stdin. Первый байт
+/// выбирает режим; режим `--big` не выбирается, чтобы итерация не считала
+/// числа из миллиардов цифр.
+pub fn app_run(data: &[u8]) {
+    let mut bytes = Bytes(data);
+    let selector = bytes.u8();
+    let mode = match selector % 4 {
+        0 => Mode::U128,
+        1 => Mode::Approx,
+        _ => Mode::LastDigits(u32::from(bytes.u8() % 38) + 1),
+    };
+    let overflow = match (selector >> 2) % 3 {
+        0 => OverflowPolicy::Checked,
+        1 => OverflowPolicy::Wrapping,
+        _ => OverflowPolicy::Saturating,
+    };
+    let lang = match (selector >> 4) % 3 {
+        0 => Language::Both,
+        1 => Language::Ru,
+        _ => Language::En,
+    };
+    let options = Options {
+        mode,
+        overflow,
+        lang,
+        batch: selector & 0x80 != 0,
+        threads: NonZeroUsize::new(1),
+        ..Options::default()
+    };
+
+    let mut output = Vec::new();
+    let mut logger = Logger::new();
+    let _ = app::run(&options, bytes.0, &mut output, &mut logger);
+}
+
+/// `Logger::log` и `Logger::error` сохраняют произвольный UTF-8 без изменений.
+pub fn logger_utf8(data: &[u8]) {
+    let Ok(message) = std::str::from_utf8(data) else {
+        return;
+    };
+    let mut logger = Logger::new();
+
+    logger.log(message);
+    logger.error(message);
+
+    assert_eq!(logger.entries().len(), 2);
+    for entry in logger.entries() {
+        assert!(
+            entry.ends_with(message),
+            "запись {:?} потеряла сообщение",
+            entry
+        );
+    }
+}
+
+/// Сообщение об ошибке непустое (двуязычное, если `bilingual`), код — в
+/// `SCREAMING_SNAKE_CASE`.
+fn check_error(error: &impl Display, code: &str, bilingual: bool) {
+    let message = error.to_string();
+    assert!(!message.is_empty());
+    if bilingual {
+        assert!(message.starts_with("RU: "), "{:?}", message);
+        assert!(message.contains("\nEN: "), "{:?}", message);
+    }
+    assert!(!code.is_empty());
+    assert!(
+        code.bytes().all(|b| b.is_ascii_uppercase() || b == b'_'),
+        "{}",
+        code
+    );
+}
+
+/// `Display` и `code` каждого значения `InputError`, `ComputationError` и
+/// `BudgetError`, включая крайние значения полей.
+pub fn error_display(data: &[u8]) {
+    let mut bytes = Bytes(data);
+    let selector = bytes.u8();
+    let (a, b, c) = (bytes.u128(), bytes.u128(), bytes.u128());
+
+    let input = match selector % 8 {
+        0 => InputError::EmptyInput,
+        1 => InputError::NegativeNumber,
+        2 => InputError::FloatNumber,
+        3 => InputError::InvalidCharacters,
+        4 => InputError::MultipleValues,
+        5 => InputError::NumberTooLarge,
+        6 => InputError::ComputationOverflow(a),
+        _ => InputError::InvalidRange {
+            from: a,
+            to: b,
+            max_len: c,
+        },
+    };
+    check_error(&input, input.code(), true);
+
+    let computation = if selector & 0x08 == 0 {
+        ComputationError::Overflow(a)
+    } else {
+        ComputationError::ZeroModulus
+    };
+    // ComputationError — внутренняя ошибка, её сообщение только на русском.
+    check_error(&computation, computation.code(), false);
+    if let ComputationError::Overflow(n) = computation {
+        assert!(computation.to_string().ends_with(&n.to_string()));
+    }
+
+    let budget = match (selector >> 4) % 3 {
+        0 => BudgetError::Digits {
+            n: a,
+            estimated: b,
+            limit: c,
+        },
+        1 => BudgetError::Memory {
+            n: a,
+            estimated: b,
+            limit: c,
+        },
+        _ => BudgetError::Time {
+            n: a,
+            estimated: Duration::new(b as u64, (b >> 64) as u32 % 1_000_000_000),
+            limit: Duration::new(c as u64, (c >> 64) as u32 % 1_000_000_000),
+        },
+    };
+    check_error(&budget, budget.code(), true);
+}
+
+/// Эталон: F(n) сложением по рекуррентности в `u128`, `None` при переполнении.
+fn reference_u128(n: u128) -> Option<u128> {
+    // F(n + 1) может не поместиться, даже когда F(n) помещается.
+    let (mut a, mut b) = (Some(0u128), Some(1u128));
+    for _ in 0..n {
+        (a, b) = (b, a.zip(b).and_then(|(a, b)| a.checked_add(b)));
+    }
+    a
+}
+
+/// Эталон: F(n) mod 2^128 сложением по рекуррентности.
+fn reference_wrapping(n: u128) -> u128 {
+    let (mut a, mut b) = (0u128, 1u128);
+    for _ in 0..n {
+        (a, b) = (b, a.wrapping_add(b));
+    }
+    a
+}
+
+/// Эталон: десятичная запись F(n) сложением в цифрах по основанию 10^9.
+fn reference_decimal(n: u128) -> String {
+    const BASE: u32 = 1_000_000_000;
+    let (mut a, mut b) = (vec![0u32], vec![1u32]);
+    for _ in 0..n {
+        let mut sum = Vec::with_capacity(b.len() + 1);
+        let mut carry = 0;
+        for (i, &limb) in b.iter().enumerate() {
+            let digit = limb + a.get(i).copied().unwrap_or(0) + carry;
+            carry = u32::from(digit >= BASE);
+            sum.push(digit - carry * BASE);
+        }
+        if carry > 0 {
+            sum.push(carry);
+        }
+        (a, b) = (b, sum);
+    }
+    let mut digits = a.iter().rev();
+    let mut text = digits.next().unwrap().to_string();
+    for limb in digits {
+        text.push_str(&format!("{:09}", limb));
+    }
+    text
+}
+
+/// Дифференциальная проверка: `fibonacci`, `fibonacci_with_policy` и
+/// `fibonacci_big` против независимых реализаций сложением.
+pub fn fibonacci_differential(data: &[u8]) {
+    let n = Bytes(data).u128();
+
+    let expected = if n <= 200 {
+        reference_u128(n).ok_or(ComputationError::Overflow(n))
+    } else {
+        Err(ComputationError::Overflow(n))
+    };
+    assert_eq!(fibonacci(n), expected, "fibonacci({})", n);
+
+    let small = n % MAX_WRAPPING_INDEX;
+    assert_eq!(
+        fibonacci_with_policy(small, OverflowPolicy::Wrapping),
+        Ok(reference_wrapping(small)),
+        "fibonacci_with_policy({}, Wrapping)",
+        small
+    );
+
+    let big = n % MAX_BIG_INDEX;
+    assert_eq!(
+        fibonacci_big(big).to_string(),
+        reference_decimal(big),
+        "fibonacci_big({})",
+        big
+    );
+}
@@ -0,0 +1,87 @@
+/* fibonacci_stable: C ABI. Сгенерировано cbindgen, не редактировать вручную; обновление: UPDATE_FFI_HEADER=1 cargo test --test ffi */
+
+#ifndef FIBONACCI_STABLE_H
+#define FIBONACCI_STABLE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Код результата функции C ABI.
+typedef enum {
+  // Успех, результат записан в `out`.
+  FIB_STATUS_OK = 0,
+  // [`InputError::EmptyInput`].
+  FIB_STATUS_EMPTY_INPUT = 1,
+  // [`InputError::NegativeNumber`].
+  FIB_STATUS_NEGATIVE_NUMBER = 2,
+  // [`InputError::FloatNumber`].
+  FIB_STATUS_FLOAT_NUMBER = 3,
+  // [`InputError::InvalidCharacters`], в том числе строка не в UTF-8.
+  FIB_STATUS_INVALID_CHARACTERS = 4,
+  // [`InputError::MultipleValues`].
+  FIB_STATUS_MULTIPLE_VALUES = 5,
+  // [`InputError::NumberTooLarge`].
+  FIB_STATUS_NUMBER_TOO_LARGE = 6,
+  // [`InputError::ComputationOverflow`] и [`ComputationError::Overflow`].
+  FIB_STATUS_OVERFLOW = 7,
+  // [`InputError::InvalidRange`].
+  FIB_STATUS_INVALID_RANGE = 8,
+  // [`ComputationError::ZeroModulus`].
+  FIB_STATUS_ZERO_MODULUS = 9,
+  // Передан нулевой указатель.
+  FIB_STATUS_NULL_POINTER = 10,
+} FibStatus;
+
+// Беззнаковое 128-битное число: `lo` — младшие 64 бита, `hi` — старшие.
+typedef struct {
+  // Младшие 64 бита.
+  uint64_t lo;
+  // Старшие 64 бита.
+  uint64_t hi;
+} FibU128;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Вычисляет fib(n) в `u128`.
+//
+// Возвращает `FIB_STATUS_OVERFLOW` для n > 186.
+//
+// # Safety
+//
+// `out` — нулевой указатель (тогда возвращается `FIB_STATUS_NULL_POINTER`)
+// или указатель, доступный для записи `FibU128`.
+FibStatus fib_u128(FibU128 n,
+                   FibU128 *out);
+
+// Вычисляет fib(n) mod m для любых `n` и `m`.
+//
+// Возвращает `FIB_STATUS_ZERO_MODULUS` при `m == 0`.
+//
+// # Safety
+//
+// `out` — нулевой указатель (тогда возвращается `FIB_STATUS_NULL_POINTER`)
+// или указатель, доступный для записи `FibU128`.
+FibStatus fib_mod(FibU128 n,
+                  FibU128 m,
+                  FibU128 *out);
+
+// Проверяет строку так же, как [`parse_input()`], и записывает число в `out`.
+//
+// # Safety
+//
+// `input` — нулевой указатель или указатель на строку, завершённую нулём;
+// `out` — нулевой указатель или указатель, доступный для записи `FibU128`.
+// При нулевом указателе возвращается `FIB_STATUS_NULL_POINTER`.
+FibStatus fib_parse(const char *input,
+                    FibU128 *out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* FIBONACCI_STABLE_H */
@@ -0,0 +1,456 @@
+use std::fmt::Display;
+use std::fs::OpenOptions;
+use std::io::{self, BufRead, Write};
+
+use crate::approx::fibonacci_approx;
+
+use crate::cli::{BudgetAction, Language, LogTarget, Mode, Options, OutputFormat};
+use crate::errors::{BudgetError, InputError};
+use crate::fibonacci::{OverflowPolicy, fibonacci_with_policy};
+use crate::io::{parse_input, read_input_from, write_result};
+use crate::logger::Logger;
+use crate::modular::fibonacci_tail;
+use crate::parallel::{fibonacci_big_parallel, map_indices};
+use crate::server::json_string;
+
+/// Запускает программу с заданными параметрами: интерактивный цикл или пакетный режим.
+///
+/// Ввод и вывод передаются явно, поэтому сессию можно провести по сценарию в памяти.
+/// Из вывода убираются строки сообщений на языке, не выбранном в `options.lang`.
+/// В конце записи лога выводятся туда, куда указывает `options.log`.
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::{Logger, Options};
+/// use fibonacci_stable::app::run;
+///
+/// let mut output = Vec::new();
+/// let mut logger = Logger::new();
+///
+/// run(&Options::default(), "abc\n10\n".as_bytes(), &mut output, &mut logger).unwrap();
+///
+/// let output = String::from_utf8(output).unwrap();
+/// assert!(output.contains("fibonacci(10) = 55"));
+/// assert!(output.contains("--- Логи ---"));
+/// ```
+pub fn run(
+    options: &Options,
+    mut input: impl BufRead,
+    output: impl Write,
+    logger: &mut Logger,
+) -> io::Result<()> {
+    let mut output = Localized::new(output, options.lang);
+    logger.log("Программа запущена");
+    if options.mode == Mode::Big {
+        logger.log("Режим вычисления: big");
+    } else if options.mode == Mode::Approx {
+        logger.log("Режим вычисления: approx");
+    } else if let Mode::LastDigits(k) = options.mode {
+        logger.log(&format!("Режим вычисления: последние {} цифр", k));
+    } else if options.overflow != OverflowPolicy::Checked {
+        logger.log(&format!("Политика переполнения: {}", options.overflow));
+    }
+    if options.threads.is_some() {
+        logger.log(&format!("Потоков: {}", options.thread_count()));
+    }
+
+    if options.batch {
+        run_batch(options, &mut input, &mut output, logger)?;
+    } else {
+        run_interactive(options, &mut input, &mut output, logger)?;
+    }
+
+    match &options.log {
+        LogTarget::Output => {
+            writeln!(output, "\n--- Логи ---")?;
+            write_entries(&mut output, logger)?;
+        }
+        LogTarget::Stderr => write_entries(&mut io::stderr().lock(), logger)?,
+        LogTarget::None => {}
+        LogTarget::File(path) => {
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            write_entries(&mut file, logger)?;
+        }
+    }
+    output.flush()
+}
+
+/// Выводит записи лога по одной на строку.
+fn write_entries(output: &mut impl Write, logger: &Logger) -> io::Result<()> {
+    for entry in logger.entries() {
+        writeln!(output, "{}", entry)?;
+    }
+    Ok(())
+}
+
+/// Вывод, из которого убираются строки двуязычных сообщений на невыбранном языке.
+///
+/// Строки копятся до `\n` и отбрасываются целиком, если [`Language::keeps`]
+/// возвращает `false`; незавершённая строка выводится при `flush`.
+struct Localized<W: Write> {
+    inner: W,
+    lang: Language,
+    line: Vec<u8>,
+}
+
+impl<W: Write> Localized<W> {
+    fn new(inner: W, lang: Language) -> Self {
+        Localized {
+            inner,
+            lang,
+            line: Vec::new(),
+        }
+    }
+
+    /// Выводит накопленную строку, если её язык выбран.
+    fn emit(&mut self) -> io::Result<()> {
+        if self.lang.keeps(&String::from_utf8_lossy(&self.line)) {
+            self.inner.write_all(&self.line)?;
+        }
+        self.line.clear();
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for Localized<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for &byte in buf {
+            self.line.push(byte);
+            if byte == b'\n' {
+                self.emit()?;
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.emit()?;
+        self.inner.flush()
+    }
+}
+
+/// Интерактивный цикл: запрашивает числа, пока одно из них не будет вычислено.
+fn run_interactive(
+    options: &Options,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    logger: &mut Logger,
+) -> io::Result<()> {
+    loop {
+        let n = match read_input_from(input, output, logger)? {
+            Some(n) => n,
+            None => {
+                logger.log("Пользователь вышел из программы");
+                writeln!(output, "До свидания!")?;
+                return Ok(());
+            }
+        };
+
+        if let Some(e) = check_budget(n, options, logger)
+            && !report_budget(n, &e, options, output)?
+        {
+            writeln!(output, "Попробуйте снова или введите 'q' для выхода:")?;
+            continue;
+        }
+
+        logger.log(&format!("Старт вычисления fibonacci({})", n));
+
+        match compute(n, options, options.thread_count()) {
+            Ok(result) => {
+                logger.log(&format!("Конец вычисления: fibonacci({}) = {}", n, result));
+                write_value(output, options, n, &result)?;
+                logger.log("Результат выведен в терминал");
+                return Ok(());
+            }
+            Err(error) => {
+                logger.error(&format!("{}", error));
+                write_error(output, options, &n.to_string(), error.code(), &error)?;
+                writeln!(output, "Попробуйте снова или введите 'q' для выхода:")?;
+            }
+        }
+    }
+}
+
+/// Строка пакетного ввода после валидации и проверки бюджета.
+enum BatchItem {
+    /// Корректный индекс и превышение бюджета, если оно есть.
+    Index(u128, Option<BudgetError>),
+    /// Некорректный ввод.
+    Invalid(String, InputError),
+}
+
+/// Пакетный режим: вычисляет все индексы из ввода параллельно и выводит
+/// результаты в порядке ввода.
+fn run_batch(
+    options: &Options,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    logger: &mut Logger,
+) -> io::Result<()> {
+    let mut items = Vec::new();
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        items.push(match parse_input(&line) {
+            Ok(n) => BatchItem::Index(n, check_budget(n, options, logger)),
+            Err(e) => {
+                logger.error(&format!(
+                    "Некорректный ввод: '{}'. Ошибка: {}",
+                    line.trim(),
+                    e
+                ));
+                BatchItem::Invalid(line.trim().to_string(), e)
+            }
+        });
+    }
+
+    let proceed = |budget: &Option<BudgetError>| {
+        budget.is_none() || options.budget.action == BudgetAction::Warn
+    };
+    let indices: Vec<u128> = items
+        .iter()
+        .filter_map(|item| match item {
+            BatchItem::Index(n, budget) if proceed(budget) => Some(*n),
+            _ => None,
+        })
+        .collect();
+
+    logger.log(&format!(
+        "Старт пакетного вычисления: {} индексов, {} потоков",
+        indices.len(),
+        options.thread_count()
+    ));
+    let mut results =
+        map_indices(&indices, options.thread_count(), |n| compute(n, options, 1)).into_iter();
+    logger.log("Конец пакетного вычисления");
+
+    for item in &items {
+        match item {
+            BatchItem::Index(n, budget) => {
+                if let Some(e) = budget
+                    && !report_budget(*n, e, options, output)?
+                {
+                    continue;
+                }
+                match results.next().expect("результат для каждого индекса")
+                {
+                    Ok(result) => write_value(output, options, *n, &result)?,
+                    Err(error) => {
+                        logger.error(&format!("{}", error));
+                        write_error(output, options, &n.to_string(), error.code(), &error)?;
+                    }
+                }
+            }
+            BatchItem::Invalid(line, error) => match options.format {
+                OutputFormat::Text => writeln!(output, "'{}':\n{}", line, error)?,
+                OutputFormat::Json => write_error(output, options, line, error.code(), error)?,
+            },
+        }
+    }
+    logger.log("Результаты выведены в терминал");
+    Ok(())
+}
+
+/// Логирует прогноз вычисления fib(n) и возвращает превышение бюджета, если оно есть.
+fn check_budget(n: u128, options: &Options, logger: &mut Logger) -> Option<BudgetError> {
+    let forecast = options.budget.estimate(n, options.mode.backend());
+    // Время оценивается только при заданном --max-time.
+    let time = match options.budget.max_time {
+        Some(_) => format!(", ~{:?}", forecast.time),
+        None => String::new(),
+    };
+    logger.log(&format!(
+        "Оценка fibonacci({}): {} бит, {} цифр, ~{} байт{}",
+        n, forecast.bits, forecast.decimal_digits, forecast.memory_bytes, time
+    ));
+
+    let error = options.budget.check(n, &forecast).err();
+    if let Some(e) = &error {
+        logger.error(&format!("{}", e));
+    }
+    error
+}
+
+/// Выводит превышение бюджета; возвращает `true`, если вычислять всё равно нужно.
+fn report_budget(
+    n: u128,
+    error: &BudgetError,
+    options: &Options,
+    output: &mut impl Write,
+) -> io::Result<bool> {
+    write_error(output, options, &n.to_string(), error.code(), error)?;
+    Ok(match options.budget.action {
+        BudgetAction::Refuse => false,
+        BudgetAction::Warn if options.format == OutputFormat::Json => true,
+        BudgetAction::Warn => {
+            writeln!(
+                output,
+                "RU: Вычисление продолжается (--on-budget warn)\nEN: Continuing anyway (--on-budget warn)"
+            )?;
+            true
+        }
+    })
+}
+
+/// Выводит fib(n) в формате `options.format`.
+fn write_value(output: &mut impl Write, options: &Options, n: u128, value: &str) -> io::Result<()> {
+    match options.format {
+        OutputFormat::Text if options.mode == Mode::Approx => {
+            writeln!(output, "fibonacci({}) ≈ {}", n, value)
+        }
+        OutputFormat::Text => write_result(output, n, value),
+        OutputFormat::Json => writeln!(output, r#"{{"n":"{}","value":"{}"}}"#, n, value),
+    }
+}
+
+/// Выводит ошибку для ввода `input` в формате `options.format`.
+///
+/// В JSON сообщение содержит только строки выбранного языка.
+fn write_error(
+    output: &mut impl Write,
+    options: &Options,
+    input: &str,
+    code: &str,
+    error: &impl Display,
+) -> io::Result<()> {
+    match options.format {
+        OutputFormat::Text => writeln!(output, "{}", error),
+        OutputFormat::Json => {
+            let message = error.to_string();
+            let message: Vec<&str> = message
+                .lines()
+                .filter(|line| options.lang.keeps(line))
+                .collect();
+            writeln!(
+                output,
+                r#"{{"input":{},"error":{{"code":"{}","message":{}}}}}"#,
+                json_string(input),
+                code,
+                json_string(&message.join("\n"))
+            )
+        }
+    }
+}
+
+/// Сколько первых цифр выводится в режиме `--approx`.
+pub(crate) const APPROX_DIGITS: u32 = 16;
+
+/// Последние `k` цифр fib(n) для режима `--last-digits`.
+pub(crate) fn last_digits(n: u128, k: u32) -> String {
+    fibonacci_tail(n, k)
+        .expect("--last-digits проверяется при разборе")
+        .to_string()
+}
+
+/// Вычисляет fib(n) в выбранном режиме; `threads` — потоки для умножений `BigUint`.
+fn compute(n: u128, options: &Options, threads: usize) -> Result<String, InputError> {
+    match options.mode {
+        Mode::U128 => fibonacci_with_policy(n, options.overflow)
+            .map(|v| v.to_string())
+            .map_err(|_| InputError::ComputationOverflow(n)),
+        Mode::Big => Ok(fibonacci_big_parallel(n, threads).to_string()),
+        Mode::Approx => Ok(fibonacci_approx(n, APPROX_DIGITS)
+            .expect("APPROX_DIGITS не больше MAX_LEADING_DIGITS")
+            .to_string()),
+        Mode::LastDigits(k) => Ok(last_digits(n, k)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_script(options: &Options, script: &str) -> String {
+        let mut output = Vec::new();
+        let mut logger = Logger::new();
+        run(options, script.as_bytes(), &mut output, &mut logger).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn given_script_ending_without_quit_when_run_then_terminates() {
+        // Given
+        let script = "abc\n187\n";
+
+        // When
+        let output = run_script(&Options::default(), script);
+
+        // Then
+        assert!(output.contains("До свидания!"), "output: {}", output);
+        assert!(output.contains("Конец ввода"), "output: {}", output);
+    }
+
+    #[test]
+    fn given_batch_script_when_run_then_results_in_input_order() {
+        // Given
+        let options = Options {
+            batch: true,
+            ..Options::default()
+        };
+
+        // When
+        let output = run_script(&options, "12\n\n3\n");
+
+        // Then
+        let first = output.find("fibonacci(12) = 144").unwrap();
+        let second = output.find("fibonacci(3) = 2").unwrap();
+        assert!(first < second, "output: {}", output);
+    }
+
+    #[test]
+    fn given_english_lang_when_run_invalid_input_then_only_english_lines() {
+        // Given
+        let options = Options {
+            lang: Language::En,
+            ..Options::default()
+        };
+
+        // When
+        let output = run_script(&options, "abc\n10\n");
+
+        // Then
+        assert!(output.contains("EN: Error!"), "output: {}", output);
+        assert!(
+            !output.lines().any(|line| line.starts_with("RU: ")),
+            "output: {}",
+            output
+        );
+        assert!(output.contains("fibonacci(10) = 55"), "output: {}", output);
+    }
+
+    #[test]
+    fn given_json_batch_without_logs_when_run_then_outputs_json_lines() {
+        // Given
+        let options = Options {
+            batch: true,
+            format: OutputFormat::Json,
+            lang: Language::En,
+            log: LogTarget::None,
+            ..Options::default()
+        };
+
+        // When
+        let output = run_script(&options, "10\n187\nabc\n");
+
+        // Then
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3, "output: {}", output);
+        assert_eq!(lines[0], r#"{"n":"10","value":"55"}"#);
+        assert!(
+            lines[1].starts_with(
+                r#"{"input":"187","error":{"code":"COMPUTATION_OVERFLOW","message":"EN: Error!"#
+            ),
+            "line: {}",
+            lines[1]
+        );
+        assert!(
+            lines[2].starts_with(r#"{"input":"abc","error":{"code":"#),
+            "line: {}",
+            lines[2]
+        );
+        assert!(!output.contains("--- Логи ---"));
+    }
+}
@@ -0,0 +1,366 @@
+//! Количество цифр и первые цифры F(n) через логарифм золотого сечения.
+//!
+//! По формуле Бине `log10 F(n) = n·log10 φ − log10 √5 + δ`, где `|δ| < φ^(−2n)`
+//! пренебрежимо мало при `n > 186`. Логарифмы считаются в целочисленной
+//! арифметике с фиксированной точкой (128 бит дробной части, `log10 φ` — 256 бит),
+//! поэтому погрешность не превышает `2^-116` при любом `n`. Если результат
+//! ближе погрешности к границе округления, он пересчитывается точно через
+//! [`fibonacci_big()`] — ответ всегда совпадает с точным.
+//!
+//! ```
+//! use fibonacci_stable::approx::{fibonacci_approx, fibonacci_digits, fibonacci_leading_digits};
+//!
+//! assert_eq!(fibonacci_digits(1_000_000_000_000), 208_987_640_250);
+//! assert_eq!(fibonacci_leading_digits(1_000_000_000, 10), Some(7_952_317_874));
+//! assert_eq!(
+//!     fibonacci_approx(1_000_000_000, 4).unwrap().to_string(),
+//!     "7.952…e208987639"
+//! );
+//! ```
+
+use core::fmt;
+
+use alloc::string::ToString;
+
+use crate::fibonacci::{fibonacci, fibonacci_big};
+use crate::modular::widening_mul;
+
+/// Наибольшее число первых цифр, которое можно запросить.
+pub const MAX_LEADING_DIGITS: u32 = 30;
+
+/// Наибольший индекс, для которого F(n) помещается в `u128` и считается точно.
+const MAX_U128_INDEX: u128 = 186;
+
+/// `log10 φ · 2^256`: старшие и младшие 128 бит.
+const LOG10_PHI: (u128, u128) = (
+    0x358036c82451b7f365d3db23845599f5,
+    0x887a5e47e9bdd71cff8b851da61d586d,
+);
+/// `log10 √5 · 2^128`.
+const LOG10_SQRT5: u128 = 0x5977d95ec10c0219dc1da994fd20dba1;
+/// `log10 2 · 2^128`.
+const LOG10_2: u128 = 0x4d104d427de7fbcc47c4acd605be48bc;
+
+/// Граница погрешности логарифмов: `2^-116` в единицах дробной части.
+const EPSILON: u128 = 1 << 12;
+
+/// Приближение F(n) в научной записи: первые цифры и десятичный порядок.
+///
+/// Выводится как `1.234…e208987639`; многоточие означает, что цифры отброшены.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Approximation {
+    /// Первые цифры F(n).
+    pub leading: u128,
+    /// Порядок: F(n) ≈ `leading` · 10^(`exponent` − цифр в `leading` + 1).
+    pub exponent: u128,
+    /// `true`, если `leading` — все цифры F(n).
+    pub exact: bool,
+}
+
+impl fmt::Display for Approximation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.leading.to_string();
+        let (first, rest) = digits.split_at(1);
+        // Нули в конце точного значения не значимы: 610 — это 6.1e2.
+        let rest = if self.exact {
+            rest.trim_end_matches('0')
+        } else {
+            rest
+        };
+        f.write_str(first)?;
+        if !rest.is_empty() {
+            write!(f, ".{}", rest)?;
+        }
+        if !self.exact {
+            f.write_str("…")?;
+        }
+        write!(f, "e{}", self.exponent)
+    }
+}
+
+/// Число в фиксированной точке: `int + frac / 2^128`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Fixed {
+    int: u128,
+    frac: u128,
+}
+
+impl Fixed {
+    /// Сравнение, если `|self − other|` заведомо больше погрешности; иначе `None`.
+    fn compare(self, other: Fixed) -> Option<core::cmp::Ordering> {
+        let (high, low) = if self >= other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let (frac, borrow) = high.frac.overflowing_sub(low.frac);
+        let int = high.int - low.int - borrow as u128;
+        (int > 0 || frac > 2 * EPSILON).then(|| self.cmp(&other))
+    }
+}
+
+/// Количество десятичных цифр F(n).
+///
+/// ```
+/// use fibonacci_stable::approx::fibonacci_digits;
+///
+/// assert_eq!(fibonacci_digits(0), 1);
+/// assert_eq!(fibonacci_digits(1000), 209);
+/// ```
+pub fn fibonacci_digits(n: u128) -> u128 {
+    if let Ok(value) = fibonacci(n) {
+        return value.checked_ilog10().map_or(1, |log| log as u128 + 1);
+    }
+    let log = log10_fibonacci(n);
+    if log.frac < EPSILON || log.frac > u128::MAX - EPSILON {
+        return fibonacci_big(n).to_string().len() as u128;
+    }
+    log.int + 1
+}
+
+/// Первые `k` цифр F(n); если у F(n) меньше `k` цифр — всё число.
+///
+/// Возвращает `None`, если `k` равно 0 или больше [`MAX_LEADING_DIGITS`].
+///
+/// ```
+/// use fibonacci_stable::approx::fibonacci_leading_digits;
+///
+/// assert_eq!(fibonacci_leading_digits(1000, 5), Some(43466));
+/// assert_eq!(fibonacci_leading_digits(10, 5), Some(55));
+/// assert_eq!(fibonacci_leading_digits(10, 0), None);
+/// ```
+pub fn fibonacci_leading_digits(n: u128, k: u32) -> Option<u128> {
+    fibonacci_approx(n, k).map(|approx| approx.leading)
+}
+
+/// Приближение F(n) с `k` значащими цифрами, см. [`Approximation`].
+///
+/// Возвращает `None`, если `k` равно 0 или больше [`MAX_LEADING_DIGITS`].
+///
+/// ```
+/// use fibonacci_stable::approx::fibonacci_approx;
+///
+/// assert_eq!(fibonacci_approx(10, 16).unwrap().to_string(), "5.5e1");
+/// assert_eq!(fibonacci_approx(1000, 3).unwrap().to_string(), "4.34…e208");
+/// ```
+pub fn fibonacci_approx(n: u128, k: u32) -> Option<Approximation> {
+    if k == 0 || k > MAX_LEADING_DIGITS {
+        return None;
+    }
+    if n <= MAX_U128_INDEX {
+        let value = fibonacci(n).ok()?;
+        let digits = value.checked_ilog10().map_or(1, |log| log + 1);
+        let dropped = digits.saturating_sub(k);
+        return Some(Approximation {
+            leading: value / 10u128.pow(dropped),
+            exponent: digits as u128 - 1,
+            exact: dropped == 0,
+        });
+    }
+
+    let log = log10_fibonacci(n);
+    let target = Fixed {
+        int: k as u128 - 1,
+        frac: log.frac,
+    };
+    match largest_with_log_below(target, k) {
+        Some(leading) if log.frac >= EPSILON && log.frac <= u128::MAX - EPSILON => {
+            Some(Approximation {
+                leading,
+                exponent: log.int,
+                exact: false,
+            })
+        }
+        _ => {
+            let digits = fibonacci_big(n).to_string();
+            Some(Approximation {
+                leading: digits[..k as usize].parse().ok()?,
+                exponent: digits.len() as u128 - 1,
+                exact: false,
+            })
+        }
+    }
+}
+
+/// `log10 F(n)` для `n > 186` с погрешностью меньше `2^-126`.
+fn log10_fibonacci(n: u128) -> Fixed {
+    let (int, high_frac) = widening_mul(n, LOG10_PHI.0);
+    let (low_carry, _) = widening_mul(n, LOG10_PHI.1);
+    let (frac, carry) = high_frac.overflowing_add(low_carry);
+    let int = int + carry as u128;
+
+    let (frac, borrow) = frac.overflowing_sub(LOG10_SQRT5);
+    Fixed {
+        int: int - borrow as u128,
+        frac,
+    }
+}
+
+/// `log10 c` для `c ≥ 1` с погрешностью меньше `2^-118`.
+///
+/// `log2 c` считается побитно: мантисса `m ∈ [1, 2)` возводится в квадрат,
+/// и каждый переход через 2 даёт очередной единичный бит дробной части.
+fn log10(c: u128) -> Fixed {
+    let exponent = (u128::BITS - 1 - c.leading_zeros()) as u128;
+    // Мантисса в формате 1.127: старший бит — целая единица.
+    let mut mantissa = c << c.leading_zeros();
+    let mut frac = 0;
+    for bit in (0..u128::BITS).rev() {
+        let (high, low) = widening_mul(mantissa, mantissa);
+        if high >> 127 == 1 {
+            frac |= 1 << bit;
+            mantissa = high;
+        } else {
+            mantissa = (high << 1) | (low >> 127);
+        }
+    }
+
+    let (int, int_frac) = widening_mul(exponent, LOG10_2);
+    let (frac_part, _) = widening_mul(frac, LOG10_2);
+    let (frac, carry) = int_frac.overflowing_add(frac_part);
+    Fixed {
+        int: int + carry as u128,
+        frac,
+    }
+}
+
+/// Наибольшее `c` из `[10^(k−1), 10^k)` с `log10 c ≤ target` — первые `k` цифр.
+///
+/// Возвращает `None`, если какое-то сравнение попало в пределы погрешности.
+fn largest_with_log_below(target: Fixed, k: u32) -> Option<u128> {
+    let (mut low, mut high) = (10u128.pow(k - 1), 10u128.pow(k) - 1);
+    while low < high {
+        let middle = low + (high - low).div_ceil(2);
+        if log10(middle).compare(target)?.is_le() {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+    // Граничное сравнение для `low + 1` тоже должно быть однозначным.
+    if low + 1 < 10u128.pow(k) {
+        log10(low + 1).compare(target)?;
+    }
+    Some(low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_indices_up_to_5000_when_digits_and_leading_then_match_exact() {
+        for n in (0..5000).step_by(37) {
+            // Given
+            let exact = fibonacci_big(n).to_string();
+
+            // When
+            let digits = fibonacci_digits(n);
+
+            // Then
+            assert_eq!(digits, exact.len() as u128, "n={}", n);
+            for k in [1, 5, 17, MAX_LEADING_DIGITS] {
+                let expected = &exact[..exact.len().min(k as usize)];
+                assert_eq!(
+                    fibonacci_leading_digits(n, k),
+                    Some(expected.parse().unwrap()),
+                    "n={}, k={}",
+                    n,
+                    k
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn given_huge_indices_when_leading_digits_then_match_reference() {
+        // Given: значения вычислены по формуле Бине с точностью 250 знаков
+        let cases = [
+            (
+                1_000_000_000,
+                208_987_640,
+                795_231_787_455_468_346_782_938_519_619,
+            ),
+            (
+                1_000_000_000_000,
+                208_987_640_250,
+                425_842_268_899_588_358_863_483_369_437,
+            ),
+            (
+                1_000_000_000_000_000_000,
+                208_987_640_249_978_734,
+                262_897_881_867_922_046_740_750_648_916,
+            ),
+            (
+                1 << 127,
+                35_557_404_440_742_175_635_671_960_621_301_812_475,
+                261_260_403_801_452_114_237_152_426_817,
+            ),
+        ];
+
+        for (n, digits, leading) in cases {
+            // When / Then
+            assert_eq!(fibonacci_digits(n), digits, "n={}", n);
+            assert_eq!(
+                fibonacci_leading_digits(n, MAX_LEADING_DIGITS),
+                Some(leading),
+                "n={}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn given_u128_max_when_approx_then_no_overflow() {
+        // Given / When
+        let approx = fibonacci_approx(u128::MAX, 3).unwrap();
+
+        // Then
+        assert_eq!(approx.leading.to_string().len(), 3);
+        assert!(!approx.exact);
+    }
+
+    #[test]
+    fn given_invalid_precision_when_approx_then_none() {
+        // Given / When / Then
+        assert_eq!(fibonacci_approx(1000, 0), None);
+        assert_eq!(fibonacci_approx(1000, MAX_LEADING_DIGITS + 1), None);
+    }
+
+    #[test]
+    fn given_approximations_when_display_then_scientific_notation() {
+        // Given
+        let cases = [
+            (0, 5, "0e0"),
+            (7, 5, "1.3e1"),
+            (15, 5, "6.1e2"),
+            (30, 2, "8.3…e5"),
+            (187, 4, "5.385…e38"),
+        ];
+
+        for (n, k, expected) in cases {
+            // When
+            let text = fibonacci_approx(n, k).unwrap().to_string();
+
+            // Then
+            assert_eq!(text, expected, "n={}", n);
+        }
+    }
+
+    #[test]
+    fn given_powers_of_ten_when_log10_then_exact_within_epsilon() {
+        for power in 0..38 {
+            // Given
+            let expected = Fixed {
+                int: power,
+                frac: 0,
+            };
+
+            // When
+            let log = log10(10u128.pow(power as u32));
+
+            // Then
+            assert_eq!(log.compare(expected), None, "10^{}", power);
+        }
+    }
+}
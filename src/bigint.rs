@@ -0,0 +1,673 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, Mul};
+
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Неотрицательное целое число произвольной длины.
+///
+/// Минимальная реализация для точного вычисления чисел Фибоначчи, не помещающихся
+/// в `u128`: сложение, вычитание, умножение и десятичный вывод.
+///
+/// # Пример
+///
+/// ```
+/// use fibonacci_stable::BigUint;
+///
+/// let a = BigUint::from(u128::MAX);
+/// let b = &a + &BigUint::one();
+///
+/// assert_eq!(b.to_string(), "340282366920938463463374607431768211456");
+/// assert_eq!(b.to_u128(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
+pub struct BigUint {
+    /// Разряды по основанию 2^64, младший первым, без ведущих нулей.
+    limbs: Vec<u64>,
+}
+
+/// Наибольшая степень 10, помещающаяся в `u64`, — основание десятичного вывода.
+const DECIMAL_BASE: u128 = 10_000_000_000_000_000_000;
+const DECIMAL_BASE_DIGITS: usize = 19;
+
+impl BigUint {
+    /// Ноль.
+    pub fn zero() -> Self {
+        BigUint { limbs: Vec::new() }
+    }
+
+    /// Единица.
+    pub fn one() -> Self {
+        BigUint { limbs: vec![1] }
+    }
+
+    /// Проверяет, равно ли число нулю.
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Количество значащих бит (0 для нуля).
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() as u64 * 64 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// Преобразует в `u128`, если число помещается.
+    pub fn to_u128(&self) -> Option<u128> {
+        match self.limbs.as_slice() {
+            [] => Some(0),
+            [lo] => Some(*lo as u128),
+            [lo, hi] => Some(((*hi as u128) << 64) | *lo as u128),
+            _ => None,
+        }
+    }
+
+    /// Байты числа, младший первым, без ведущих нулей (пусто для нуля).
+    ///
+    /// ```
+    /// use fibonacci_stable::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0x0102u64).to_bytes_le(), vec![0x02, 0x01]);
+    /// assert!(BigUint::zero().to_bytes_le().is_empty());
+    /// ```
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = self.limbs.iter().flat_map(|l| l.to_le_bytes()).collect();
+        while bytes.last() == Some(&0) {
+            bytes.pop();
+        }
+        bytes
+    }
+
+    /// Вычитание; `None`, если `rhs > self`.
+    pub fn checked_sub(&self, rhs: &BigUint) -> Option<BigUint> {
+        if *self < *rhs {
+            return None;
+        }
+        let mut result = self.clone();
+        result.sub_assign(rhs);
+        Some(result)
+    }
+
+    /// Наибольший общий делитель; `gcd(0, 0) = 0`.
+    ///
+    /// Бинарный алгоритм Стейна: только сдвиги и вычитания, без деления.
+    ///
+    /// ```
+    /// use fibonacci_stable::{BigUint, fibonacci_big};
+    ///
+    /// // gcd(F(60), F(45)) = F(gcd(60, 45)) = F(15) = 610
+    /// let gcd = fibonacci_big(60).gcd(&fibonacci_big(45));
+    /// assert_eq!(gcd, BigUint::from(610u64));
+    /// ```
+    pub fn gcd(&self, other: &BigUint) -> BigUint {
+        if self.is_zero() {
+            return other.clone();
+        }
+        if other.is_zero() {
+            return self.clone();
+        }
+        let (mut a, mut b) = (self.clone(), other.clone());
+        let shift = a.trailing_zeros().min(b.trailing_zeros());
+        a.shr_assign(a.trailing_zeros());
+        b.shr_assign(b.trailing_zeros());
+        // Оба числа нечётны: разность чётна, и её двойки можно отбросить.
+        loop {
+            if a > b {
+                core::mem::swap(&mut a, &mut b);
+            }
+            b.sub_assign(&a);
+            if b.is_zero() {
+                break;
+            }
+            b.shr_assign(b.trailing_zeros());
+        }
+        a.shl_assign(shift);
+        a
+    }
+
+    /// Вычитает `rhs` на месте; требует `rhs <= self`.
+    fn sub_assign(&mut self, rhs: &BigUint) {
+        let mut borrow = false;
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let r = rhs.limbs.get(i).copied().unwrap_or(0);
+            if r == 0 && !borrow && i >= rhs.limbs.len() {
+                break;
+            }
+            let (diff, b1) = limb.overflowing_sub(r);
+            let (diff, b2) = diff.overflowing_sub(borrow as u64);
+            *limb = diff;
+            borrow = b1 || b2;
+        }
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    /// Количество младших нулевых бит (0 для нуля).
+    fn trailing_zeros(&self) -> u64 {
+        match self.limbs.iter().position(|&limb| limb != 0) {
+            Some(i) => i as u64 * 64 + self.limbs[i].trailing_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// Сдвиг вправо на `bits` бит.
+    fn shr_assign(&mut self, bits: u64) {
+        let (words, bits) = ((bits / 64) as usize, (bits % 64) as u32);
+        self.limbs.drain(..words.min(self.limbs.len()));
+        if bits > 0 {
+            for i in 0..self.limbs.len() {
+                let high = self.limbs.get(i + 1).map_or(0, |next| next << (64 - bits));
+                self.limbs[i] = (self.limbs[i] >> bits) | high;
+            }
+        }
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    /// Сдвиг влево на `bits` бит.
+    fn shl_assign(&mut self, bits: u64) {
+        if self.is_zero() {
+            return;
+        }
+        let (words, bits) = ((bits / 64) as usize, (bits % 64) as u32);
+        if bits > 0 {
+            let mut carry = 0;
+            for limb in self.limbs.iter_mut() {
+                let next = *limb >> (64 - bits);
+                *limb = (*limb << bits) | carry;
+                carry = next;
+            }
+            if carry != 0 {
+                self.limbs.push(carry);
+            }
+        }
+        self.limbs.splice(..0, core::iter::repeat_n(0, words));
+    }
+
+    fn from_limbs(mut limbs: Vec<u64>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        BigUint { limbs }
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        BigUint::from_limbs(vec![value])
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(value: u128) -> Self {
+        BigUint::from_limbs(vec![value as u64, (value >> 64) as u64])
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> BigUint {
+        let (long, short) = if self.limbs.len() >= rhs.limbs.len() {
+            (&self.limbs, &rhs.limbs)
+        } else {
+            (&rhs.limbs, &self.limbs)
+        };
+        let mut limbs = Vec::with_capacity(long.len() + 1);
+        let mut carry = false;
+        for (i, &l) in long.iter().enumerate() {
+            let (sum, c1) = l.overflowing_add(short.get(i).copied().unwrap_or(0));
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            limbs.push(sum);
+            carry = c1 || c2;
+        }
+        if carry {
+            limbs.push(1);
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        karatsuba(self, rhs, 1)
+    }
+}
+
+/// Начиная с этого размера (в 64-битных разрядах) умножение идёт по Карацубе.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Начиная с этого размера подзадачи Карацубы выносятся в отдельные потоки:
+/// на меньших числах создание потока дороже самого умножения.
+#[cfg(feature = "std")]
+const PARALLEL_THRESHOLD: usize = 512;
+
+impl BigUint {
+    /// Умножение с распараллеливанием на `threads` потоков.
+    ///
+    /// Три независимых произведения каждого шага Карацубы вычисляются в разных
+    /// потоках (`std::thread::scope`), пока хватает потоков и числа достаточно велики.
+    /// `threads` ≤ 1 — то же, что оператор `*`.
+    ///
+    /// ```
+    /// use fibonacci_stable::{BigUint, fibonacci_big};
+    ///
+    /// let a = fibonacci_big(100_000);
+    /// assert_eq!(a.mul_parallel(&a, 4), &a * &a);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn mul_parallel(&self, rhs: &BigUint, threads: usize) -> BigUint {
+        karatsuba(self, rhs, threads)
+    }
+
+    /// Разбивает число на младшие `at` разрядов и остальные.
+    fn split_at(&self, at: usize) -> (BigUint, BigUint) {
+        let at = at.min(self.limbs.len());
+        (
+            BigUint::from_limbs(self.limbs[..at].to_vec()),
+            BigUint::from_limbs(self.limbs[at..].to_vec()),
+        )
+    }
+
+    /// Разбирает десятичную запись из одних цифр; `None` для пустой строки
+    /// и любых других символов.
+    #[cfg(feature = "serde")]
+    fn from_decimal(digits: &str) -> Option<BigUint> {
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let mut value = BigUint::zero();
+        for chunk in digits.as_bytes().chunks(DECIMAL_BASE_DIGITS) {
+            let scale = BigUint::from(10u64.pow(chunk.len() as u32));
+            let chunk = core::str::from_utf8(chunk).ok()?.parse::<u64>().ok()?;
+            value = &(&value * &scale) + &BigUint::from(chunk);
+        }
+        Some(value)
+    }
+
+    /// Умножает на 2^(64·limbs).
+    fn shl_limbs(mut self, limbs: usize) -> BigUint {
+        if !self.is_zero() {
+            self.limbs.splice(0..0, core::iter::repeat_n(0, limbs));
+        }
+        self
+    }
+}
+
+#[cfg_attr(not(feature = "std"), allow(unused_variables))]
+fn karatsuba(a: &BigUint, b: &BigUint, threads: usize) -> BigUint {
+    let short = a.limbs.len().min(b.limbs.len());
+    if short < KARATSUBA_THRESHOLD {
+        return schoolbook(a, b);
+    }
+
+    let half = a.limbs.len().max(b.limbs.len()) / 2;
+    let (a0, a1) = a.split_at(half);
+    let (b0, b1) = b.split_at(half);
+    let (sum_a, sum_b) = (&a0 + &a1, &b0 + &b1);
+
+    let sequential = || {
+        (
+            karatsuba(&a0, &b0, 1),
+            karatsuba(&sum_a, &sum_b, 1),
+            karatsuba(&a1, &b1, 1),
+        )
+    };
+    #[cfg(feature = "std")]
+    let (z0, z1, z2) = if threads > 1 && short >= PARALLEL_THRESHOLD {
+        let share = (threads / 3).max(1);
+        std::thread::scope(|scope| {
+            let low = scope.spawn(|| karatsuba(&a0, &b0, share));
+            let high = scope.spawn(|| karatsuba(&a1, &b1, share));
+            let mid = karatsuba(&sum_a, &sum_b, threads.saturating_sub(2 * share).max(1));
+            (
+                low.join().expect("поток умножения не паникует"),
+                mid,
+                high.join().expect("поток умножения не паникует"),
+            )
+        })
+    } else {
+        sequential()
+    };
+    #[cfg(not(feature = "std"))]
+    let (z0, z1, z2) = sequential();
+
+    // z1 = (a0 + a1)(b0 + b1) − z0 − z2 = a0·b1 + a1·b0 ≥ 0
+    let middle = z1
+        .checked_sub(&z0)
+        .and_then(|m| m.checked_sub(&z2))
+        .expect("a0·b1 + a1·b0 неотрицательно");
+    &(&z0 + &middle.shl_limbs(half)) + &z2.shl_limbs(2 * half)
+}
+
+fn schoolbook(a: &BigUint, b: &BigUint) -> BigUint {
+    if a.is_zero() || b.is_zero() {
+        return BigUint::zero();
+    }
+    let mut limbs = vec![0u64; a.limbs.len() + b.limbs.len()];
+    for (i, &x) in a.limbs.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.limbs.iter().enumerate() {
+            let t = x as u128 * y as u128 + limbs[i + j] as u128 + carry;
+            limbs[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        limbs[i + b.limbs.len()] = carry as u64;
+    }
+    BigUint::from_limbs(limbs)
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Делим на 10^19, собирая десятичные «разряды» от младшего к старшему.
+        let mut chunks = Vec::new();
+        let mut rest = self.limbs.clone();
+        while !rest.is_empty() {
+            let mut rem = 0u128;
+            for limb in rest.iter_mut().rev() {
+                let value = (rem << 64) | *limb as u128;
+                *limb = (value / DECIMAL_BASE) as u64;
+                rem = value % DECIMAL_BASE;
+            }
+            chunks.push(rem as u64);
+            while rest.last() == Some(&0) {
+                rest.pop();
+            }
+        }
+
+        let mut digits = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{:0width$}", chunk, width = DECIMAL_BASE_DIGITS));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+/// Десятичной строкой, как [`fmt::Display`]: JSON-числа теряют точность после 2^53.
+#[cfg(feature = "serde")]
+impl serde::Serialize for BigUint {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BigUint {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DecimalVisitor;
+
+        impl serde::de::Visitor<'_> for DecimalVisitor {
+            type Value = BigUint;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("неотрицательное целое десятичной строкой")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<BigUint, E> {
+                BigUint::from_decimal(value)
+                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_str(DecimalVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_u128_values_when_roundtrip_then_unchanged() {
+        // Given
+        for value in [0, 1, u64::MAX as u128, u64::MAX as u128 + 1, u128::MAX] {
+            // When
+            let big = BigUint::from(value);
+
+            // Then
+            assert_eq!(big.to_u128(), Some(value));
+            assert_eq!(big.to_string(), value.to_string());
+            assert_eq!(big.bits(), (u128::BITS - value.leading_zeros()) as u64);
+        }
+    }
+
+    #[test]
+    fn given_u128_values_when_to_bytes_le_then_matches_u128_bytes() {
+        // Given
+        for value in [1u128, 255, 256, u64::MAX as u128 + 1, u128::MAX] {
+            // When
+            let bytes = BigUint::from(value).to_bytes_le();
+
+            // Then
+            let expected = value.to_le_bytes();
+            let len = 16 - value.leading_zeros() as usize / 8;
+            assert_eq!(bytes, expected[..len], "value={}", value);
+        }
+    }
+
+    #[test]
+    fn given_max_u128_when_squared_then_matches_known_value() {
+        // Given
+        let a = BigUint::from(u128::MAX);
+
+        // When
+        let square = &a * &a;
+
+        // Then: (2^128 - 1)^2 = 2^256 - 2^129 + 1
+        assert_eq!(
+            square.to_string(),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+        assert_eq!(square.bits(), 256);
+    }
+
+    #[test]
+    fn given_smaller_minus_larger_when_checked_sub_then_returns_none() {
+        // Given
+        let small = BigUint::from(5u64);
+        let large = BigUint::from(u128::MAX);
+
+        // When / Then
+        assert_eq!(small.checked_sub(&large), None);
+        assert_eq!(
+            large.checked_sub(&small),
+            Some(BigUint::from(u128::MAX - 5))
+        );
+        assert_eq!(large.checked_sub(&large), Some(BigUint::zero()));
+    }
+
+    #[test]
+    fn given_borrow_across_limbs_when_checked_sub_then_propagates() {
+        // Given
+        let a = BigUint::from(1u128 << 64);
+
+        // When
+        let diff = a.checked_sub(&BigUint::one());
+
+        // Then
+        assert_eq!(diff, Some(BigUint::from(u64::MAX)));
+    }
+
+    #[test]
+    fn given_zero_when_display_then_prints_zero_with_padding() {
+        // Given
+        let zero = BigUint::zero();
+
+        // When / Then
+        assert_eq!(zero.to_string(), "0");
+        assert_eq!(format!("{:>5}", zero), "    0");
+    }
+
+    /// Число из `limbs` разрядов с псевдослучайными значениями.
+    fn pseudo_random(limbs: usize, seed: u64) -> BigUint {
+        let mut state = seed;
+        BigUint::from_limbs(
+            (0..limbs)
+                .map(|_| {
+                    state = state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    state
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn given_large_operands_when_karatsuba_then_matches_schoolbook() {
+        // Given
+        for (la, lb) in [(32, 32), (33, 100), (200, 64), (257, 31), (700, 650)] {
+            let (a, b) = (
+                pseudo_random(la, la as u64),
+                pseudo_random(lb, lb as u64 + 7),
+            );
+
+            // When
+            let product = &a * &b;
+
+            // Then
+            assert_eq!(product, schoolbook(&a, &b), "{}x{}", la, lb);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn given_threads_when_mul_parallel_then_matches_sequential() {
+        // Given
+        let (a, b) = (pseudo_random(1500, 1), pseudo_random(1300, 2));
+
+        // When
+        let sequential = a.mul_parallel(&b, 1);
+        let parallel = a.mul_parallel(&b, 8);
+
+        // Then
+        assert_eq!(parallel, sequential);
+        assert_eq!(parallel, schoolbook(&a, &b));
+    }
+
+    #[test]
+    fn given_common_factor_when_gcd_then_returns_it() {
+        // Given: общий множитель с 130 двойками и разными нечётными сомножителями
+        let factor = &pseudo_random(5, 3) * &BigUint::from(1u128 << 127);
+        let factor = &factor * &BigUint::from(8u64);
+        let a = &factor * &BigUint::from(7u64);
+        let b = &factor * &BigUint::from(11u64);
+
+        // When
+        let gcd = a.gcd(&b);
+
+        // Then
+        assert_eq!(gcd, factor);
+        assert_eq!(a.gcd(&BigUint::zero()), a);
+        assert_eq!(BigUint::zero().gcd(&BigUint::zero()), BigUint::zero());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn given_big_value_when_serialize_then_decimal_string_round_trips() {
+        // Given
+        let value = crate::fibonacci::fibonacci_big(1000);
+
+        // When
+        let json = serde_json::to_string(&value).unwrap();
+
+        // Then
+        assert_eq!(json, format!("\"{}\"", value));
+        assert_eq!(serde_json::from_str::<BigUint>(&json).unwrap(), value);
+        assert_eq!(
+            serde_json::from_str::<BigUint>("\"0\"").unwrap(),
+            BigUint::zero()
+        );
+        for invalid in ["1000", "\"\"", "\"-1\"", "\"12a\"", "\"1e5\""] {
+            assert!(
+                serde_json::from_str::<BigUint>(invalid).is_err(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn given_two_u64_when_add_and_mul_then_match_u128(a: u64, b: u64) {
+            // Given
+            let (x, y) = (BigUint::from(a), BigUint::from(b));
+
+            // When
+            let sum = &x + &y;
+            let product = &x * &y;
+
+            // Then
+            prop_assert_eq!(sum.to_u128(), Some(a as u128 + b as u128));
+            prop_assert_eq!(product.to_u128(), Some(a as u128 * b as u128));
+        }
+
+        #[test]
+        fn given_any_u128_when_compare_then_matches_u128_order(a: u128, b: u128) {
+            // Given / When
+            let ordering = BigUint::from(a).cmp(&BigUint::from(b));
+
+            // Then
+            prop_assert_eq!(ordering, a.cmp(&b));
+        }
+
+        #[test]
+        fn given_any_u128_when_gcd_then_matches_euclid(a: u128, b: u128) {
+            // Given
+            let euclid = {
+                let (mut x, mut y) = (a, b);
+                while y != 0 {
+                    (x, y) = (y, x % y);
+                }
+                x
+            };
+
+            // When
+            let gcd = BigUint::from(a).gcd(&BigUint::from(b));
+
+            // Then
+            prop_assert_eq!(gcd.to_u128(), Some(euclid));
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn given_any_u128_when_serde_round_trip_then_unchanged(a: u128) {
+            // Given
+            let value = BigUint::from(a);
+
+            // When
+            let json = serde_json::to_string(&value).unwrap();
+            let parsed: BigUint = serde_json::from_str(&json).unwrap();
+
+            // Then
+            prop_assert_eq!(json, format!("\"{}\"", a));
+            prop_assert_eq!(parsed, value);
+        }
+    }
+}
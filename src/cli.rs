@@ -0,0 +1,855 @@
+use std::net::{Ipv4Addr, SocketAddr};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::config::{Config, SETTINGS};
+use crate::errors::{ArgsError, BudgetError};
+use crate::fibonacci::{Backend, Calibration, Estimate, OverflowPolicy, estimate, estimate_with};
+use crate::identities::MAX_VERIFY_UP_TO;
+use crate::logger::LogLevel;
+
+/// Параметры запуска, заданные в командной строке.
+///
+/// # Пример
+///
+/// ```
+/// use fibonacci_stable::{OverflowPolicy, parse_args};
+///
+/// let options = parse_args(["--overflow", "saturating"]).unwrap();
+/// assert_eq!(options.overflow, OverflowPolicy::Saturating);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Options {
+    /// Что запустить: интерактивный калькулятор или сервер.
+    pub command: Command,
+    /// Политика переполнения (`--overflow checked|wrapping|saturating`).
+    pub overflow: OverflowPolicy,
+    /// Режим вычисления.
+    pub mode: Mode,
+    /// Ограничения на стоимость одного вычисления.
+    pub budget: Budget,
+    /// Количество потоков (`--threads N`); `None` — по числу ядер.
+    pub threads: Option<NonZeroUsize>,
+    /// Пакетный режим (`--batch`): все индексы из stdin, по одному на строку.
+    pub batch: bool,
+    /// Язык сообщений об ошибках (`--lang ru|en|both`).
+    pub lang: Language,
+    /// Формат результатов и ошибок (`--format text|json`).
+    pub format: OutputFormat,
+    /// Куда выводить лог в конце работы (`--log output|stderr|none|<путь>`).
+    pub log: LogTarget,
+    /// Уровень логирования (`--log-level info|error`).
+    pub log_level: LogLevel,
+}
+
+impl Options {
+    /// Количество потоков для вычислений.
+    pub fn thread_count(&self) -> usize {
+        self.threads
+            .map_or_else(crate::parallel::default_threads, NonZeroUsize::get)
+    }
+}
+
+/// Подкоманда, заданная первым аргументом.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Command {
+    /// Интерактивный или пакетный калькулятор (по умолчанию).
+    #[default]
+    Run,
+    /// Сервер (`serve [--addr IP:PORT] [--protocol http|line]`).
+    Serve {
+        /// Адрес, на котором принимаются соединения.
+        addr: SocketAddr,
+        /// Протокол сервера.
+        protocol: Protocol,
+    },
+    /// Проверка тождеств (`verify [--up-to N]`), см. [`identities`](crate::identities).
+    Verify {
+        /// Наибольший индекс числа Фибоначчи в проверках, не больше
+        /// [`MAX_VERIFY_UP_TO`](crate::identities::MAX_VERIFY_UP_TO).
+        up_to: u128,
+    },
+}
+
+/// Протокол сервера, заданный флагом `--protocol`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Protocol {
+    /// HTTP JSON API, см. [`server`](crate::server) (по умолчанию).
+    #[default]
+    Http,
+    /// Строковый протокол поверх TCP, см. [`line_protocol`](crate::line_protocol).
+    Line,
+}
+
+/// Граница индексов для `verify` без `--up-to`.
+pub const DEFAULT_VERIFY_UP_TO: u128 = 100;
+
+/// Адрес сервера по умолчанию — только локальные соединения.
+pub const DEFAULT_SERVE_ADDR: SocketAddr =
+    SocketAddr::new(std::net::IpAddr::V4(Ipv4Addr::LOCALHOST), 8080);
+
+/// Режим вычисления, выбранный в командной строке.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// Вычисление в `u128` с политикой [`Options::overflow`] (по умолчанию, `--mode u128`).
+    #[default]
+    U128,
+    /// Точное вычисление в [`BigUint`](crate::BigUint) (`--big`).
+    Big,
+    /// Первые цифры и порядок через [`approx`](crate::approx) (`--approx`).
+    Approx,
+    /// Последние `k` цифр через [`modular`](crate::modular) (`--last-digits k`).
+    LastDigits(u32),
+}
+
+impl Mode {
+    /// Бэкенд, на котором выполняется вычисление в этом режиме.
+    pub fn backend(self) -> Backend {
+        match self {
+            Mode::U128 => Backend::U128,
+            Mode::Big => Backend::Big,
+            Mode::Approx => Backend::Approx,
+            Mode::LastDigits(_) => Backend::LastDigits,
+        }
+    }
+}
+
+/// Лимит памяти по умолчанию — 1 ГиБ, чтобы `--big` не исчерпал память на огромном индексе.
+pub const DEFAULT_MAX_MEMORY_BYTES: u128 = 1 << 30;
+
+/// Ограничения на стоимость одного вычисления, проверяемые по [`Estimate`].
+///
+/// # Пример
+///
+/// ```
+/// use fibonacci_stable::Budget;
+/// use fibonacci_stable::fibonacci::{Backend, estimate};
+///
+/// let budget = Budget {
+///     max_digits: Some(100),
+///     ..Budget::default()
+/// };
+///
+/// assert!(budget.check(100, &estimate(100, Backend::Big)).is_ok());
+/// assert!(budget.check(1000, &estimate(1000, Backend::Big)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Budget {
+    /// Наибольшее количество десятичных цифр результата (`--max-digits`).
+    pub max_digits: Option<u128>,
+    /// Наибольшая память, байт (`--max-memory`, по умолчанию [`DEFAULT_MAX_MEMORY_BYTES`]).
+    pub max_memory_bytes: Option<u128>,
+    /// Наибольшее время (`--max-time`, в секундах).
+    pub max_time: Option<Duration>,
+    /// Что делать при превышении (`--on-budget refuse|warn`).
+    pub action: BudgetAction,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Budget {
+            max_digits: None,
+            max_memory_bytes: Some(DEFAULT_MAX_MEMORY_BYTES),
+            max_time: None,
+            action: BudgetAction::Refuse,
+        }
+    }
+}
+
+impl Budget {
+    /// Прогноз fib(n) для проверки этого бюджета.
+    ///
+    /// Микробенчмарк [`Calibration::measure()`] запускается, только если задан
+    /// лимит времени; иначе время не оценивается и `time` нулевой.
+    pub fn estimate(&self, n: u128, backend: Backend) -> Estimate {
+        match self.max_time {
+            Some(_) => estimate(n, backend),
+            None => estimate_with(n, backend, &Calibration::SIZE_ONLY),
+        }
+    }
+
+    /// Проверяет прогноз вычисления fib(n); возвращает первое превышенное ограничение.
+    pub fn check(&self, n: u128, estimate: &Estimate) -> Result<(), BudgetError> {
+        if let Some(limit) = self.max_digits.filter(|&l| estimate.decimal_digits > l) {
+            return Err(BudgetError::Digits {
+                n,
+                estimated: estimate.decimal_digits,
+                limit,
+            });
+        }
+        if let Some(limit) = self.max_memory_bytes.filter(|&l| estimate.memory_bytes > l) {
+            return Err(BudgetError::Memory {
+                n,
+                estimated: estimate.memory_bytes,
+                limit,
+            });
+        }
+        if let Some(limit) = self.max_time.filter(|&l| estimate.time > l) {
+            return Err(BudgetError::Time {
+                n,
+                estimated: estimate.time,
+                limit,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Реакция на превышение бюджета.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BudgetAction {
+    /// Отказать в вычислении и запросить другое число (по умолчанию).
+    #[default]
+    Refuse,
+    /// Предупредить и всё равно вычислить.
+    Warn,
+}
+
+/// Язык двуязычных сообщений (`RU: ...` / `EN: ...`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    /// Обе строки, как в [`InputError`](crate::InputError) (по умолчанию).
+    #[default]
+    Both,
+    /// Только строки `RU: ...`.
+    Ru,
+    /// Только строки `EN: ...`.
+    En,
+}
+
+impl Language {
+    /// Нужно ли выводить строку сообщения: отбрасывает строки другого языка.
+    ///
+    /// ```
+    /// use fibonacci_stable::cli::Language;
+    ///
+    /// assert!(Language::En.keeps("EN: Error! Empty input"));
+    /// assert!(!Language::En.keeps("RU: Ошибка! Пустой ввод"));
+    /// assert!(Language::En.keeps("fibonacci(10) = 55"));
+    /// ```
+    pub fn keeps(self, line: &str) -> bool {
+        match self {
+            Language::Both => true,
+            Language::Ru => !line.starts_with("EN: "),
+            Language::En => !line.starts_with("RU: "),
+        }
+    }
+}
+
+/// Формат вывода результатов и ошибок.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// `fibonacci(10) = 55` и текст ошибки (по умолчанию).
+    #[default]
+    Text,
+    /// JSON-объект на строку: `{"n":"10","value":"55"}` или
+    /// `{"input":"187","error":{"code":"COMPUTATION_OVERFLOW","message":"..."}}`.
+    Json,
+}
+
+/// Куда выводится лог в конце работы.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum LogTarget {
+    /// В тот же вывод после результатов, под заголовком `--- Логи ---` (по умолчанию).
+    #[default]
+    Output,
+    /// В stderr.
+    Stderr,
+    /// Не выводить.
+    None,
+    /// Дописать в файл.
+    File(PathBuf),
+}
+
+/// Разбирает аргументы командной строки (без имени программы).
+///
+/// Значение флага можно передать отдельным аргументом (`--overflow wrapping`)
+/// или через `=` (`--overflow=wrapping`). Подкоманда `serve` или `verify` указывается первой.
+pub fn parse_args<I, S>(args: I) -> Result<Options, ArgsError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    parse_args_with(&Config::default(), args)
+}
+
+/// Разбирает аргументы командной строки поверх настроек из файла и окружения.
+///
+/// Флаги переопределяют значения `config`; см. порядок приоритета в [`config`](crate::config).
+///
+/// # Пример
+///
+/// ```
+/// use fibonacci_stable::config::Config;
+/// use fibonacci_stable::{OverflowPolicy, parse_args_with};
+///
+/// let mut config = Config::default();
+/// config.apply_toml("overflow = \"wrapping\"\nthreads = 2", "fibonacci_stable.toml").unwrap();
+///
+/// let options = parse_args_with(&config, ["--overflow", "saturating"]).unwrap();
+/// assert_eq!(options.overflow, OverflowPolicy::Saturating);
+/// assert_eq!(options.thread_count(), 2);
+/// ```
+pub fn parse_args_with<I, S>(config: &Config, args: I) -> Result<Options, ArgsError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut config = config.clone();
+    let mut args = args
+        .into_iter()
+        .map(|arg| arg.as_ref().to_string())
+        .peekable();
+    let subcommand = args.next_if(|arg| arg == "serve" || arg == "verify");
+    let serve = subcommand.as_deref() == Some("serve");
+    let verify = subcommand.as_deref() == Some("verify");
+    let mut up_to = DEFAULT_VERIFY_UP_TO;
+
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => {
+                (flag.to_string(), Some(value.to_string()))
+            }
+            _ => (arg, None),
+        };
+
+        match flag.as_str() {
+            "--big" if inline_value.is_none() => config.options.mode = Mode::Big,
+            "--approx" if inline_value.is_none() => config.options.mode = Mode::Approx,
+            "--batch" if inline_value.is_none() => config.options.batch = true,
+            "--up-to" if verify => {
+                let value = flag_value(&flag, inline_value, &mut args)?;
+                up_to = value
+                    .parse()
+                    .ok()
+                    .filter(|&n| n <= MAX_VERIFY_UP_TO)
+                    .ok_or_else(|| ArgsError::InvalidValue {
+                        flag,
+                        value,
+                        expected: format!("0..={}", MAX_VERIFY_UP_TO),
+                    })?;
+            }
+            _ => {
+                let Some(setting) = SETTINGS
+                    .iter()
+                    .find(|s| s.flag == Some(flag.as_str()) && (serve || !s.serve_only()))
+                else {
+                    return Err(ArgsError::UnknownArgument(arg_name(flag, inline_value)));
+                };
+                let value = flag_value(&flag, inline_value, &mut args)?;
+                if (setting.apply)(&mut config, &value).is_none() {
+                    return Err(ArgsError::InvalidValue {
+                        flag,
+                        value,
+                        expected: setting.expected.to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    let mut options = config.options;
+    if serve {
+        options.command = Command::Serve {
+            addr: config.addr,
+            protocol: config.protocol,
+        };
+    } else if verify {
+        options.command = Command::Verify { up_to };
+    }
+    Ok(options)
+}
+
+fn flag_value(
+    flag: &str,
+    inline_value: Option<String>,
+    args: &mut impl Iterator<Item = String>,
+) -> Result<String, ArgsError> {
+    inline_value
+        .or_else(|| args.next())
+        .ok_or_else(|| ArgsError::MissingValue(flag.to_string()))
+}
+
+fn arg_name(flag: String, inline_value: Option<String>) -> String {
+    match inline_value {
+        Some(value) => format!("{}={}", flag, value),
+        None => flag,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_no_args_when_parse_args_then_returns_defaults() {
+        // Given
+        let args: [&str; 0] = [];
+
+        // When
+        let result = parse_args(args);
+
+        // Then
+        assert_eq!(result, Ok(Options::default()));
+        assert_eq!(result.unwrap().overflow, OverflowPolicy::Checked);
+    }
+
+    #[test]
+    fn given_overflow_flag_when_parse_args_then_sets_policy() {
+        // Given / When
+        let separate = parse_args(["--overflow", "wrapping"]);
+        let inline = parse_args(["--overflow=saturating"]);
+
+        // Then
+        assert_eq!(separate.unwrap().overflow, OverflowPolicy::Wrapping);
+        assert_eq!(inline.unwrap().overflow, OverflowPolicy::Saturating);
+    }
+
+    #[test]
+    fn given_overflow_without_value_when_parse_args_then_returns_missing_value() {
+        // Given
+        let args = ["--overflow"];
+
+        // When
+        let result = parse_args(args);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(ArgsError::MissingValue("--overflow".to_string()))
+        );
+    }
+
+    #[test]
+    fn given_unknown_policy_when_parse_args_then_returns_invalid_value() {
+        // Given
+        let args = ["--overflow", "modular"];
+
+        // When
+        let result = parse_args(args);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(ArgsError::InvalidValue {
+                flag: "--overflow".to_string(),
+                value: "modular".to_string(),
+                expected: "checked|wrapping|saturating".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn given_big_flag_when_parse_args_then_sets_big_mode() {
+        // Given
+        let args = ["--big"];
+
+        // When
+        let result = parse_args(args);
+
+        // Then
+        assert_eq!(result.unwrap().mode, Mode::Big);
+    }
+
+    #[test]
+    fn given_approx_flag_when_parse_args_then_sets_approx_mode() {
+        // Given
+        let args = ["--approx"];
+
+        // When
+        let options = parse_args(args).unwrap();
+
+        // Then
+        assert_eq!(options.mode, Mode::Approx);
+        assert_eq!(options.mode.backend(), Backend::Approx);
+    }
+
+    #[test]
+    fn given_last_digits_flag_when_parse_args_then_sets_last_digits_mode() {
+        // Given
+        let args = ["--last-digits", "20"];
+
+        // When
+        let options = parse_args(args).unwrap();
+
+        // Then
+        assert_eq!(options.mode, Mode::LastDigits(20));
+        assert_eq!(options.mode.backend(), Backend::LastDigits);
+    }
+
+    #[test]
+    fn given_last_digits_out_of_range_when_parse_args_then_returns_invalid_value() {
+        // Given / When
+        let zero = parse_args(["--last-digits=0"]);
+        let too_many = parse_args(["--last-digits", "39"]);
+
+        // Then
+        assert_eq!(
+            zero,
+            Err(ArgsError::InvalidValue {
+                flag: "--last-digits".to_string(),
+                value: "0".to_string(),
+                expected: "1..=38".to_string(),
+            })
+        );
+        assert!(matches!(too_many, Err(ArgsError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn given_big_flag_with_value_when_parse_args_then_returns_unknown_argument() {
+        // Given
+        let args = ["--big=yes"];
+
+        // When
+        let result = parse_args(args);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(ArgsError::UnknownArgument("--big=yes".to_string()))
+        );
+    }
+
+    #[test]
+    fn given_budget_flags_when_parse_args_then_sets_budget() {
+        // Given
+        let args = [
+            "--max-digits",
+            "1000",
+            "--max-memory=4096",
+            "--max-time",
+            "0.5",
+            "--on-budget",
+            "warn",
+        ];
+
+        // When
+        let budget = parse_args(args).unwrap().budget;
+
+        // Then
+        assert_eq!(
+            budget,
+            Budget {
+                max_digits: Some(1000),
+                max_memory_bytes: Some(4096),
+                max_time: Some(Duration::from_millis(500)),
+                action: BudgetAction::Warn,
+            }
+        );
+    }
+
+    #[test]
+    fn given_non_numeric_budget_when_parse_args_then_returns_invalid_value() {
+        // Given / When
+        let digits = parse_args(["--max-digits", "many"]);
+        let time = parse_args(["--max-time", "-1"]);
+
+        // Then
+        assert!(matches!(digits, Err(ArgsError::InvalidValue { .. })));
+        assert!(matches!(time, Err(ArgsError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn given_estimate_over_limits_when_check_then_reports_first_exceeded() {
+        // Given
+        let estimate = Estimate {
+            bits: 700,
+            decimal_digits: 210,
+            memory_bytes: 2000,
+            time: Duration::from_secs(2),
+        };
+        let budget = Budget {
+            max_digits: Some(300),
+            max_memory_bytes: Some(1000),
+            max_time: Some(Duration::from_secs(1)),
+            action: BudgetAction::Refuse,
+        };
+
+        // When
+        let result = budget.check(1000, &estimate);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(BudgetError::Memory {
+                n: 1000,
+                estimated: 2000,
+                limit: 1000,
+            })
+        );
+    }
+
+    #[test]
+    fn given_default_budget_when_check_small_estimate_then_ok() {
+        // Given
+        let estimate = Estimate {
+            bits: 7,
+            decimal_digits: 2,
+            memory_bytes: 32,
+            time: Duration::from_nanos(10),
+        };
+
+        // When
+        let result = Budget::default().check(10, &estimate);
+
+        // Then
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn given_budget_without_max_time_when_estimate_then_skips_time_and_keeps_size() {
+        // Given
+        let budget = Budget::default();
+
+        // When
+        let estimate = budget.estimate(1000, Backend::Big);
+
+        // Then
+        assert_eq!(estimate.time, Duration::ZERO);
+        assert_eq!(estimate.decimal_digits, 209);
+    }
+
+    #[test]
+    fn given_threads_and_batch_when_parse_args_then_sets_both() {
+        // Given
+        let args = ["--threads", "8", "--batch"];
+
+        // When
+        let options = parse_args(args).unwrap();
+
+        // Then
+        assert_eq!(options.threads, NonZeroUsize::new(8));
+        assert_eq!(options.thread_count(), 8);
+        assert!(options.batch);
+    }
+
+    #[test]
+    fn given_zero_threads_when_parse_args_then_returns_invalid_value() {
+        // Given
+        let args = ["--threads=0"];
+
+        // When
+        let result = parse_args(args);
+
+        // Then
+        assert!(matches!(result, Err(ArgsError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn given_serve_when_parse_args_then_sets_default_addr() {
+        // Given
+        let args = ["serve", "--big"];
+
+        // When
+        let options = parse_args(args).unwrap();
+
+        // Then
+        assert_eq!(
+            options.command,
+            Command::Serve {
+                addr: DEFAULT_SERVE_ADDR,
+                protocol: Protocol::Http,
+            }
+        );
+        assert_eq!(options.mode, Mode::Big);
+    }
+
+    #[test]
+    fn given_serve_with_addr_when_parse_args_then_sets_addr() {
+        // Given
+        let args = ["serve", "--addr=0.0.0.0:9000", "--protocol", "line"];
+
+        // When
+        let options = parse_args(args).unwrap();
+
+        // Then
+        assert_eq!(
+            options.command,
+            Command::Serve {
+                addr: "0.0.0.0:9000".parse().unwrap(),
+                protocol: Protocol::Line,
+            }
+        );
+    }
+
+    #[test]
+    fn given_addr_without_serve_when_parse_args_then_returns_unknown_argument() {
+        // Given / When
+        let without_serve = parse_args(["--addr", "127.0.0.1:1"]);
+        let serve_not_first = parse_args(["--big", "serve"]);
+
+        // Then
+        assert_eq!(
+            without_serve,
+            Err(ArgsError::UnknownArgument("--addr".to_string()))
+        );
+        assert_eq!(
+            serve_not_first,
+            Err(ArgsError::UnknownArgument("serve".to_string()))
+        );
+    }
+
+    #[test]
+    fn given_invalid_addr_when_parse_args_then_returns_invalid_value() {
+        // Given
+        let args = ["serve", "--addr", "localhost"];
+
+        // When
+        let addr = parse_args(args);
+        let protocol = parse_args(["serve", "--protocol", "grpc"]);
+
+        // Then
+        assert!(matches!(addr, Err(ArgsError::InvalidValue { .. })));
+        assert!(matches!(protocol, Err(ArgsError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn given_unknown_flag_when_parse_args_then_returns_unknown_argument() {
+        // Given
+        let args = ["--verbose"];
+
+        // When
+        let result = parse_args(args);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(ArgsError::UnknownArgument("--verbose".to_string()))
+        );
+    }
+
+    #[test]
+    fn given_output_flags_when_parse_args_then_sets_output_options() {
+        // Given
+        let args = [
+            "--lang",
+            "en",
+            "--format=json",
+            "--log",
+            "/tmp/fibonacci.log",
+            "--log-level",
+            "error",
+        ];
+
+        // When
+        let options = parse_args(args).unwrap();
+
+        // Then
+        assert_eq!(options.lang, Language::En);
+        assert_eq!(options.format, OutputFormat::Json);
+        assert_eq!(
+            options.log,
+            LogTarget::File(PathBuf::from("/tmp/fibonacci.log"))
+        );
+        assert_eq!(options.log_level, LogLevel::Error);
+    }
+
+    #[test]
+    fn given_config_when_parse_args_with_flag_then_flag_overrides_config() {
+        // Given
+        let mut config = Config::default();
+        config
+            .apply_toml("overflow = \"wrapping\"\nlang = \"ru\"", "test.toml")
+            .unwrap();
+
+        // When
+        let options = parse_args_with(&config, ["--overflow", "saturating"]).unwrap();
+
+        // Then
+        assert_eq!(options.overflow, OverflowPolicy::Saturating);
+        assert_eq!(options.lang, Language::Ru);
+    }
+
+    #[test]
+    fn given_big_in_config_when_parse_args_with_mode_flag_then_flag_overrides_mode() {
+        // Given
+        let mut config = Config::default();
+        config.apply_toml("big = true", "test.toml").unwrap();
+
+        // When
+        let from_config = parse_args_with(&config, ["--threads", "1"]).unwrap();
+        let u128_mode = parse_args_with(&config, ["--mode", "u128"]).unwrap();
+        let approx = parse_args_with(&config, ["--mode=approx"]).unwrap();
+        let invalid = parse_args_with(&config, ["--mode", "last"]);
+
+        // Then
+        assert_eq!(from_config.mode, Mode::Big);
+        assert_eq!(u128_mode.mode, Mode::U128);
+        assert_eq!(approx.mode, Mode::Approx);
+        assert_eq!(
+            invalid,
+            Err(ArgsError::InvalidValue {
+                flag: "--mode".to_string(),
+                value: "last".to_string(),
+                expected: "u128|big|approx".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn given_language_when_keeps_then_filters_other_language_lines() {
+        // Given
+        let lines = ["RU: Ошибка! Пустой ввод", "EN: Error! Empty input", "55"];
+
+        // When
+        let ru: Vec<_> = lines.iter().filter(|l| Language::Ru.keeps(l)).collect();
+        let both: Vec<_> = lines.iter().filter(|l| Language::Both.keeps(l)).collect();
+
+        // Then
+        assert_eq!(ru, [&lines[0], &lines[2]]);
+        assert_eq!(both.len(), 3);
+    }
+
+    #[test]
+    fn given_verify_when_parse_args_then_sets_up_to() {
+        // Given / When
+        let default = parse_args(["verify"]).unwrap();
+        let explicit = parse_args(["verify", "--up-to=250"]).unwrap();
+        let without_verify = parse_args(["--up-to", "250"]);
+        let invalid = parse_args(["verify", "--up-to", "-1"]);
+
+        // Then
+        assert_eq!(
+            default.command,
+            Command::Verify {
+                up_to: DEFAULT_VERIFY_UP_TO
+            }
+        );
+        assert_eq!(explicit.command, Command::Verify { up_to: 250 });
+        assert_eq!(
+            without_verify,
+            Err(ArgsError::UnknownArgument("--up-to".to_string()))
+        );
+        assert!(matches!(invalid, Err(ArgsError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn given_up_to_above_limit_when_parse_args_then_returns_invalid_value() {
+        // Given
+        let limit = MAX_VERIFY_UP_TO.to_string();
+        let above = (MAX_VERIFY_UP_TO + 1).to_string();
+
+        // When
+        let at_limit = parse_args(["verify", "--up-to", limit.as_str()]);
+        let too_large = parse_args(["verify", "--up-to", above.as_str()]);
+        let u128_max = parse_args(["verify", "--up-to", &u128::MAX.to_string()]);
+
+        // Then
+        assert_eq!(
+            at_limit.unwrap().command,
+            Command::Verify {
+                up_to: MAX_VERIFY_UP_TO
+            }
+        );
+        assert_eq!(
+            too_large,
+            Err(ArgsError::InvalidValue {
+                flag: "--up-to".to_string(),
+                value: above,
+                expected: "0..=1000".to_string(),
+            })
+        );
+        assert!(matches!(u128_max, Err(ArgsError::InvalidValue { .. })));
+    }
+}
@@ -0,0 +1,758 @@
+//! Файл конфигурации `fibonacci_stable.toml` и переменные окружения.
+//!
+//! Порядок приоритета — каждый следующий уровень переопределяет предыдущий:
+//!
+//! 1. значения по умолчанию;
+//! 2. файл конфигурации — первый найденный из
+//!    `$FIBONACCI_STABLE_CONFIG` (должен существовать), `./fibonacci_stable.toml`,
+//!    `$XDG_CONFIG_HOME/fibonacci_stable.toml` (по умолчанию `~/.config/fibonacci_stable.toml`);
+//! 3. переменные окружения `FIBONACCI_STABLE_<КЛЮЧ>`: ключ в верхнем регистре,
+//!    точка заменена на `_`, например `FIBONACCI_STABLE_BUDGET_MAX_DIGITS`;
+//! 4. флаги командной строки ([`parse_args_with()`](crate::parse_args_with)).
+//!
+//! Файл — подмножество TOML: комментарии `#`, разделы `[budget]` и `[serve]`,
+//! пары `ключ = значение` со строками в кавычках, числами и `true`/`false`.
+//!
+//! ```toml
+//! overflow = "wrapping"      # --overflow checked|wrapping|saturating
+//! mode = "u128"              # --mode u128|big|approx
+//! big = false                # --big
+//! approx = false             # --approx
+//! last_digits = 20           # --last-digits 1..=38
+//! threads = 4                # --threads
+//! lang = "both"              # --lang ru|en|both
+//! format = "text"            # --format text|json
+//! log = "output"             # --log output|stderr|none|<путь>
+//! log_level = "info"         # --log-level info|error
+//!
+//! [budget]
+//! max_digits = 1_000_000     # --max-digits
+//! max_memory = 1073741824    # --max-memory, байт
+//! max_time = 2.5             # --max-time, секунд
+//! on_budget = "refuse"       # --on-budget refuse|warn
+//!
+//! [serve]
+//! addr = "127.0.0.1:8080"    # serve --addr
+//! protocol = "http"          # serve --protocol http|line
+//! ```
+
+use std::env;
+use std::fs;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::cli::{
+    BudgetAction, DEFAULT_SERVE_ADDR, Language, LogTarget, Mode, Options, OutputFormat, Protocol,
+};
+use crate::errors::ConfigError;
+use crate::fibonacci::OverflowPolicy;
+use crate::logger::LogLevel;
+use crate::modular::MAX_LAST_DIGITS;
+
+/// Имя файла конфигурации в рабочем каталоге и в каталоге XDG.
+pub const CONFIG_FILE_NAME: &str = "fibonacci_stable.toml";
+
+/// Переменная окружения с явным путём к файлу конфигурации.
+pub const CONFIG_PATH_VAR: &str = "FIBONACCI_STABLE_CONFIG";
+
+/// Префикс переменных окружения с настройками.
+pub const ENV_PREFIX: &str = "FIBONACCI_STABLE_";
+
+/// Настройки из файла конфигурации и окружения — основа для флагов CLI.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// Параметры калькулятора; `command` всегда [`Command::Run`](crate::Command::Run).
+    pub options: Options,
+    /// Адрес для `serve` без `--addr`.
+    pub addr: SocketAddr,
+    /// Протокол для `serve` без `--protocol`.
+    pub protocol: Protocol,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            options: Options::default(),
+            addr: DEFAULT_SERVE_ADDR,
+            protocol: Protocol::Http,
+        }
+    }
+}
+
+impl Config {
+    /// Загружает настройки процесса: файл из текущего каталога или XDG,
+    /// затем переменные окружения.
+    ///
+    /// # Ошибки
+    ///
+    /// [`ConfigError`] для нечитаемого файла, синтаксической ошибки,
+    /// неизвестного ключа или недопустимого значения.
+    pub fn load() -> Result<Config, ConfigError> {
+        Config::load_with(Path::new("."), |name| env::var(name).ok())
+    }
+
+    /// То же, что [`Config::load()`], но с заданными рабочим каталогом и окружением.
+    pub fn load_with(
+        dir: &Path,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Config, ConfigError> {
+        let mut config = Config::default();
+        if let Some(path) = find_file(dir, &env) {
+            let text = fs::read_to_string(&path).map_err(|e| ConfigError::Read {
+                path: path.display().to_string(),
+                reason: e.to_string(),
+            })?;
+            config.apply_toml(&text, &path.display().to_string())?;
+        }
+        config.apply_env(env)?;
+        Ok(config)
+    }
+
+    /// Применяет текст файла конфигурации; `origin` — имя файла для сообщений об ошибках.
+    ///
+    /// # Пример
+    ///
+    /// ```
+    /// use fibonacci_stable::OverflowPolicy;
+    /// use fibonacci_stable::config::Config;
+    ///
+    /// let mut config = Config::default();
+    /// config.apply_toml("overflow = \"saturating\"\n[budget]\nmax_digits = 100\n", "app.toml").unwrap();
+    /// assert_eq!(config.options.overflow, OverflowPolicy::Saturating);
+    /// assert_eq!(config.options.budget.max_digits, Some(100));
+    ///
+    /// let error = config.apply_toml("threads = 0", "app.toml").unwrap_err();
+    /// assert!(error.to_string().contains("EN: Error! Invalid value '0' for threads in app.toml:1"));
+    /// ```
+    pub fn apply_toml(&mut self, text: &str, origin: &str) -> Result<(), ConfigError> {
+        let mut section = String::new();
+        for (number, raw) in text.lines().enumerate() {
+            let location = format!("{}:{}", origin, number + 1);
+            let line = strip_comment(raw).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let syntax = || ConfigError::Syntax {
+                location: location.clone(),
+                text: raw.trim().to_string(),
+            };
+
+            if let Some(name) = line.strip_prefix('[') {
+                let name = name.strip_suffix(']').ok_or_else(syntax)?.trim();
+                if !is_bare_key(name) {
+                    return Err(syntax());
+                }
+                section = name.to_string();
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(syntax)?;
+            let key = key.trim();
+            if !is_bare_key(key) {
+                return Err(syntax());
+            }
+            let value = parse_value(value.trim()).ok_or_else(syntax)?;
+            let key = if section.is_empty() {
+                key.to_string()
+            } else {
+                format!("{}.{}", section, key)
+            };
+            self.apply(&key, &value, location)?;
+        }
+        Ok(())
+    }
+
+    /// Применяет переменные окружения `FIBONACCI_STABLE_<КЛЮЧ>` для всех настроек.
+    pub fn apply_env(&mut self, env: impl Fn(&str) -> Option<String>) -> Result<(), ConfigError> {
+        for setting in SETTINGS {
+            let name = setting.env_var();
+            if let Some(value) = env(&name) {
+                self.apply(setting.key, &value, name)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn apply(&mut self, key: &str, value: &str, location: String) -> Result<(), ConfigError> {
+        let setting =
+            SETTINGS
+                .iter()
+                .find(|s| s.key == key)
+                .ok_or_else(|| ConfigError::UnknownKey {
+                    location: location.clone(),
+                    key: key.to_string(),
+                })?;
+        (setting.apply)(self, value).ok_or_else(|| ConfigError::InvalidValue {
+            location,
+            key: key.to_string(),
+            value: value.to_string(),
+            expected: setting.expected.to_string(),
+        })
+    }
+}
+
+/// Первый существующий файл конфигурации или путь из `FIBONACCI_STABLE_CONFIG`.
+fn find_file(dir: &Path, env: &impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    if let Some(path) = env(CONFIG_PATH_VAR) {
+        return Some(PathBuf::from(path));
+    }
+    let xdg = env("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env("HOME").map(|home| Path::new(&home).join(".config")));
+    std::iter::once(dir.to_path_buf())
+        .chain(xdg)
+        .map(|dir| dir.join(CONFIG_FILE_NAME))
+        .find(|path| path.is_file())
+}
+
+/// Отрезает комментарий `#`, не трогая `#` внутри строк в кавычках.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                continue;
+            }
+            (Some(q), c) if c == q && !escaped => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') => return &line[..i],
+            _ => {}
+        }
+        escaped = false;
+    }
+    line
+}
+
+fn is_bare_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Значение TOML как строка: содержимое кавычек, число без `_` или `true`/`false`.
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(literal) = value.strip_prefix('\'') {
+        let literal = literal.strip_suffix('\'')?;
+        return (!literal.contains('\'')).then(|| literal.to_string());
+    }
+    if let Some(basic) = value.strip_prefix('"') {
+        let basic = basic.strip_suffix('"')?;
+        let mut result = String::new();
+        let mut chars = basic.chars();
+        while let Some(c) = chars.next() {
+            result.push(match c {
+                '\\' => match chars.next()? {
+                    '\\' => '\\',
+                    '"' => '"',
+                    'n' => '\n',
+                    't' => '\t',
+                    _ => return None,
+                },
+                '"' => return None,
+                c => c,
+            });
+        }
+        return Some(result);
+    }
+    if value == "true" || value == "false" {
+        return Some(value.to_string());
+    }
+    let number = value.replace('_', "");
+    let valid = !value.starts_with('_')
+        && !value.ends_with('_')
+        && !value.contains("__")
+        && number.parse::<f64>().is_ok()
+        && number
+            .chars()
+            .all(|c| c.is_ascii_digit() || "+-.eE".contains(c));
+    valid.then_some(number)
+}
+
+/// Настройка, задаваемая ключом файла, переменной окружения и, возможно, флагом CLI.
+pub(crate) struct Setting {
+    /// Ключ с разделом: `overflow`, `budget.max_digits`.
+    pub(crate) key: &'static str,
+    /// Флаг CLI; `None` — только файл и окружение.
+    pub(crate) flag: Option<&'static str>,
+    /// Допустимые значения для сообщения об ошибке.
+    pub(crate) expected: &'static str,
+    /// Применяет значение; `None`, если оно недопустимо.
+    pub(crate) apply: fn(&mut Config, &str) -> Option<()>,
+}
+
+impl Setting {
+    /// Имя переменной окружения: `budget.max_digits` → `FIBONACCI_STABLE_BUDGET_MAX_DIGITS`.
+    pub(crate) fn env_var(&self) -> String {
+        format!(
+            "{}{}",
+            ENV_PREFIX,
+            self.key.replace('.', "_").to_uppercase()
+        )
+    }
+
+    /// Флаг допустим только после подкоманды `serve`.
+    pub(crate) fn serve_only(&self) -> bool {
+        self.key.starts_with("serve.")
+    }
+}
+
+/// Все настройки в порядке применения переменных окружения.
+pub(crate) const SETTINGS: &[Setting] = &[
+    // Раньше big/approx/last_digits: они уточняют режим. `--mode` — единственный
+    // флаг, которым CLI возвращает режим u128 после файла или окружения.
+    Setting {
+        key: "mode",
+        flag: Some("--mode"),
+        expected: "u128|big|approx",
+        apply: |config, value| {
+            config.options.mode = match value {
+                "u128" => Mode::U128,
+                "big" => Mode::Big,
+                "approx" => Mode::Approx,
+                _ => return None,
+            };
+            Some(())
+        },
+    },
+    Setting {
+        key: "overflow",
+        flag: Some("--overflow"),
+        expected: "checked|wrapping|saturating",
+        apply: |config, value| {
+            config.options.overflow = OverflowPolicy::from_name(value)?;
+            Some(())
+        },
+    },
+    Setting {
+        key: "big",
+        flag: None,
+        expected: "true|false",
+        apply: |config, value| {
+            match value {
+                "true" => config.options.mode = Mode::Big,
+                "false" if config.options.mode == Mode::Big => config.options.mode = Mode::U128,
+                "false" => {}
+                _ => return None,
+            }
+            Some(())
+        },
+    },
+    Setting {
+        key: "approx",
+        flag: None,
+        expected: "true|false",
+        apply: |config, value| {
+            match value {
+                "true" => config.options.mode = Mode::Approx,
+                "false" if config.options.mode == Mode::Approx => config.options.mode = Mode::U128,
+                "false" => {}
+                _ => return None,
+            }
+            Some(())
+        },
+    },
+    Setting {
+        key: "last_digits",
+        flag: Some("--last-digits"),
+        expected: "1..=38",
+        apply: |config, value| {
+            let k = value
+                .parse()
+                .ok()
+                .filter(|k| (1..=MAX_LAST_DIGITS).contains(k))?;
+            config.options.mode = Mode::LastDigits(k);
+            Some(())
+        },
+    },
+    Setting {
+        key: "threads",
+        flag: Some("--threads"),
+        expected: "<threads ≥ 1>",
+        apply: |config, value| {
+            config.options.threads = Some(value.parse().ok()?);
+            Some(())
+        },
+    },
+    Setting {
+        key: "lang",
+        flag: Some("--lang"),
+        expected: "ru|en|both",
+        apply: |config, value| {
+            config.options.lang = match value {
+                "ru" => Language::Ru,
+                "en" => Language::En,
+                "both" => Language::Both,
+                _ => return None,
+            };
+            Some(())
+        },
+    },
+    Setting {
+        key: "format",
+        flag: Some("--format"),
+        expected: "text|json",
+        apply: |config, value| {
+            config.options.format = match value {
+                "text" => OutputFormat::Text,
+                "json" => OutputFormat::Json,
+                _ => return None,
+            };
+            Some(())
+        },
+    },
+    Setting {
+        key: "log",
+        flag: Some("--log"),
+        expected: "output|stderr|none|<path>",
+        apply: |config, value| {
+            config.options.log = match value {
+                "" => return None,
+                "output" => LogTarget::Output,
+                "stderr" => LogTarget::Stderr,
+                "none" => LogTarget::None,
+                path => LogTarget::File(PathBuf::from(path)),
+            };
+            Some(())
+        },
+    },
+    Setting {
+        key: "log_level",
+        flag: Some("--log-level"),
+        expected: "info|error",
+        apply: |config, value| {
+            config.options.log_level = match value {
+                "info" => LogLevel::Info,
+                "error" => LogLevel::Error,
+                _ => return None,
+            };
+            Some(())
+        },
+    },
+    Setting {
+        key: "budget.max_digits",
+        flag: Some("--max-digits"),
+        expected: "<digits>",
+        apply: |config, value| {
+            config.options.budget.max_digits = Some(value.parse().ok()?);
+            Some(())
+        },
+    },
+    Setting {
+        key: "budget.max_memory",
+        flag: Some("--max-memory"),
+        expected: "<bytes>",
+        apply: |config, value| {
+            config.options.budget.max_memory_bytes = Some(value.parse().ok()?);
+            Some(())
+        },
+    },
+    Setting {
+        key: "budget.max_time",
+        flag: Some("--max-time"),
+        expected: "<seconds>",
+        apply: |config, value| {
+            let seconds = value.parse::<f64>().ok()?;
+            config.options.budget.max_time = Some(Duration::try_from_secs_f64(seconds).ok()?);
+            Some(())
+        },
+    },
+    Setting {
+        key: "budget.on_budget",
+        flag: Some("--on-budget"),
+        expected: "refuse|warn",
+        apply: |config, value| {
+            config.options.budget.action = match value {
+                "refuse" => BudgetAction::Refuse,
+                "warn" => BudgetAction::Warn,
+                _ => return None,
+            };
+            Some(())
+        },
+    },
+    Setting {
+        key: "serve.addr",
+        flag: Some("--addr"),
+        expected: "<ip:port>",
+        apply: |config, value| {
+            config.addr = value.parse().ok()?;
+            Some(())
+        },
+    },
+    Setting {
+        key: "serve.protocol",
+        flag: Some("--protocol"),
+        expected: "http|line",
+        apply: |config, value| {
+            config.protocol = match value {
+                "http" => Protocol::Http,
+                "line" => Protocol::Line,
+                _ => return None,
+            };
+            Some(())
+        },
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env_of(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> + use<> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    /// Временный каталог теста, удаляемый после завершения.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir =
+                env::temp_dir().join(format!("fibonacci_stable-{}-{}", name, std::process::id()));
+            fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn given_full_file_when_apply_toml_then_sets_every_setting() {
+        // Given
+        let text = r#"
+            # настройки по умолчанию
+            overflow = "wrapping"   # политика
+            big = true
+            threads = 4
+            lang = 'en'
+            format = "json"
+            log = "/tmp/fib # log.txt"
+            log_level = "error"
+
+            [budget]
+            max_digits = 1_000_000
+            max_memory = 1024
+            max_time = 2.5
+            on_budget = "warn"
+
+            [serve]
+            addr = "0.0.0.0:9000"
+            protocol = "line"
+        "#;
+        let mut config = Config::default();
+
+        // When
+        let result = config.apply_toml(text, "fibonacci_stable.toml");
+
+        // Then
+        assert_eq!(result, Ok(()));
+        let options = &config.options;
+        assert_eq!(options.overflow, OverflowPolicy::Wrapping);
+        assert_eq!(options.mode, Mode::Big);
+        assert_eq!(options.thread_count(), 4);
+        assert_eq!(options.lang, Language::En);
+        assert_eq!(options.format, OutputFormat::Json);
+        assert_eq!(options.log, LogTarget::File("/tmp/fib # log.txt".into()));
+        assert_eq!(options.log_level, LogLevel::Error);
+        assert_eq!(options.budget.max_digits, Some(1_000_000));
+        assert_eq!(options.budget.max_memory_bytes, Some(1024));
+        assert_eq!(options.budget.max_time, Some(Duration::from_millis(2500)));
+        assert_eq!(options.budget.action, BudgetAction::Warn);
+        assert_eq!(config.addr, "0.0.0.0:9000".parse().unwrap());
+        assert_eq!(config.protocol, Protocol::Line);
+    }
+
+    #[test]
+    fn given_invalid_lines_when_apply_toml_then_syntax_error_with_line() {
+        // Given
+        let cases = [
+            "overflow wrapping",
+            "overflow = wrapping",
+            "overflow = \"wrapping",
+            "[budget",
+            "threads = 1__000",
+            "bad key = 1",
+        ];
+
+        for text in cases {
+            // When
+            let result = Config::default().apply_toml(&format!("\n{}", text), "app.toml");
+
+            // Then
+            assert_eq!(
+                result,
+                Err(ConfigError::Syntax {
+                    location: "app.toml:2".to_string(),
+                    text: text.to_string(),
+                }),
+                "text={}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn given_approx_setting_when_apply_toml_then_false_resets_only_approx_mode() {
+        // Given
+        let mut config = Config::default();
+
+        // When
+        config.apply_toml("approx = true", "app.toml").unwrap();
+        let approx = config.options.mode;
+        config.apply_toml("approx = false", "app.toml").unwrap();
+        let reset = config.options.mode;
+        config
+            .apply_toml("big = true\napprox = false", "app.toml")
+            .unwrap();
+
+        // Then
+        assert_eq!(approx, Mode::Approx);
+        assert_eq!(reset, Mode::U128);
+        assert_eq!(config.options.mode, Mode::Big);
+    }
+
+    #[test]
+    fn given_big_setting_when_apply_toml_then_false_resets_only_big_mode() {
+        // Given
+        let mut config = Config::default();
+
+        // When
+        config
+            .apply_toml("big = true\nbig = false", "app.toml")
+            .unwrap();
+        let reset = config.options.mode;
+        config
+            .apply_toml("approx = true\nbig = false", "app.toml")
+            .unwrap();
+        let approx = config.options.mode;
+        config.apply_toml("last_digits = 20", "app.toml").unwrap();
+        config
+            .apply_env(env_of(&[("FIBONACCI_STABLE_BIG", "false")]))
+            .unwrap();
+
+        // Then
+        assert_eq!(reset, Mode::U128);
+        assert_eq!(approx, Mode::Approx);
+        assert_eq!(config.options.mode, Mode::LastDigits(20));
+    }
+
+    #[test]
+    fn given_unknown_key_or_invalid_value_when_apply_toml_then_reports_key() {
+        // Given
+        let mut config = Config::default();
+
+        // When
+        let unknown = config.apply_toml("[budget]\nmax_digit = 10", "app.toml");
+        let invalid = config.apply_toml("[serve]\nprotocol = \"ftp\"", "app.toml");
+
+        // Then
+        assert_eq!(
+            unknown,
+            Err(ConfigError::UnknownKey {
+                location: "app.toml:2".to_string(),
+                key: "budget.max_digit".to_string(),
+            })
+        );
+        assert_eq!(
+            invalid,
+            Err(ConfigError::InvalidValue {
+                location: "app.toml:2".to_string(),
+                key: "serve.protocol".to_string(),
+                value: "ftp".to_string(),
+                expected: "http|line".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn given_settings_when_env_var_then_uppercase_with_prefix() {
+        // Given / When
+        let names: Vec<String> = SETTINGS.iter().map(Setting::env_var).collect();
+
+        // Then
+        assert!(names.contains(&"FIBONACCI_STABLE_OVERFLOW".to_string()));
+        assert!(names.contains(&"FIBONACCI_STABLE_BUDGET_MAX_DIGITS".to_string()));
+        assert!(names.contains(&"FIBONACCI_STABLE_SERVE_ADDR".to_string()));
+    }
+
+    #[test]
+    fn given_file_and_env_when_load_with_then_env_overrides_file() {
+        // Given
+        let dir = TempDir::new("precedence");
+        fs::write(
+            dir.0.join(CONFIG_FILE_NAME),
+            "overflow = \"wrapping\"\nthreads = 2\n",
+        )
+        .unwrap();
+        let env = env_of(&[("FIBONACCI_STABLE_THREADS", "8")]);
+
+        // When
+        let config = Config::load_with(&dir.0, env).unwrap();
+
+        // Then
+        assert_eq!(config.options.overflow, OverflowPolicy::Wrapping);
+        assert_eq!(config.options.thread_count(), 8);
+    }
+
+    #[test]
+    fn given_xdg_dir_when_no_local_file_then_loads_xdg_file() {
+        // Given
+        let work = TempDir::new("work");
+        let xdg = TempDir::new("xdg");
+        fs::write(xdg.0.join(CONFIG_FILE_NAME), "lang = \"ru\"").unwrap();
+        let env = env_of(&[("XDG_CONFIG_HOME", xdg.0.to_str().unwrap())]);
+
+        // When
+        let config = Config::load_with(&work.0, env).unwrap();
+
+        // Then
+        assert_eq!(config.options.lang, Language::Ru);
+    }
+
+    #[test]
+    fn given_missing_explicit_path_when_load_with_then_read_error() {
+        // Given
+        let dir = TempDir::new("explicit");
+        let missing = dir.0.join("nope.toml");
+        let env = env_of(&[(CONFIG_PATH_VAR, missing.to_str().unwrap())]);
+
+        // When
+        let result = Config::load_with(&dir.0, env);
+
+        // Then
+        assert!(matches!(result, Err(ConfigError::Read { .. })));
+    }
+
+    #[test]
+    fn given_invalid_env_value_when_apply_env_then_location_is_variable() {
+        // Given
+        let env = env_of(&[("FIBONACCI_STABLE_THREADS", "0")]);
+
+        // When
+        let result = Config::default().apply_env(env);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(ConfigError::InvalidValue {
+                location: "FIBONACCI_STABLE_THREADS".to_string(),
+                key: "threads".to_string(),
+                value: "0".to_string(),
+                expected: "<threads ≥ 1>".to_string(),
+            })
+        );
+    }
+}
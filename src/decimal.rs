@@ -0,0 +1,80 @@
+//! Сериализация `u128` десятичной строкой (feature `serde`).
+//!
+//! JSON-числа в JavaScript и многих парсерах — `f64`, и значения больше 2^53
+//! теряют точность, поэтому `u128` пишется как `"332825110087067562321196029789634457848"`,
+//! так же как в HTTP API. Подключается к полю через `#[serde(with = "crate::decimal")]`.
+
+use core::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserializer, Serializer};
+
+pub(crate) fn serialize<S: Serializer>(value: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+    deserializer.deserialize_str(DecimalVisitor)
+}
+
+struct DecimalVisitor;
+
+impl Visitor<'_> for DecimalVisitor {
+    type Value = u128;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("u128 десятичной строкой")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<u128, E> {
+        // `parse` принимает ведущий `+`, а строка должна быть каноничной.
+        if value.starts_with('+') {
+            return Err(E::invalid_value(de::Unexpected::Str(value), &self));
+        }
+        value
+            .parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "crate::decimal")] u128);
+
+    #[test]
+    fn given_u128_max_when_serialize_then_decimal_string() {
+        // Given
+        let value = Wrapper(u128::MAX);
+
+        // When
+        let json = serde_json::to_string(&value).unwrap();
+
+        // Then
+        assert_eq!(json, "\"340282366920938463463374607431768211455\"");
+        assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), value);
+    }
+
+    #[test]
+    fn given_invalid_strings_when_deserialize_then_error() {
+        // Given
+        let inputs = [
+            "42",
+            "\"-1\"",
+            "\"+1\"",
+            "\"1.5\"",
+            "\"\"",
+            "\"340282366920938463463374607431768211456\"",
+        ];
+
+        for input in inputs {
+            // When
+            let result = serde_json::from_str::<Wrapper>(input);
+
+            // Then
+            assert!(result.is_err(), "input={}", input);
+        }
+    }
+}
@@ -1,25 +1,76 @@
-use std::fmt;
+use core::fmt;
+use core::time::Duration;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::fibonacci::max_index;
 
 /// Ошибка вычисления числа Фибоначчи.
 ///
-/// Возникает, когда результат не помещается в `u128`.
+/// Возникает, когда результат не помещается в тип или модуль равен нулю.
+///
+/// С feature `serde` сериализуется с тегом `code`, равным [`ComputationError::code()`]:
+/// `{"code":"OVERFLOW","details":"187"}`.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "code", content = "details", rename_all = "SCREAMING_SNAKE_CASE")
+)]
 pub enum ComputationError {
     /// Переполнение при вычислении для заданного входного значения.
-    Overflow(u128),
+    Overflow(#[cfg_attr(feature = "serde", serde(with = "crate::decimal"))] u128),
+    /// Вычисление по модулю 0.
+    ZeroModulus,
+}
+
+impl ComputationError {
+    /// Машиночитаемый код ошибки для сетевых API, например `OVERFLOW`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ComputationError::Overflow(_) => "OVERFLOW",
+            ComputationError::ZeroModulus => "ZERO_MODULUS",
+        }
+    }
+
+    /// Двуязычное сообщение (RU + EN) для сетевых API и привязок: [`fmt::Display`]
+    /// выводит только русский текст. Переполнение описывается как
+    /// [`InputError::ComputationOverflow`] — с границей индекса для `u128`.
+    #[cfg(feature = "std")]
+    pub(crate) fn bilingual_message(&self) -> String {
+        match self {
+            ComputationError::Overflow(n) => InputError::ComputationOverflow(*n).to_string(),
+            ComputationError::ZeroModulus => {
+                "RU: Ошибка! Модуль должен быть больше нуля\nEN: Error! The modulus must be greater than zero"
+                    .to_string()
+            }
+        }
+    }
 }
 
 impl fmt::Display for ComputationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let ComputationError::Overflow(x) = self;
-        write!(f, "Переполнение при вычислении для входа {}", x)
+        match self {
+            ComputationError::Overflow(x) => {
+                write!(f, "Переполнение при вычислении для входа {}", x)
+            }
+            ComputationError::ZeroModulus => write!(f, "Модуль должен быть больше нуля"),
+        }
     }
 }
 
 /// Ошибка валидации пользовательского ввода.
 ///
 /// Каждый вариант выводится на двух языках (RU + EN) через [`fmt::Display`].
+/// С feature `serde` сериализуется с тегом `code`, равным [`InputError::code()`]:
+/// `{"code":"NEGATIVE_NUMBER"}`, `{"code":"COMPUTATION_OVERFLOW","details":"187"}`.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "code", content = "details", rename_all = "SCREAMING_SNAKE_CASE")
+)]
 pub enum InputError {
     /// Пользователь ничего не ввёл.
     EmptyInput,
@@ -34,42 +85,388 @@ pub enum InputError {
     /// Число не помещается в `u128`.
     NumberTooLarge,
     /// Переполнение при вычислении Фибоначчи для данного числа.
-    ComputationOverflow(u128),
+    ComputationOverflow(#[cfg_attr(feature = "serde", serde(with = "crate::decimal"))] u128),
+    /// Начало диапазона больше конца или диапазон слишком длинный.
+    InvalidRange {
+        /// Начало диапазона.
+        #[cfg_attr(feature = "serde", serde(with = "crate::decimal"))]
+        from: u128,
+        /// Конец диапазона (включительно).
+        #[cfg_attr(feature = "serde", serde(with = "crate::decimal"))]
+        to: u128,
+        /// Наибольшее количество значений в диапазоне.
+        #[cfg_attr(feature = "serde", serde(with = "crate::decimal"))]
+        max_len: u128,
+    },
+}
+
+impl InputError {
+    /// Машиночитаемый код ошибки для сетевых API, например `NEGATIVE_NUMBER`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            InputError::EmptyInput => "EMPTY_INPUT",
+            InputError::NegativeNumber => "NEGATIVE_NUMBER",
+            InputError::FloatNumber => "FLOAT_NUMBER",
+            InputError::InvalidCharacters => "INVALID_CHARACTERS",
+            InputError::MultipleValues => "MULTIPLE_VALUES",
+            InputError::NumberTooLarge => "NUMBER_TOO_LARGE",
+            InputError::ComputationOverflow(_) => "COMPUTATION_OVERFLOW",
+            InputError::InvalidRange { .. } => "INVALID_RANGE",
+        }
+    }
 }
 
 impl fmt::Display for InputError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (ru, en) = match self {
-            InputError::EmptyInput => ("Пустой ввод".to_string(), "Empty input".to_string()),
-            InputError::NegativeNumber => (
-                "Отрицательные числа не поддерживаются".to_string(),
-                "Negative numbers are not supported".to_string(),
+        match self {
+            InputError::EmptyInput => {
+                bilingual(f, format_args!("Пустой ввод"), format_args!("Empty input"))
+            }
+            InputError::NegativeNumber => bilingual(
+                f,
+                format_args!("Отрицательные числа не поддерживаются"),
+                format_args!("Negative numbers are not supported"),
             ),
-            InputError::FloatNumber => (
-                "Дробные числа не поддерживаются".to_string(),
-                "Floating point numbers are not supported".to_string(),
+            InputError::FloatNumber => bilingual(
+                f,
+                format_args!("Дробные числа не поддерживаются"),
+                format_args!("Floating point numbers are not supported"),
             ),
-            InputError::InvalidCharacters => (
-                "Ввод содержит нечисловые символы".to_string(),
-                "Input contains non-numeric characters".to_string(),
+            InputError::InvalidCharacters => bilingual(
+                f,
+                format_args!("Ввод содержит нечисловые символы"),
+                format_args!("Input contains non-numeric characters"),
             ),
-            InputError::MultipleValues => (
-                "Введите только одно число".to_string(),
-                "Enter only one number".to_string(),
+            InputError::MultipleValues => bilingual(
+                f,
+                format_args!("Введите только одно число"),
+                format_args!("Enter only one number"),
             ),
-            InputError::NumberTooLarge => (
-                "Число слишком большое".to_string(),
-                "Number is too large".to_string(),
+            InputError::NumberTooLarge => bilingual(
+                f,
+                format_args!("Число слишком большое"),
+                format_args!("Number is too large"),
             ),
-            InputError::ComputationOverflow(x) => (
-                format!("Переполнение при вычислении fibonacci({})", x),
-                format!("Overflow when computing fibonacci({})", x),
+            InputError::ComputationOverflow(x) => {
+                let limit = max_index::<u128>();
+                bilingual(
+                    f,
+                    format_args!(
+                        "Переполнение при вычислении fibonacci({}): наибольший индекс для u128 — {}. \
+                         Используйте --big для точного результата или --overflow wrapping \
+                         для значения по модулю 2^128",
+                        x, limit
+                    ),
+                    format_args!(
+                        "Overflow when computing fibonacci({}): the largest index that fits in u128 is {}. \
+                         Use --big for the exact result or --overflow wrapping \
+                         for the value modulo 2^128",
+                        x, limit
+                    ),
+                )
+            }
+            InputError::InvalidRange { from, to, max_len } => bilingual(
+                f,
+                format_args!(
+                    "Некорректный диапазон {}..={}: начало не должно превышать конец, \
+                     а значений должно быть не больше {}",
+                    from, to, max_len
+                ),
+                format_args!(
+                    "Invalid range {}..={}: the start must not exceed the end \
+                     and the range must hold at most {} values",
+                    from, to, max_len
+                ),
             ),
+        }
+    }
+}
+
+/// Ошибка разбора аргументов командной строки.
+///
+/// Как и [`InputError`], выводится на двух языках (RU + EN).
+#[cfg(feature = "alloc")]
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "code", content = "details", rename_all = "SCREAMING_SNAKE_CASE")
+)]
+pub enum ArgsError {
+    /// Неизвестный флаг или лишний аргумент.
+    UnknownArgument(String),
+    /// У флага не указано значение.
+    MissingValue(String),
+    /// Значение флага не входит в допустимый набор.
+    InvalidValue {
+        /// Флаг, например `--overflow`.
+        flag: String,
+        /// Переданное значение.
+        value: String,
+        /// Допустимые значения через `|`.
+        expected: String,
+    },
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgsError::UnknownArgument(arg) => bilingual(
+                f,
+                format_args!("Неизвестный аргумент '{}'", arg),
+                format_args!("Unknown argument '{}'", arg),
+            ),
+            ArgsError::MissingValue(flag) => bilingual(
+                f,
+                format_args!("Не указано значение для {}", flag),
+                format_args!("Missing value for {}", flag),
+            ),
+            ArgsError::InvalidValue {
+                flag,
+                value,
+                expected,
+            } => bilingual(
+                f,
+                format_args!(
+                    "Недопустимое значение '{}' для {} (ожидается {})",
+                    value, flag, expected
+                ),
+                format_args!(
+                    "Invalid value '{}' for {} (expected {})",
+                    value, flag, expected
+                ),
+            ),
+        }
+    }
+}
+
+/// Прогноз вычисления превышает бюджет, заданный в CLI.
+///
+/// Выводится на двух языках (RU + EN) через [`fmt::Display`].
+/// С feature `serde` сериализуется с тегом `code`, равным [`BudgetError::code()`].
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "code", content = "details"))]
+pub enum BudgetError {
+    /// Слишком много десятичных цифр в результате (`--max-digits`).
+    #[cfg_attr(feature = "serde", serde(rename = "BUDGET_DIGITS"))]
+    Digits {
+        /// Индекс числа Фибоначчи.
+        #[cfg_attr(feature = "serde", serde(with = "crate::decimal"))]
+        n: u128,
+        /// Прогноз количества цифр.
+        #[cfg_attr(feature = "serde", serde(with = "crate::decimal"))]
+        estimated: u128,
+        /// Лимит.
+        #[cfg_attr(feature = "serde", serde(with = "crate::decimal"))]
+        limit: u128,
+    },
+    /// Слишком много памяти (`--max-memory`).
+    #[cfg_attr(feature = "serde", serde(rename = "BUDGET_MEMORY"))]
+    Memory {
+        /// Индекс числа Фибоначчи.
+        #[cfg_attr(feature = "serde", serde(with = "crate::decimal"))]
+        n: u128,
+        /// Прогноз памяти, байт.
+        #[cfg_attr(feature = "serde", serde(with = "crate::decimal"))]
+        estimated: u128,
+        /// Лимит, байт.
+        #[cfg_attr(feature = "serde", serde(with = "crate::decimal"))]
+        limit: u128,
+    },
+    /// Слишком долго (`--max-time`).
+    #[cfg_attr(feature = "serde", serde(rename = "BUDGET_TIME"))]
+    Time {
+        /// Индекс числа Фибоначчи.
+        #[cfg_attr(feature = "serde", serde(with = "crate::decimal"))]
+        n: u128,
+        /// Прогноз времени.
+        estimated: Duration,
+        /// Лимит.
+        limit: Duration,
+    },
+}
+
+impl BudgetError {
+    /// Машиночитаемый код ошибки для сетевых API, например `BUDGET_DIGITS`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            BudgetError::Digits { .. } => "BUDGET_DIGITS",
+            BudgetError::Memory { .. } => "BUDGET_MEMORY",
+            BudgetError::Time { .. } => "BUDGET_TIME",
+        }
+    }
+}
+
+impl fmt::Display for BudgetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = match self {
+            BudgetError::Digits { n, .. }
+            | BudgetError::Memory { n, .. }
+            | BudgetError::Time { n, .. } => n,
         };
-        write!(f, "RU: Ошибка! {}\nEN: Error! {}", ru, en)
+        match self {
+            BudgetError::Digits {
+                estimated, limit, ..
+            } => bilingual(
+                f,
+                format_args!(
+                    "Вычисление fibonacci({}) превышает бюджет: ~{} цифр при лимите {}",
+                    n, estimated, limit
+                ),
+                format_args!(
+                    "Computing fibonacci({}) exceeds the budget: ~{} digits with a limit of {}",
+                    n, estimated, limit
+                ),
+            ),
+            BudgetError::Memory {
+                estimated, limit, ..
+            } => bilingual(
+                f,
+                format_args!(
+                    "Вычисление fibonacci({}) превышает бюджет: ~{} байт памяти при лимите {}",
+                    n, estimated, limit
+                ),
+                format_args!(
+                    "Computing fibonacci({}) exceeds the budget: ~{} bytes of memory with a limit of {}",
+                    n, estimated, limit
+                ),
+            ),
+            BudgetError::Time {
+                estimated, limit, ..
+            } => bilingual(
+                f,
+                format_args!(
+                    "Вычисление fibonacci({}) превышает бюджет: ~{:?} при лимите {:?}",
+                    n, estimated, limit
+                ),
+                format_args!(
+                    "Computing fibonacci({}) exceeds the budget: ~{:?} with a limit of {:?}",
+                    n, estimated, limit
+                ),
+            ),
+        }
+    }
+}
+
+/// Ошибка файла конфигурации или переменной окружения с настройкой.
+///
+/// `location` — место ошибки: `путь:строка` для файла или имя переменной окружения.
+/// Выводится на двух языках (RU + EN) через [`fmt::Display`].
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "code", content = "details"))]
+pub enum ConfigError {
+    /// Файл не удалось прочитать.
+    #[cfg_attr(feature = "serde", serde(rename = "CONFIG_READ"))]
+    Read {
+        /// Путь к файлу.
+        path: String,
+        /// Причина от операционной системы.
+        reason: String,
+    },
+    /// Строка файла не является `ключ = значение`, `[раздел]` или комментарием.
+    #[cfg_attr(feature = "serde", serde(rename = "CONFIG_SYNTAX"))]
+    Syntax {
+        /// Место ошибки.
+        location: String,
+        /// Текст строки.
+        text: String,
+    },
+    /// Неизвестный ключ.
+    #[cfg_attr(feature = "serde", serde(rename = "CONFIG_UNKNOWN_KEY"))]
+    UnknownKey {
+        /// Место ошибки.
+        location: String,
+        /// Ключ с разделом, например `budget.max_digit`.
+        key: String,
+    },
+    /// Значение не входит в допустимый набор.
+    #[cfg_attr(feature = "serde", serde(rename = "CONFIG_INVALID_VALUE"))]
+    InvalidValue {
+        /// Место ошибки.
+        location: String,
+        /// Ключ с разделом.
+        key: String,
+        /// Переданное значение.
+        value: String,
+        /// Допустимые значения.
+        expected: String,
+    },
+}
+
+#[cfg(feature = "std")]
+impl ConfigError {
+    /// Машиночитаемый код ошибки, например `CONFIG_UNKNOWN_KEY`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ConfigError::Read { .. } => "CONFIG_READ",
+            ConfigError::Syntax { .. } => "CONFIG_SYNTAX",
+            ConfigError::UnknownKey { .. } => "CONFIG_UNKNOWN_KEY",
+            ConfigError::InvalidValue { .. } => "CONFIG_INVALID_VALUE",
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Read { path, reason } => bilingual(
+                f,
+                format_args!(
+                    "Не удалось прочитать файл конфигурации {}: {}",
+                    path, reason
+                ),
+                format_args!("Cannot read config file {}: {}", path, reason),
+            ),
+            ConfigError::Syntax { location, text } => bilingual(
+                f,
+                format_args!(
+                    "Синтаксическая ошибка в {}: '{}' (ожидается ключ = значение или [раздел])",
+                    location, text
+                ),
+                format_args!(
+                    "Syntax error in {}: '{}' (expected key = value or [section])",
+                    location, text
+                ),
+            ),
+            ConfigError::UnknownKey { location, key } => bilingual(
+                f,
+                format_args!("Неизвестный ключ '{}' в {}", key, location),
+                format_args!("Unknown key '{}' in {}", key, location),
+            ),
+            ConfigError::InvalidValue {
+                location,
+                key,
+                value,
+                expected,
+            } => bilingual(
+                f,
+                format_args!(
+                    "Недопустимое значение '{}' для {} в {} (ожидается {})",
+                    value, key, location, expected
+                ),
+                format_args!(
+                    "Invalid value '{}' for {} in {} (expected {})",
+                    value, key, location, expected
+                ),
+            ),
+        }
     }
 }
 
+/// Пишет двуязычное сообщение `RU: Ошибка! ...\nEN: Error! ...` без выделения памяти.
+fn bilingual(
+    f: &mut fmt::Formatter<'_>,
+    ru: fmt::Arguments<'_>,
+    en: fmt::Arguments<'_>,
+) -> fmt::Result {
+    write!(f, "RU: Ошибка! {}\nEN: Error! {}", ru, en)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,6 +562,21 @@ mod tests {
         assert!(message.contains("EN: Error! Overflow when computing fibonacci(187)"));
     }
 
+    #[test]
+    fn given_overflow_when_display_then_shows_limit_and_alternatives() {
+        // Given
+        let error = InputError::ComputationOverflow(187);
+
+        // When
+        let message = format!("{}", error);
+
+        // Then
+        assert!(message.contains("наибольший индекс для u128 — 186"));
+        assert!(message.contains("the largest index that fits in u128 is 186"));
+        assert!(message.contains("--big"));
+        assert!(message.contains("--overflow wrapping"));
+    }
+
     #[test]
     fn given_computation_overflow_when_display_then_shows_message() {
         let err = ComputationError::Overflow(187);
@@ -173,4 +585,272 @@ mod tests {
             "Переполнение при вычислении для входа 187"
         );
     }
+
+    #[test]
+    fn given_zero_modulus_when_display_then_shows_message() {
+        // Given
+        let error = ComputationError::ZeroModulus;
+
+        // When
+        let message = format!("{}", error);
+
+        // Then
+        assert_eq!(message, "Модуль должен быть больше нуля");
+    }
+
+    #[test]
+    fn given_invalid_range_when_display_then_shows_ru_and_en() {
+        // Given
+        let error = InputError::InvalidRange {
+            from: 10,
+            to: 5,
+            max_len: 1000,
+        };
+
+        // When
+        let message = format!("{}", error);
+
+        // Then
+        assert!(message.contains("RU: Ошибка! Некорректный диапазон 10..=5"));
+        assert!(message.contains("EN: Error! Invalid range 10..=5"));
+        assert!(message.contains("1000"));
+    }
+
+    #[test]
+    fn given_errors_when_code_then_returns_stable_identifiers() {
+        // Given / When / Then
+        assert_eq!(InputError::NegativeNumber.code(), "NEGATIVE_NUMBER");
+        assert_eq!(
+            InputError::ComputationOverflow(187).code(),
+            "COMPUTATION_OVERFLOW"
+        );
+        assert_eq!(ComputationError::Overflow(187).code(), "OVERFLOW");
+        assert_eq!(ComputationError::ZeroModulus.code(), "ZERO_MODULUS");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn given_unknown_argument_when_display_then_shows_ru_and_en() {
+        // Given
+        let error = ArgsError::UnknownArgument("--foo".to_string());
+
+        // When
+        let message = format!("{}", error);
+
+        // Then
+        assert!(message.contains("RU: Ошибка! Неизвестный аргумент '--foo'"));
+        assert!(message.contains("EN: Error! Unknown argument '--foo'"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn given_invalid_value_when_display_then_shows_expected_values() {
+        // Given
+        let error = ArgsError::InvalidValue {
+            flag: "--overflow".to_string(),
+            value: "modular".to_string(),
+            expected: "checked|wrapping|saturating".to_string(),
+        };
+
+        // When
+        let message = format!("{}", error);
+
+        // Then
+        assert!(message.contains(
+            "RU: Ошибка! Недопустимое значение 'modular' для --overflow (ожидается checked|wrapping|saturating)"
+        ));
+        assert!(message.contains(
+            "EN: Error! Invalid value 'modular' for --overflow (expected checked|wrapping|saturating)"
+        ));
+    }
+
+    #[test]
+    fn given_memory_budget_error_when_display_then_shows_ru_and_en() {
+        // Given
+        let error = BudgetError::Memory {
+            n: 1_000_000_000,
+            estimated: 700_000_000,
+            limit: 1_000,
+        };
+
+        // When
+        let message = format!("{}", error);
+
+        // Then
+        assert!(message.contains(
+            "RU: Ошибка! Вычисление fibonacci(1000000000) превышает бюджет: ~700000000 байт памяти при лимите 1000"
+        ));
+        assert!(message.contains(
+            "EN: Error! Computing fibonacci(1000000000) exceeds the budget: ~700000000 bytes of memory with a limit of 1000"
+        ));
+    }
+
+    #[test]
+    fn given_time_budget_error_when_display_then_shows_durations() {
+        // Given
+        let error = BudgetError::Time {
+            n: 10,
+            estimated: Duration::from_secs(5),
+            limit: Duration::from_secs(1),
+        };
+
+        // When
+        let message = format!("{}", error);
+
+        // Then
+        assert!(message.contains("~5s при лимите 1s"));
+        assert!(message.contains("~5s with a limit of 1s"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn given_config_errors_when_display_then_shows_ru_en_and_location() {
+        // Given
+        let unknown = ConfigError::UnknownKey {
+            location: "fibonacci_stable.toml:3".to_string(),
+            key: "budget.max_digit".to_string(),
+        };
+        let invalid = ConfigError::InvalidValue {
+            location: "FIBONACCI_STABLE_THREADS".to_string(),
+            key: "threads".to_string(),
+            value: "0".to_string(),
+            expected: "<threads ≥ 1>".to_string(),
+        };
+
+        // When
+        let unknown = unknown.to_string();
+        let invalid = invalid.to_string();
+
+        // Then
+        assert_eq!(
+            unknown,
+            "RU: Ошибка! Неизвестный ключ 'budget.max_digit' в fibonacci_stable.toml:3\n\
+             EN: Error! Unknown key 'budget.max_digit' in fibonacci_stable.toml:3"
+        );
+        assert!(invalid.contains(
+            "RU: Ошибка! Недопустимое значение '0' для threads в FIBONACCI_STABLE_THREADS"
+        ));
+        assert!(invalid.contains("EN: Error! Invalid value '0' for threads"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn given_input_errors_when_serialize_then_tag_matches_code() {
+        // Given
+        let errors = [
+            InputError::EmptyInput,
+            InputError::NegativeNumber,
+            InputError::FloatNumber,
+            InputError::InvalidCharacters,
+            InputError::MultipleValues,
+            InputError::NumberTooLarge,
+            InputError::ComputationOverflow(u128::MAX),
+            InputError::InvalidRange {
+                from: 10,
+                to: 5,
+                max_len: 1000,
+            },
+        ];
+
+        for error in errors {
+            // When
+            let json = serde_json::to_value(&error).unwrap();
+
+            // Then
+            assert_eq!(json["code"], error.code());
+            assert_eq!(serde_json::from_value::<InputError>(json).unwrap(), error);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn given_errors_with_numbers_when_serialize_then_numbers_are_decimal_strings() {
+        // Given
+        let overflow = InputError::ComputationOverflow(187);
+        let range = InputError::InvalidRange {
+            from: 10,
+            to: 5,
+            max_len: 1000,
+        };
+        let zero = ComputationError::ZeroModulus;
+
+        // When / Then
+        assert_eq!(
+            serde_json::to_string(&overflow).unwrap(),
+            r#"{"code":"COMPUTATION_OVERFLOW","details":"187"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&range).unwrap(),
+            r#"{"code":"INVALID_RANGE","details":{"from":"10","to":"5","max_len":"1000"}}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&zero).unwrap(),
+            r#"{"code":"ZERO_MODULUS"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<ComputationError>(r#"{"code":"OVERFLOW","details":"187"}"#)
+                .unwrap(),
+            ComputationError::Overflow(187)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn given_budget_errors_when_serialize_then_tag_matches_code() {
+        // Given
+        let errors = [
+            BudgetError::Digits {
+                n: 10,
+                estimated: 3,
+                limit: 2,
+            },
+            BudgetError::Memory {
+                n: 10,
+                estimated: 64,
+                limit: 32,
+            },
+            BudgetError::Time {
+                n: 10,
+                estimated: Duration::from_millis(1500),
+                limit: Duration::from_secs(1),
+            },
+        ];
+
+        for error in errors {
+            // When
+            let json = serde_json::to_value(&error).unwrap();
+
+            // Then
+            assert_eq!(json["code"], error.code());
+            assert_eq!(json["details"]["n"], "10");
+            assert_eq!(serde_json::from_value::<BudgetError>(json).unwrap(), error);
+        }
+    }
+
+    #[cfg(all(feature = "serde", feature = "alloc"))]
+    #[test]
+    fn given_args_error_when_serialize_then_tagged_by_variant() {
+        // Given
+        let error = ArgsError::MissingValue("--overflow".to_string());
+
+        // When
+        let json = serde_json::to_string(&error).unwrap();
+
+        // Then
+        assert_eq!(json, r#"{"code":"MISSING_VALUE","details":"--overflow"}"#);
+        assert_eq!(serde_json::from_str::<ArgsError>(&json).unwrap(), error);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn given_unknown_code_when_deserialize_then_error() {
+        // Given
+        let json = r#"{"code":"NOT_A_CODE"}"#;
+
+        // When
+        let result = serde_json::from_str::<InputError>(json);
+
+        // Then
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,256 @@
+//! C ABI для вызова библиотеки из C, C++, Go и других языков.
+//!
+//! Библиотека собирается как `cdylib` (`libfibonacci_stable.so`) и `staticlib`
+//! (`libfibonacci_stable.a`) командой `cargo rustc --lib --crate-type <тип>`;
+//! объявления — в `include/fibonacci_stable.h`, сгенерированном cbindgen из этого модуля.
+//!
+//! Все функции возвращают [`FibStatus`]: `FIB_STATUS_OK` или код ошибки,
+//! соответствующий варианту [`InputError`] или [`ComputationError`]. Результат
+//! записывается по указателю `out` только при `FIB_STATUS_OK`. Числа `u128`
+//! передаются структурой [`FibU128`] из двух 64-битных половин, потому что
+//! `__int128` есть не во всех компиляторах и языках.
+//!
+//! ```c
+//! #include "fibonacci_stable.h"
+//!
+//! FibU128 n = {.lo = 42, .hi = 0}, value;
+//! if (fib_u128(n, &value) == FIB_STATUS_OK) {
+//!     printf("%llu\n", (unsigned long long)value.lo);  // 267914296
+//! }
+//! ```
+
+use std::ffi::{CStr, c_char};
+
+use crate::errors::{ComputationError, InputError};
+use crate::fibonacci::fibonacci;
+use crate::io::parse_input;
+use crate::modular::fibonacci_mod;
+
+/// Беззнаковое 128-битное число: `lo` — младшие 64 бита, `hi` — старшие.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FibU128 {
+    /// Младшие 64 бита.
+    pub lo: u64,
+    /// Старшие 64 бита.
+    pub hi: u64,
+}
+
+impl From<u128> for FibU128 {
+    fn from(value: u128) -> Self {
+        FibU128 {
+            lo: value as u64,
+            hi: (value >> 64) as u64,
+        }
+    }
+}
+
+impl From<FibU128> for u128 {
+    fn from(value: FibU128) -> Self {
+        (value.hi as u128) << 64 | value.lo as u128
+    }
+}
+
+/// Код результата функции C ABI.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FibStatus {
+    /// Успех, результат записан в `out`.
+    Ok = 0,
+    /// [`InputError::EmptyInput`].
+    EmptyInput = 1,
+    /// [`InputError::NegativeNumber`].
+    NegativeNumber = 2,
+    /// [`InputError::FloatNumber`].
+    FloatNumber = 3,
+    /// [`InputError::InvalidCharacters`], в том числе строка не в UTF-8.
+    InvalidCharacters = 4,
+    /// [`InputError::MultipleValues`].
+    MultipleValues = 5,
+    /// [`InputError::NumberTooLarge`].
+    NumberTooLarge = 6,
+    /// [`InputError::ComputationOverflow`] и [`ComputationError::Overflow`].
+    Overflow = 7,
+    /// [`InputError::InvalidRange`].
+    InvalidRange = 8,
+    /// [`ComputationError::ZeroModulus`].
+    ZeroModulus = 9,
+    /// Передан нулевой указатель.
+    NullPointer = 10,
+}
+
+impl From<InputError> for FibStatus {
+    fn from(error: InputError) -> Self {
+        match error {
+            InputError::EmptyInput => FibStatus::EmptyInput,
+            InputError::NegativeNumber => FibStatus::NegativeNumber,
+            InputError::FloatNumber => FibStatus::FloatNumber,
+            InputError::InvalidCharacters => FibStatus::InvalidCharacters,
+            InputError::MultipleValues => FibStatus::MultipleValues,
+            InputError::NumberTooLarge => FibStatus::NumberTooLarge,
+            InputError::ComputationOverflow(_) => FibStatus::Overflow,
+            InputError::InvalidRange { .. } => FibStatus::InvalidRange,
+        }
+    }
+}
+
+impl From<ComputationError> for FibStatus {
+    fn from(error: ComputationError) -> Self {
+        match error {
+            ComputationError::Overflow(_) => FibStatus::Overflow,
+            ComputationError::ZeroModulus => FibStatus::ZeroModulus,
+        }
+    }
+}
+
+/// Записывает результат по указателю и возвращает код.
+///
+/// # Safety
+///
+/// `out` — нулевой указатель или указатель, доступный для записи `T`.
+unsafe fn write_out<T, E: Into<FibStatus>>(result: Result<T, E>, out: *mut T) -> FibStatus {
+    if out.is_null() {
+        return FibStatus::NullPointer;
+    }
+    match result {
+        Ok(value) => {
+            // SAFETY: `out` не нулевой и доступен для записи по контракту вызывающего.
+            unsafe { out.write(value) };
+            FibStatus::Ok
+        }
+        Err(e) => e.into(),
+    }
+}
+
+/// Вычисляет fib(n) в `u128`.
+///
+/// Возвращает `FIB_STATUS_OVERFLOW` для n > 186.
+///
+/// # Safety
+///
+/// `out` — нулевой указатель (тогда возвращается `FIB_STATUS_NULL_POINTER`)
+/// или указатель, доступный для записи `FibU128`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fib_u128(n: FibU128, out: *mut FibU128) -> FibStatus {
+    let result = fibonacci(n.into()).map(FibU128::from);
+    // SAFETY: контракт `out` совпадает с контрактом этой функции.
+    unsafe { write_out(result, out) }
+}
+
+/// Вычисляет fib(n) mod m для любых `n` и `m`.
+///
+/// Возвращает `FIB_STATUS_ZERO_MODULUS` при `m == 0`.
+///
+/// # Safety
+///
+/// `out` — нулевой указатель (тогда возвращается `FIB_STATUS_NULL_POINTER`)
+/// или указатель, доступный для записи `FibU128`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fib_mod(n: FibU128, m: FibU128, out: *mut FibU128) -> FibStatus {
+    let result = fibonacci_mod(n.into(), m.into()).map(FibU128::from);
+    // SAFETY: контракт `out` совпадает с контрактом этой функции.
+    unsafe { write_out(result, out) }
+}
+
+/// Проверяет строку так же, как [`parse_input()`], и записывает число в `out`.
+///
+/// # Safety
+///
+/// `input` — нулевой указатель или указатель на строку, завершённую нулём;
+/// `out` — нулевой указатель или указатель, доступный для записи `FibU128`.
+/// При нулевом указателе возвращается `FIB_STATUS_NULL_POINTER`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fib_parse(input: *const c_char, out: *mut FibU128) -> FibStatus {
+    if input.is_null() {
+        return FibStatus::NullPointer;
+    }
+    // SAFETY: `input` не нулевой и завершён нулём по контракту вызывающего.
+    let input = unsafe { CStr::from_ptr(input) };
+    let result = match input.to_str() {
+        Ok(text) => parse_input(text).map(FibU128::from),
+        Err(_) => Err(InputError::InvalidCharacters),
+    };
+    // SAFETY: контракт `out` совпадает с контрактом этой функции.
+    unsafe { write_out(result, out) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    #[test]
+    fn given_u128_when_round_trip_through_fib_u128_struct_then_unchanged() {
+        // Given
+        let values = [0u128, 1, u64::MAX as u128, u64::MAX as u128 + 1, u128::MAX];
+
+        // When / Then
+        for value in values {
+            assert_eq!(u128::from(FibU128::from(value)), value);
+        }
+    }
+
+    #[test]
+    fn given_valid_and_overflowing_index_when_fib_u128_then_status_matches() {
+        // Given
+        let mut out = FibU128::default();
+
+        // When
+        let ok = unsafe { fib_u128(186u128.into(), &mut out) };
+        let overflow = unsafe { fib_u128(187u128.into(), &mut out) };
+
+        // Then
+        assert_eq!(ok, FibStatus::Ok);
+        assert_eq!(u128::from(out), fibonacci(186).unwrap());
+        assert_eq!(overflow, FibStatus::Overflow);
+    }
+
+    #[test]
+    fn given_null_pointers_when_called_then_returns_null_pointer() {
+        // Given
+        let mut out = FibU128::default();
+
+        // When / Then
+        assert_eq!(
+            unsafe { fib_u128(1u128.into(), ptr::null_mut()) },
+            FibStatus::NullPointer
+        );
+        assert_eq!(
+            unsafe { fib_parse(ptr::null(), &mut out) },
+            FibStatus::NullPointer
+        );
+    }
+
+    #[test]
+    fn given_invalid_strings_when_fib_parse_then_maps_input_errors() {
+        // Given
+        let cases: [(&CStr, FibStatus); 5] = [
+            (c"42", FibStatus::Ok),
+            (c"-5", FibStatus::NegativeNumber),
+            (c"3.14", FibStatus::FloatNumber),
+            (c"", FibStatus::EmptyInput),
+            (c"\xff", FibStatus::InvalidCharacters),
+        ];
+
+        for (input, expected) in cases {
+            // When
+            let mut out = FibU128::default();
+            let status = unsafe { fib_parse(input.as_ptr(), &mut out) };
+
+            // Then
+            assert_eq!(status, expected, "input={:?}", input);
+        }
+    }
+
+    #[test]
+    fn given_zero_modulus_when_fib_mod_then_returns_zero_modulus() {
+        // Given
+        let mut out = FibU128::default();
+
+        // When
+        let status = unsafe { fib_mod(10u128.into(), 0u128.into(), &mut out) };
+
+        // Then
+        assert_eq!(status, FibStatus::ZeroModulus);
+    }
+}
@@ -1,4 +1,21 @@
+use core::fmt;
+use core::num::{Saturating, Wrapping};
+#[cfg(feature = "alloc")]
+use core::ops::RangeInclusive;
+#[cfg(feature = "std")]
+use std::hint::black_box;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::bigint::BigUint;
 use crate::errors::ComputationError;
+use crate::num::FibNum;
 
 /// Вычисляет число Фибоначчи для заданного индекса `x`.
 ///
@@ -35,6 +52,481 @@ pub fn fibonacci(x: u128) -> Result<u128, ComputationError> {
         .ok_or(ComputationError::Overflow(x))
 }
 
+/// Политика обработки переполнения `u128`.
+///
+/// | Политика | fib(186) | fib(187) | fib(300) |
+/// |---|---|---|---|
+/// | `Checked` | точное значение | `Err(Overflow(187))` | `Err(Overflow(300))` |
+/// | `Wrapping` | точное значение | fib(187) mod 2^128 | fib(300) mod 2^128 |
+/// | `Saturating` | точное значение | `u128::MAX` | `u128::MAX` |
+///
+/// До fib(186) включительно все политики дают одинаковый результат.
+/// С feature `serde` сериализуется именем из CLI: `"checked"`, `"wrapping"`, `"saturating"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum OverflowPolicy {
+    /// Ошибка [`ComputationError::Overflow`] при переполнении (по умолчанию).
+    #[default]
+    Checked,
+    /// Результат по модулю 2^128.
+    Wrapping,
+    /// Результат ограничивается `u128::MAX`.
+    Saturating,
+}
+
+impl OverflowPolicy {
+    /// Все политики в порядке объявления.
+    pub const ALL: [OverflowPolicy; 3] = [
+        OverflowPolicy::Checked,
+        OverflowPolicy::Wrapping,
+        OverflowPolicy::Saturating,
+    ];
+
+    /// Имя политики, как оно задаётся в CLI (`--overflow <имя>`).
+    pub fn name(self) -> &'static str {
+        match self {
+            OverflowPolicy::Checked => "checked",
+            OverflowPolicy::Wrapping => "wrapping",
+            OverflowPolicy::Saturating => "saturating",
+        }
+    }
+
+    /// Ищет политику по имени из CLI.
+    ///
+    /// ```
+    /// use fibonacci_stable::OverflowPolicy;
+    ///
+    /// assert_eq!(OverflowPolicy::from_name("wrapping"), Some(OverflowPolicy::Wrapping));
+    /// assert_eq!(OverflowPolicy::from_name("modular"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|policy| policy.name() == name)
+    }
+}
+
+impl fmt::Display for OverflowPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Вычисляет число Фибоначчи для индекса `x` с заданной политикой переполнения.
+///
+/// Для [`OverflowPolicy::Checked`] совпадает с [`fibonacci()`]. Политики
+/// `Wrapping` и `Saturating` никогда не возвращают ошибку и работают за `O(log x)`,
+/// поэтому принимают любой индекс `u128`.
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::{OverflowPolicy, fibonacci_with_policy};
+///
+/// assert!(fibonacci_with_policy(187, OverflowPolicy::Checked).is_err());
+/// assert_eq!(
+///     fibonacci_with_policy(187, OverflowPolicy::Wrapping),
+///     Ok(198239973509362327032045173661212819077)
+/// );
+/// assert_eq!(fibonacci_with_policy(187, OverflowPolicy::Saturating), Ok(u128::MAX));
+/// ```
+pub fn fibonacci_with_policy(x: u128, policy: OverflowPolicy) -> Result<u128, ComputationError> {
+    match policy {
+        OverflowPolicy::Checked => fibonacci(x),
+        OverflowPolicy::Wrapping => fibonacci_in::<Wrapping<u128>>(x).map(|v| v.0),
+        OverflowPolicy::Saturating => fibonacci_in::<Saturating<u128>>(x).map(|v| v.0),
+    }
+}
+
+/// Вычисляет число Фибоначчи для индекса `x` в произвольном числовом типе `T`.
+///
+/// Переполнение определяется для самого типа `T`, а не для `u128`: например,
+/// `fibonacci_in::<u32>(48)` — ошибка, хотя fib(48) помещается в `u128`.
+/// Семантика переполнения для [`Wrapping`](core::num::Wrapping) и
+/// [`Saturating`](core::num::Saturating) описана в [`FibNum`].
+///
+/// # Примеры
+///
+/// ```
+/// use std::num::{Saturating, Wrapping};
+/// use fibonacci_stable::fibonacci_in;
+///
+/// assert_eq!(fibonacci_in::<u32>(47), Ok(2971215073));
+/// assert!(fibonacci_in::<u32>(48).is_err());
+/// assert_eq!(fibonacci_in::<Wrapping<u8>>(14), Ok(Wrapping(121)));
+/// assert_eq!(fibonacci_in::<Saturating<u8>>(14), Ok(Saturating(255)));
+/// ```
+pub fn fibonacci_in<T: FibNum>(x: u128) -> Result<T, ComputationError> {
+    T::fibonacci_of(x)
+}
+
+/// Наибольший индекс, для которого число Фибоначчи помещается в тип `T`.
+///
+/// Для типов без переполнения ([`Wrapping`](core::num::Wrapping),
+/// [`Saturating`](core::num::Saturating)) возвращает `u128::MAX`.
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::max_index;
+///
+/// assert_eq!(max_index::<u128>(), 186);
+/// assert_eq!(max_index::<u64>(), 93);
+/// assert_eq!(max_index::<u32>(), 47);
+/// ```
+pub fn max_index<T: FibNum>() -> u128 {
+    T::max_index()
+}
+
+/// Точно вычисляет число Фибоначчи для любого индекса с помощью [`BigUint`].
+///
+/// Не переполняется, но размер результата растёт линейно: fib(x) занимает
+/// около `0.694 · x` бит.
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::fibonacci_big;
+///
+/// assert_eq!(
+///     fibonacci_big(187).to_string(),
+///     "538522340430300790495419781092981030533"
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn fibonacci_big(x: u128) -> BigUint {
+    fibonacci_big_by(x, |a, diff, b| (a * diff, a * a, b * b))
+}
+
+/// Быстрое удвоение в [`BigUint`] с подставляемым способом умножения.
+///
+/// `products(a, d, b)` возвращает `(a·d, a², b²)` — три независимых произведения
+/// одного шага, которые можно считать параллельно.
+#[cfg(feature = "alloc")]
+pub(crate) fn fibonacci_big_by(
+    x: u128,
+    products: impl Fn(&BigUint, &BigUint, &BigUint) -> (BigUint, BigUint, BigUint),
+) -> BigUint {
+    let bits = u128::BITS - x.leading_zeros();
+
+    // (a, b) = (fib(k), fib(k+1)), где k — старшие биты x.
+    let (mut a, mut b) = (BigUint::zero(), BigUint::one());
+    for i in (0..bits).rev() {
+        let diff = (&b + &b)
+            .checked_sub(&a)
+            .expect("2·fib(k+1) ≥ fib(k) для любого k");
+        let (even, a2, b2) = products(&a, &diff, &b);
+        let odd = &a2 + &b2;
+
+        (a, b) = if (x >> i) & 1 == 1 {
+            let next = &even + &odd;
+            (odd, next)
+        } else {
+            (even, odd)
+        };
+    }
+    a
+}
+
+/// Количество чисел Фибоначчи, помещающихся в `u128`: fib(0) ..= fib(186).
+pub const FIBONACCI_TABLE_LEN: usize = 187;
+
+/// Таблица всех чисел Фибоначчи, помещающихся в `u128`, вычисленная на этапе компиляции.
+///
+/// `FIBONACCI_TABLE[n] == fibonacci(n)` для всех `n` от 0 до 186.
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::fibonacci::FIBONACCI_TABLE;
+///
+/// assert_eq!(FIBONACCI_TABLE[10], 55);
+/// assert_eq!(FIBONACCI_TABLE.len(), 187);
+/// ```
+pub const FIBONACCI_TABLE: [u128; FIBONACCI_TABLE_LEN] = {
+    let mut table = [0u128; FIBONACCI_TABLE_LEN];
+    let mut i = 1;
+    table[1] = 1;
+    while i + 1 < FIBONACCI_TABLE_LEN {
+        table[i + 1] = table[i] + table[i - 1];
+        i += 1;
+    }
+    table
+};
+
+/// Вычисляет число Фибоначчи на этапе компиляции.
+///
+/// `const`-аналог [`fibonacci()`] с тем же поведением при переполнении:
+/// возвращает `None`, если результат не помещается в `u128` (начиная с fib(187)).
+///
+/// # Аргументы
+///
+/// * `n` — индекс числа Фибоначчи (0-based)
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::fibonacci_const;
+///
+/// const FIB_10: u128 = match fibonacci_const(10) {
+///     Some(v) => v,
+///     None => panic!("переполнение"),
+/// };
+/// let buffer = [0u8; FIB_10 as usize];
+///
+/// assert_eq!(buffer.len(), 55);
+/// assert_eq!(fibonacci_const(186), Some(332825110087067562321196029789634457848));
+/// assert_eq!(fibonacci_const(187), None);
+/// ```
+pub const fn fibonacci_const(n: u32) -> Option<u128> {
+    if n == 0 {
+        return Some(0);
+    }
+    let (mut a, mut b) = (0u128, 1u128);
+    let mut i = 1;
+    while i < n {
+        let sum = match b.checked_add(a) {
+            Some(sum) => sum,
+            None => return None,
+        };
+        a = b;
+        b = sum;
+        i += 1;
+    }
+    Some(b)
+}
+
+/// Вычисляет числа Фибоначчи для всех индексов диапазона с политикой переполнения.
+///
+/// # Ошибки
+///
+/// Первая ошибка вычисления, например `Err(ComputationError::Overflow(187))`
+/// для диапазона, выходящего за 186 с [`OverflowPolicy::Checked`].
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::OverflowPolicy;
+/// use fibonacci_stable::fibonacci::fibonacci_range;
+///
+/// assert_eq!(fibonacci_range(5..=8, OverflowPolicy::Checked), Ok(vec![5, 8, 13, 21]));
+/// assert!(fibonacci_range(185..=188, OverflowPolicy::Checked).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn fibonacci_range(
+    range: RangeInclusive<u128>,
+    policy: OverflowPolicy,
+) -> Result<Vec<u128>, ComputationError> {
+    range.map(|n| fibonacci_with_policy(n, policy)).collect()
+}
+
+/// Находит индекс числа Фибоначчи, равного `value`.
+///
+/// Для 1 = fib(1) = fib(2) возвращается меньший индекс.
+///
+/// # Возвращает
+///
+/// * `Some(n)` — если `fibonacci(n) == value`
+/// * `None` — если `value` не является числом Фибоначчи
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::fibonacci::fibonacci_index_of;
+///
+/// assert_eq!(fibonacci_index_of(55), Some(10));
+/// assert_eq!(fibonacci_index_of(1), Some(1));
+/// assert_eq!(fibonacci_index_of(4), None);
+/// ```
+pub fn fibonacci_index_of(value: u128) -> Option<u128> {
+    let index = FIBONACCI_TABLE.partition_point(|&v| v < value);
+    (FIBONACCI_TABLE.get(index) == Some(&value)).then_some(index as u128)
+}
+
+/// Способ хранения результата, для которого оценивается стоимость вычисления.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Backend {
+    /// [`fibonacci_with_policy()`] — результат в `u128`.
+    U128,
+    /// [`fibonacci_big()`] — точный результат в [`BigUint`].
+    Big,
+    /// [`approx::fibonacci_approx()`](crate::approx::fibonacci_approx) — первые цифры
+    /// и порядок через логарифм φ.
+    Approx,
+    /// [`modular::fibonacci_last_digits()`](crate::modular::fibonacci_last_digits) —
+    /// последние цифры по модулю степени 10.
+    LastDigits,
+}
+
+/// Прогноз размера fib(n) и стоимости его вычисления.
+///
+/// Размеры — точные для n ≤ 186 и оценка через log(φ) для больших n.
+/// Память и время — оценки порядка величины, а не гарантии.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Estimate {
+    /// Длина fib(n) в битах.
+    #[cfg_attr(feature = "serde", serde(with = "crate::decimal"))]
+    pub bits: u128,
+    /// Количество десятичных цифр fib(n).
+    #[cfg_attr(feature = "serde", serde(with = "crate::decimal"))]
+    pub decimal_digits: u128,
+    /// Пиковая память на вычисление и десятичный вывод, байт.
+    #[cfg_attr(feature = "serde", serde(with = "crate::decimal"))]
+    pub memory_bytes: u128,
+    /// Ожидаемое время вычисления и десятичного вывода.
+    pub time: Duration,
+}
+
+/// Коэффициенты модели времени, измеренные микробенчмарком на текущей машине.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Calibration {
+    /// Наносекунд на один шаг итерации в `u128`.
+    pub nanos_per_u128_step: f64,
+    /// Наносекунд на квадрат числа 64-битных разрядов результата в [`BigUint`]:
+    /// десятичный вывод растёт как `O(limbs²)` и на больших числах дороже
+    /// умножений Карацубы.
+    pub nanos_per_limb_squared: f64,
+}
+
+/// Индекс для калибровки `BigUint`: около 200 разрядов, единицы миллисекунд.
+#[cfg(feature = "std")]
+const CALIBRATION_INDEX: u128 = 20_000;
+#[cfg(feature = "std")]
+const CALIBRATION_ROUNDS: u32 = 3;
+
+#[cfg(feature = "std")]
+impl Calibration {
+    /// Калибровка без замера времени: прогноз содержит только размер и память,
+    /// `time` всегда нулевой. Подходит для проверки лимитов цифр и памяти.
+    pub const SIZE_ONLY: Calibration = Calibration {
+        nanos_per_u128_step: 0.0,
+        nanos_per_limb_squared: 0.0,
+    };
+
+    /// Измеряет коэффициенты, вычисляя fib(186) в `u128` и fib(20000) в [`BigUint`].
+    ///
+    /// Занимает несколько десятков миллисекунд.
+    pub fn measure() -> Self {
+        let started = Instant::now();
+        for _ in 0..CALIBRATION_ROUNDS * 1000 {
+            let _ = black_box(fibonacci(black_box(186)));
+        }
+        let nanos_per_u128_step =
+            started.elapsed().as_nanos() as f64 / (CALIBRATION_ROUNDS as f64 * 1000.0 * 186.0);
+
+        let started = Instant::now();
+        for _ in 0..CALIBRATION_ROUNDS {
+            black_box(fibonacci_big(black_box(CALIBRATION_INDEX)).to_string());
+        }
+        let limbs = limbs_for_bits(bit_length(CALIBRATION_INDEX));
+        let nanos_per_limb_squared =
+            started.elapsed().as_nanos() as f64 / (CALIBRATION_ROUNDS as f64 * limbs * limbs);
+
+        Calibration {
+            nanos_per_u128_step,
+            nanos_per_limb_squared,
+        }
+    }
+}
+
+/// Прогнозирует размер fib(n) и стоимость его вычисления на выбранном бэкенде.
+///
+/// При первом вызове один раз запускает [`Calibration::measure()`];
+/// для воспроизводимых прогнозов используйте [`estimate_with()`].
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::fibonacci::{Backend, estimate};
+///
+/// let e = estimate(1000, Backend::Big);
+/// assert_eq!(e.bits, 694);
+/// assert_eq!(e.decimal_digits, 209);
+/// ```
+#[cfg(feature = "std")]
+pub fn estimate(n: u128, backend: Backend) -> Estimate {
+    static CALIBRATION: OnceLock<Calibration> = OnceLock::new();
+    estimate_with(n, backend, CALIBRATION.get_or_init(Calibration::measure))
+}
+
+/// То же, что [`estimate()`], но с заданными коэффициентами времени.
+#[cfg(feature = "std")]
+pub fn estimate_with(n: u128, backend: Backend, calibration: &Calibration) -> Estimate {
+    let bits = bit_length(n);
+    let decimal_digits = decimal_digits(n);
+
+    let (memory_bytes, nanos) = match backend {
+        // Пара (a, b) на стеке; итераций не больше 187 — дальше переполнение или O(log n).
+        Backend::U128 => (
+            2 * 16,
+            calibration.nanos_per_u128_step * n.min(FIBONACCI_TABLE_LEN as u128) as f64,
+        ),
+        // Несколько чисел с фиксированной точкой и двоичный поиск по первым цифрам:
+        // стоимость не зависит от n.
+        Backend::Approx => (
+            16 * 16,
+            calibration.nanos_per_u128_step * FIBONACCI_TABLE_LEN as f64,
+        ),
+        // Быстрое удвоение по модулю 10^k: O(log n) умножений, после сокращения
+        // по периоду Пизано n < 2^128.
+        Backend::LastDigits => (4 * 16, calibration.nanos_per_u128_step * u128::BITS as f64),
+        // Быстрое удвоение держит около восьми чисел размера результата,
+        // плюс десятичная строка.
+        Backend::Big => {
+            let limbs = limbs_for_bits(bits);
+            (
+                (8.0 * limbs * 8.0) as u128 + decimal_digits,
+                calibration.nanos_per_limb_squared * limbs * limbs,
+            )
+        }
+    };
+
+    Estimate {
+        bits,
+        decimal_digits,
+        memory_bytes,
+        time: Duration::try_from_secs_f64(nanos / 1e9).unwrap_or(Duration::MAX),
+    }
+}
+
+/// log2(φ), log2(√5), log10(φ), log10(√5).
+#[cfg(feature = "std")]
+const LOG2_PHI: f64 = 0.694_241_913_630_617_3;
+#[cfg(feature = "std")]
+const LOG2_SQRT5: f64 = 1.160_964_047_443_681;
+#[cfg(feature = "std")]
+const LOG10_PHI: f64 = 0.208_987_640_249_978_74;
+#[cfg(feature = "std")]
+const LOG10_SQRT5: f64 = 0.349_485_002_168_009_4;
+
+#[cfg(feature = "std")]
+fn bit_length(n: u128) -> u128 {
+    if n < FIBONACCI_TABLE_LEN as u128 {
+        (u128::BITS - FIBONACCI_TABLE[n as usize].leading_zeros()) as u128
+    } else {
+        (n as f64 * LOG2_PHI - LOG2_SQRT5).floor() as u128 + 1
+    }
+}
+
+#[cfg(feature = "std")]
+fn decimal_digits(n: u128) -> u128 {
+    if n < FIBONACCI_TABLE_LEN as u128 {
+        FIBONACCI_TABLE[n as usize].checked_ilog10().unwrap_or(0) as u128 + 1
+    } else {
+        (n as f64 * LOG10_PHI - LOG10_SQRT5).floor() as u128 + 1
+    }
+}
+
+#[cfg(feature = "std")]
+fn limbs_for_bits(bits: u128) -> f64 {
+    (bits as f64 / 64.0).ceil().max(1.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,26 +612,17 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn given_n_when_cassini_identity_then_holds() {
         // Given: тождество Кассини: fib(n-1) * fib(n+1) - fib(n)² = (-1)^n
-        // ⚠ Не увеличивать диапазон! При n > 93 произведение fib(n)² переполняет i128,
-        // а `as i128` молча обрежет значение — тест будет проходить с неверными данными.
-        for n in 1u128..=30 {
+        // проверяется в BigUint, поэтому диапазон не ограничен размером i128
+        for n in 1u128..=1000 {
             // When
-            let fib_prev = fibonacci(n - 1).unwrap() as i128;
-            let fib_curr = fibonacci(n).unwrap() as i128;
-            let fib_next = fibonacci(n + 1).unwrap() as i128;
-
-            let result = fib_prev * fib_next - fib_curr * fib_curr;
-            let expected = if n % 2 == 0 { 1 } else { -1 };
+            let holds = crate::identities::cassini(n);
 
             // Then
-            assert_eq!(
-                result, expected,
-                "Тождество Кассини не выполняется для n={}",
-                n
-            );
+            assert!(holds, "Тождество Кассини не выполняется для n={}", n);
         }
     }
 
@@ -183,6 +666,319 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn given_indices_up_to_186_when_fibonacci_const_then_matches_fibonacci() {
+        // Given
+        for n in 0u32..=186 {
+            // When
+            let result = fibonacci_const(n);
+
+            // Then
+            assert_eq!(result, fibonacci(n as u128).ok(), "n={}", n);
+        }
+    }
+
+    #[test]
+    fn given_187_when_fibonacci_const_then_returns_none() {
+        // Given
+        let input = 187;
+
+        // When
+        let result = fibonacci_const(input);
+
+        // Then
+        assert_eq!(result, None);
+        assert_eq!(fibonacci_const(u32::MAX), None);
+    }
+
+    #[test]
+    fn given_const_table_when_compared_then_matches_fibonacci_const() {
+        // Given / When
+        const TABLE: [u128; FIBONACCI_TABLE_LEN] = FIBONACCI_TABLE;
+
+        // Then
+        for (n, value) in TABLE.iter().enumerate() {
+            assert_eq!(Some(*value), fibonacci_const(n as u32), "n={}", n);
+        }
+    }
+
+    #[test]
+    fn given_table_values_when_fibonacci_index_of_then_returns_index() {
+        // Given: fib(2) = fib(1) = 1, поэтому начинаем с 3
+        for (n, &value) in FIBONACCI_TABLE.iter().enumerate().skip(3) {
+            // When
+            let result = fibonacci_index_of(value);
+
+            // Then
+            assert_eq!(result, Some(n as u128), "value={}", value);
+        }
+        assert_eq!(fibonacci_index_of(0), Some(0));
+        assert_eq!(fibonacci_index_of(1), Some(1));
+    }
+
+    #[test]
+    fn given_non_fibonacci_values_when_fibonacci_index_of_then_returns_none() {
+        // Given
+        let values = [4u128, 6, 7, 54, 56, u128::MAX];
+
+        // When / Then
+        for value in values {
+            assert_eq!(fibonacci_index_of(value), None, "value={}", value);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn given_range_when_fibonacci_range_then_matches_fibonacci() {
+        // Given
+        let range = 180..=186;
+
+        // When
+        let result = fibonacci_range(range.clone(), OverflowPolicy::Checked);
+
+        // Then
+        let expected: Vec<u128> = range.map(|n| fibonacci(n).unwrap()).collect();
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn given_u64_when_fibonacci_in_then_overflow_detected_for_u64() {
+        // Given: fib(94) помещается в u128, но не в u64
+        let input = 94;
+
+        // When
+        let result = fibonacci_in::<u64>(input);
+
+        // Then
+        assert!(fibonacci(input).is_ok());
+        assert_eq!(result, Err(ComputationError::Overflow(94)));
+    }
+
+    #[test]
+    fn given_u128_when_fibonacci_in_then_matches_fibonacci() {
+        // Given
+        for n in 0u128..=190 {
+            // When
+            let result = fibonacci_in::<u128>(n);
+
+            // Then
+            assert_eq!(result, fibonacci(n), "n={}", n);
+        }
+    }
+
+    #[test]
+    fn given_fitting_index_when_any_policy_then_results_equal() {
+        // Given
+        for n in 0u128..=186 {
+            for policy in OverflowPolicy::ALL {
+                // When
+                let result = fibonacci_with_policy(n, policy);
+
+                // Then
+                assert_eq!(result, fibonacci(n), "n={}, policy={}", n, policy);
+            }
+        }
+    }
+
+    #[test]
+    fn given_187_when_wrapping_policy_then_returns_sum_modulo_2_pow_128() {
+        // Given
+        let prev = fibonacci(186).unwrap();
+        let prev2 = fibonacci(185).unwrap();
+
+        // When
+        let result = fibonacci_with_policy(187, OverflowPolicy::Wrapping);
+
+        // Then
+        assert_eq!(result, Ok(prev.wrapping_add(prev2)));
+    }
+
+    #[test]
+    fn given_overflow_when_saturating_policy_then_returns_max() {
+        // Given
+        let input = u128::MAX;
+
+        // When
+        let result = fibonacci_with_policy(input, OverflowPolicy::Saturating);
+
+        // Then
+        assert_eq!(result, Ok(u128::MAX));
+    }
+
+    #[test]
+    fn given_overflow_when_checked_policy_then_returns_error() {
+        // Given
+        let input = 187;
+
+        // When
+        let result = fibonacci_with_policy(input, OverflowPolicy::Checked);
+
+        // Then
+        assert_eq!(result, Err(ComputationError::Overflow(187)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn given_fitting_index_when_fibonacci_big_then_matches_fibonacci() {
+        // Given
+        for n in 0u128..=186 {
+            // When
+            let result = fibonacci_big(n);
+
+            // Then
+            assert_eq!(result.to_u128(), fibonacci(n).ok(), "n={}", n);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn given_1000_when_fibonacci_big_then_returns_known_value() {
+        // Given
+        let input = 1000;
+
+        // When
+        let result = fibonacci_big(input);
+
+        // Then
+        assert_eq!(
+            result.to_string(),
+            "43466557686937456435688527675040625802564660517371780402481729089536555417949051890403879840079255169295922593080322634775209689623239873322471161642996440906533187938298969649928516003704476137795166849228875"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn given_consecutive_big_indices_when_fibonacci_big_then_sum_property_holds() {
+        // Given
+        let n = 500;
+
+        // When
+        let (prev2, prev, curr) = (fibonacci_big(n - 2), fibonacci_big(n - 1), fibonacci_big(n));
+
+        // Then
+        assert_eq!(&prev2 + &prev, curr);
+    }
+
+    #[cfg(feature = "std")]
+    const FIXED_CALIBRATION: Calibration = Calibration {
+        nanos_per_u128_step: 1.0,
+        nanos_per_limb_squared: 10.0,
+    };
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn given_fitting_indices_when_estimate_then_sizes_are_exact() {
+        // Given
+        for n in 0u128..=186 {
+            let value = fibonacci(n).unwrap();
+
+            // When
+            let e = estimate_with(n, Backend::U128, &FIXED_CALIBRATION);
+
+            // Then
+            assert_eq!(e.bits, (128 - value.leading_zeros()) as u128, "n={}", n);
+            assert_eq!(e.decimal_digits, value.to_string().len() as u128, "n={}", n);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn given_big_indices_when_estimate_then_sizes_match_fibonacci_big() {
+        // Given
+        for n in [187u128, 500, 1000, 4321, 10_000] {
+            let value = fibonacci_big(n);
+
+            // When
+            let e = estimate_with(n, Backend::Big, &FIXED_CALIBRATION);
+
+            // Then
+            assert_eq!(e.bits, value.bits() as u128, "n={}", n);
+            assert_eq!(e.decimal_digits, value.to_string().len() as u128, "n={}", n);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn given_larger_index_when_estimate_big_then_cost_grows() {
+        // Given
+        let small = estimate_with(10_000, Backend::Big, &FIXED_CALIBRATION);
+
+        // When
+        let large = estimate_with(1_000_000, Backend::Big, &FIXED_CALIBRATION);
+
+        // Then
+        assert!(large.memory_bytes > small.memory_bytes);
+        assert!(large.time > small.time);
+        assert!(large.memory_bytes >= large.bits / 8);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn given_huge_index_when_estimate_then_does_not_panic() {
+        // Given
+        let input = u128::MAX;
+
+        // When
+        let e = estimate_with(input, Backend::Big, &FIXED_CALIBRATION);
+
+        // Then
+        assert_eq!(e.time, Duration::MAX);
+        assert!(e.decimal_digits > 10u128.pow(37));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn given_u128_backend_when_estimate_then_memory_is_constant() {
+        // Given / When
+        let small = estimate_with(10, Backend::U128, &FIXED_CALIBRATION);
+        let large = estimate_with(u128::MAX, Backend::U128, &FIXED_CALIBRATION);
+
+        // Then
+        assert_eq!(small.memory_bytes, large.memory_bytes);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn given_machine_when_calibration_measure_then_coefficients_are_positive() {
+        // Given / When
+        let calibration = Calibration::measure();
+
+        // Then
+        assert!(calibration.nanos_per_u128_step > 0.0);
+        assert!(calibration.nanos_per_limb_squared > 0.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn given_policies_when_serialize_then_uses_cli_names() {
+        for policy in OverflowPolicy::ALL {
+            // When
+            let json = serde_json::to_string(&policy).unwrap();
+
+            // Then
+            assert_eq!(json, format!("\"{}\"", policy.name()));
+            assert_eq!(
+                serde_json::from_str::<OverflowPolicy>(&json).unwrap(),
+                policy
+            );
+        }
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn given_estimate_when_serialize_then_sizes_are_decimal_strings() {
+        // Given
+        let estimate = estimate_with(1000, Backend::Big, &FIXED_CALIBRATION);
+
+        // When
+        let json = serde_json::to_value(estimate).unwrap();
+
+        // Then
+        assert_eq!(json["bits"], "694");
+        assert_eq!(json["decimal_digits"], "209");
+        assert_eq!(serde_json::from_value::<Estimate>(json).unwrap(), estimate);
+    }
+
     use proptest::prelude::*;
 
     proptest! {
@@ -200,21 +996,14 @@ mod tests {
             prop_assert_eq!(fib_n, sum);
         }
 
+        #[cfg(feature = "alloc")]
         #[test]
-        fn fib_cassini_property(n in 1u128..30) {
-            // ⚠ Не увеличивать диапазон! При n > 93 произведение fib(n)² переполняет i128,
-            // а `as i128` молча обрежет значение — тест будет проходить с неверными данными.
-            // Given
-            let prev = fibonacci(n - 1).unwrap() as i128;
-            let curr = fibonacci(n).unwrap() as i128;
-            let next = fibonacci(n + 1).unwrap() as i128;
-
-            // When
-            let result = prev * next - curr * curr;
-            let expected = if n % 2 == 0 { 1i128 } else { -1i128 };
+        fn fib_cassini_property(n in 1u128..20_000) {
+            // Given / When
+            let holds = crate::identities::cassini(n);
 
             // Then
-            prop_assert_eq!(result, expected);
+            prop_assert!(holds);
         }
     }
 }
@@ -0,0 +1,530 @@
+//! Проверка тождеств для чисел Фибоначчи в точной арифметике [`BigUint`].
+//!
+//! Знакопеременные тождества вида `A − B = (−1)^k · C` проверяются без
+//! знаковых типов: как `A = B + C` при чётном `k` и `B = A + C` при нечётном.
+//! Поэтому нет ни переполнения, ни усечения `as i128` — индексы любые.
+//!
+//! ```
+//! use fibonacci_stable::identities::{self, Identity};
+//!
+//! assert!(identities::cassini(1000));
+//! assert!(identities::vajda(200, 17, 31));
+//!
+//! let report = identities::verify_up_to(30);
+//! assert!(report.is_ok());
+//! assert_eq!(report.checks(Identity::Cassini), 29);
+//! ```
+
+use core::fmt;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::bigint::BigUint;
+use crate::fibonacci::fibonacci_big;
+
+/// Тождество для чисел Фибоначчи.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Identity {
+    /// Кассини: `F(n−1)·F(n+1) − F(n)² = (−1)^n`, `n ≥ 1`.
+    Cassini,
+    /// Каталан: `F(n)² − F(n−r)·F(n+r) = (−1)^(n−r)·F(r)²`, `r ≤ n`.
+    Catalan,
+    /// д'Окань: `F(m)·F(n+1) − F(m+1)·F(n) = (−1)^n·F(m−n)`, `n ≤ m`.
+    DOcagne,
+    /// Вайда: `F(n+i)·F(n+j) − F(n)·F(n+i+j) = (−1)^n·F(i)·F(j)`.
+    Vajda,
+    /// НОД: `gcd(F(m), F(n)) = F(gcd(m, n))`.
+    Gcd,
+    /// Сумма: `F(0) + … + F(n) = F(n+2) − 1`.
+    Sum,
+    /// Сумма квадратов: `F(0)² + … + F(n)² = F(n)·F(n+1)`.
+    SquareSum,
+    /// Сумма нечётных индексов: `F(1) + F(3) + … + F(2n−1) = F(2n)`.
+    OddSum,
+    /// Сумма чётных индексов: `F(2) + F(4) + … + F(2n) = F(2n+1) − 1`.
+    EvenSum,
+}
+
+impl Identity {
+    /// Все тождества в порядке вывода отчёта.
+    pub const ALL: [Identity; 9] = [
+        Identity::Cassini,
+        Identity::Catalan,
+        Identity::DOcagne,
+        Identity::Vajda,
+        Identity::Gcd,
+        Identity::Sum,
+        Identity::SquareSum,
+        Identity::OddSum,
+        Identity::EvenSum,
+    ];
+
+    /// Короткое имя, например `cassini`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Identity::Cassini => "cassini",
+            Identity::Catalan => "catalan",
+            Identity::DOcagne => "d'ocagne",
+            Identity::Vajda => "vajda",
+            Identity::Gcd => "gcd",
+            Identity::Sum => "sum",
+            Identity::SquareSum => "square-sum",
+            Identity::OddSum => "odd-sum",
+            Identity::EvenSum => "even-sum",
+        }
+    }
+
+    /// Формула тождества.
+    pub fn formula(self) -> &'static str {
+        match self {
+            Identity::Cassini => "F(n-1)F(n+1) - F(n)^2 = (-1)^n",
+            Identity::Catalan => "F(n)^2 - F(n-r)F(n+r) = (-1)^(n-r) F(r)^2",
+            Identity::DOcagne => "F(m)F(n+1) - F(m+1)F(n) = (-1)^n F(m-n)",
+            Identity::Vajda => "F(n+i)F(n+j) - F(n)F(n+i+j) = (-1)^n F(i)F(j)",
+            Identity::Gcd => "gcd(F(m), F(n)) = F(gcd(m, n))",
+            Identity::Sum => "F(0) + ... + F(n) = F(n+2) - 1",
+            Identity::SquareSum => "F(0)^2 + ... + F(n)^2 = F(n)F(n+1)",
+            Identity::OddSum => "F(1) + F(3) + ... + F(2n-1) = F(2n)",
+            Identity::EvenSum => "F(2) + F(4) + ... + F(2n) = F(2n+1) - 1",
+        }
+    }
+
+    /// Имена параметров в порядке [`Violation::args`].
+    pub fn params(self) -> &'static [&'static str] {
+        match self {
+            Identity::Cassini
+            | Identity::Sum
+            | Identity::SquareSum
+            | Identity::OddSum
+            | Identity::EvenSum => &["n"],
+            Identity::Catalan => &["n", "r"],
+            Identity::DOcagne | Identity::Gcd => &["m", "n"],
+            Identity::Vajda => &["n", "i", "j"],
+        }
+    }
+}
+
+impl fmt::Display for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Набор параметров, на котором тождество не выполнилось.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Нарушенное тождество.
+    pub identity: Identity,
+    /// Значения параметров в порядке [`Identity::params()`].
+    pub args: Vec<u128>,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.identity)?;
+        for (name, value) in self.identity.params().iter().zip(&self.args) {
+            write!(f, " {}={}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Результат [`verify_up_to()`]: число проверок по каждому тождеству и нарушения.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// Наибольший индекс числа Фибоначчи, участвовавшего в проверках.
+    pub up_to: u128,
+    /// Число проверенных наборов параметров в порядке [`Identity::ALL`].
+    pub counts: [u64; 9],
+    /// Наборы параметров, на которых тождества не выполнились.
+    pub violations: Vec<Violation>,
+}
+
+impl Report {
+    /// `true`, если нарушений нет.
+    pub fn is_ok(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Число проверенных наборов параметров для тождества.
+    pub fn checks(&self, identity: Identity) -> u64 {
+        let index = Identity::ALL.iter().position(|&i| i == identity);
+        self.counts[index.expect("тождество из Identity::ALL")]
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Проверка тождеств для индексов до {}:", self.up_to)?;
+        for identity in Identity::ALL {
+            let failed = self
+                .violations
+                .iter()
+                .filter(|v| v.identity == identity)
+                .count();
+            let status = if failed == 0 {
+                "OK"
+            } else {
+                "НАРУШЕНО"
+            };
+            writeln!(
+                f,
+                "  {:<11} {:<48} {:>9} проверок  {}",
+                identity.name(),
+                identity.formula(),
+                self.checks(identity),
+                status
+            )?;
+        }
+        for violation in &self.violations {
+            writeln!(f, "  нарушение {}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+/// Проверяет тождество Кассини для `n ≥ 1`.
+///
+/// ```
+/// use fibonacci_stable::identities::cassini;
+///
+/// assert!(cassini(1) && cassini(10_000));
+/// ```
+///
+/// # Паника
+///
+/// При `n = 0` (индекс `n − 1` не определён).
+pub fn cassini(n: u128) -> bool {
+    assert!(n >= 1, "тождество Кассини определено для n ≥ 1");
+    cassini_with(n, &fibonacci_big)
+}
+
+/// Проверяет тождество Каталана для `r ≤ n`.
+///
+/// # Паника
+///
+/// При `r > n`.
+pub fn catalan(n: u128, r: u128) -> bool {
+    assert!(r <= n, "тождество Каталана определено для r ≤ n");
+    catalan_with(n, r, &fibonacci_big)
+}
+
+/// Проверяет тождество д'Оканя для `n ≤ m`.
+///
+/// # Паника
+///
+/// При `n > m`.
+pub fn d_ocagne(m: u128, n: u128) -> bool {
+    assert!(n <= m, "тождество д'Оканя определено для n ≤ m");
+    d_ocagne_with(m, n, &fibonacci_big)
+}
+
+/// Проверяет тождество Вайды.
+pub fn vajda(n: u128, i: u128, j: u128) -> bool {
+    vajda_with(n, i, j, &fibonacci_big)
+}
+
+/// Проверяет `gcd(F(m), F(n)) = F(gcd(m, n))`.
+///
+/// ```
+/// use fibonacci_stable::identities::gcd_property;
+///
+/// assert!(gcd_property(1000, 750));
+/// ```
+pub fn gcd_property(m: u128, n: u128) -> bool {
+    gcd_with(m, n, &fibonacci_big)
+}
+
+/// Проверяет `F(0) + … + F(n) = F(n+2) − 1`.
+pub fn sum(n: u128) -> bool {
+    sum_with(n, &fibonacci_big)
+}
+
+/// Проверяет `F(0)² + … + F(n)² = F(n)·F(n+1)`.
+pub fn square_sum(n: u128) -> bool {
+    square_sum_with(n, &fibonacci_big)
+}
+
+/// Проверяет `F(1) + F(3) + … + F(2n−1) = F(2n)`.
+pub fn odd_sum(n: u128) -> bool {
+    odd_sum_with(n, &fibonacci_big)
+}
+
+/// Проверяет `F(2) + F(4) + … + F(2n) = F(2n+1) − 1`.
+pub fn even_sum(n: u128) -> bool {
+    even_sum_with(n, &fibonacci_big)
+}
+
+/// Наибольшее `up_to`, которое принимает `verify --up-to`: около минуты
+/// в release-сборке.
+pub const MAX_VERIFY_UP_TO: u128 = 1000;
+
+/// Проверяет все тождества на всех наборах параметров, в которых индексы
+/// чисел Фибоначчи не превышают `up_to`.
+///
+/// Числа `F(0..=up_to)` вычисляются один раз. Число проверок растёт как
+/// `up_to²` для двухпараметрических тождеств и как `up_to³` для тождества Вайды,
+/// поэтому разумные значения — до нескольких сотен; CLI не принимает больше
+/// [`MAX_VERIFY_UP_TO`].
+///
+/// # Паника
+///
+/// Если `up_to + 1` не помещается в `usize`.
+///
+/// ```
+/// use fibonacci_stable::identities::{Identity, verify_up_to};
+///
+/// let report = verify_up_to(10);
+/// assert!(report.is_ok());
+/// assert_eq!(report.checks(Identity::Sum), 9); // n = 0..=8, F(n+2) ≤ F(10)
+/// ```
+pub fn verify_up_to(up_to: u128) -> Report {
+    let len = usize::try_from(up_to)
+        .ok()
+        .and_then(|n| n.checked_add(1))
+        .expect("up_to + 1 не помещается в usize");
+    let mut table = vec![BigUint::zero(), BigUint::one()];
+    while table.len() < len {
+        let next = &table[table.len() - 2] + &table[table.len() - 1];
+        table.push(next);
+    }
+    // Все индексы в проверках не больше up_to, поэтому помещаются в usize.
+    let fib = |k: u128| table[usize::try_from(k).expect("k ≤ up_to")].clone();
+
+    let mut report = Report {
+        up_to,
+        counts: [0; 9],
+        violations: Vec::new(),
+    };
+    let mut check = |identity: Identity, args: &[u128], holds: bool| {
+        let index = Identity::ALL.iter().position(|&i| i == identity);
+        report.counts[index.expect("тождество из Identity::ALL")] += 1;
+        if !holds {
+            report.violations.push(Violation {
+                identity,
+                args: args.to_vec(),
+            });
+        }
+    };
+
+    for n in 1..up_to {
+        check(Identity::Cassini, &[n], cassini_with(n, &fib));
+    }
+    for n in 0..=up_to {
+        for r in 0..=n.min(up_to - n) {
+            check(Identity::Catalan, &[n, r], catalan_with(n, r, &fib));
+        }
+    }
+    for m in 0..up_to {
+        for n in 0..=m {
+            check(Identity::DOcagne, &[m, n], d_ocagne_with(m, n, &fib));
+        }
+    }
+    for n in 0..=up_to {
+        for i in 0..=(up_to - n) / 2 {
+            for j in i..=up_to - n - i {
+                check(Identity::Vajda, &[n, i, j], vajda_with(n, i, j, &fib));
+            }
+        }
+    }
+    for m in 0..=up_to {
+        for n in 0..=m {
+            check(Identity::Gcd, &[m, n], gcd_with(m, n, &fib));
+        }
+    }
+    for n in 0..up_to.saturating_sub(1) {
+        check(Identity::Sum, &[n], sum_with(n, &fib));
+    }
+    for n in 0..up_to {
+        check(Identity::SquareSum, &[n], square_sum_with(n, &fib));
+    }
+    for n in 0..=up_to / 2 {
+        check(Identity::OddSum, &[n], odd_sum_with(n, &fib));
+    }
+    for n in 0..up_to.div_ceil(2) {
+        check(Identity::EvenSum, &[n], even_sum_with(n, &fib));
+    }
+    report
+}
+
+/// Проверяет `a − b = (−1)^k · c`, то есть `a = b + c` при чётном `k`
+/// и `b = a + c` при нечётном.
+fn alternating_eq(a: &BigUint, b: &BigUint, k: u128, c: &BigUint) -> bool {
+    if k.is_multiple_of(2) {
+        *a == b + c
+    } else {
+        *b == a + c
+    }
+}
+
+fn cassini_with(n: u128, fib: &impl Fn(u128) -> BigUint) -> bool {
+    let curr = fib(n);
+    alternating_eq(
+        &(&fib(n - 1) * &fib(n + 1)),
+        &(&curr * &curr),
+        n,
+        &BigUint::one(),
+    )
+}
+
+fn catalan_with(n: u128, r: u128, fib: &impl Fn(u128) -> BigUint) -> bool {
+    let (curr, step) = (fib(n), fib(r));
+    alternating_eq(
+        &(&curr * &curr),
+        &(&fib(n - r) * &fib(n + r)),
+        n - r,
+        &(&step * &step),
+    )
+}
+
+fn d_ocagne_with(m: u128, n: u128, fib: &impl Fn(u128) -> BigUint) -> bool {
+    alternating_eq(
+        &(&fib(m) * &fib(n + 1)),
+        &(&fib(m + 1) * &fib(n)),
+        n,
+        &fib(m - n),
+    )
+}
+
+fn vajda_with(n: u128, i: u128, j: u128, fib: &impl Fn(u128) -> BigUint) -> bool {
+    alternating_eq(
+        &(&fib(n + i) * &fib(n + j)),
+        &(&fib(n) * &fib(n + i + j)),
+        n,
+        &(&fib(i) * &fib(j)),
+    )
+}
+
+fn gcd_with(m: u128, n: u128, fib: &impl Fn(u128) -> BigUint) -> bool {
+    let (mut a, mut b) = (m, n);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    fib(m).gcd(&fib(n)) == fib(a)
+}
+
+fn sum_with(n: u128, fib: &impl Fn(u128) -> BigUint) -> bool {
+    let total = (0..=n).fold(BigUint::zero(), |acc, k| &acc + &fib(k));
+    &total + &BigUint::one() == fib(n + 2)
+}
+
+fn square_sum_with(n: u128, fib: &impl Fn(u128) -> BigUint) -> bool {
+    let total = (0..=n).fold(BigUint::zero(), |acc, k| {
+        let value = fib(k);
+        &acc + &(&value * &value)
+    });
+    total == &fib(n) * &fib(n + 1)
+}
+
+fn odd_sum_with(n: u128, fib: &impl Fn(u128) -> BigUint) -> bool {
+    let total = (1..=n).fold(BigUint::zero(), |acc, k| &acc + &fib(2 * k - 1));
+    total == fib(2 * n)
+}
+
+fn even_sum_with(n: u128, fib: &impl Fn(u128) -> BigUint) -> bool {
+    let total = (1..=n).fold(BigUint::zero(), |acc, k| &acc + &fib(2 * k));
+    &total + &BigUint::one() == fib(2 * n + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_indices_beyond_i128_when_check_identities_then_hold() {
+        // Given: n > 93 — здесь проверка через `as i128` уже невозможна
+        let n = 500;
+
+        // When / Then
+        assert!(cassini(n));
+        assert!(catalan(n, 123));
+        assert!(d_ocagne(n, 321));
+        assert!(vajda(n, 40, 77));
+        assert!(gcd_property(n, 375));
+        assert!(sum(n));
+        assert!(square_sum(n));
+        assert!(odd_sum(n));
+        assert!(even_sum(n));
+    }
+
+    #[test]
+    fn given_wrong_fibonacci_when_check_then_identity_fails() {
+        // Given: «последовательность», отличающаяся от Фибоначчи в F(7)
+        let fib = |k: u128| {
+            let value = fibonacci_big(k);
+            if k == 7 {
+                &value + &BigUint::one()
+            } else {
+                value
+            }
+        };
+
+        // When / Then
+        assert!(!cassini_with(7, &fib));
+        assert!(!catalan_with(7, 2, &fib));
+        assert!(!gcd_with(14, 7, &fib));
+        assert!(!sum_with(5, &fib));
+        assert!(!odd_sum_with(4, &fib));
+        assert!(cassini_with(3, &fib));
+    }
+
+    #[test]
+    fn given_up_to_when_verify_then_counts_all_parameter_sets() {
+        // Given
+        let up_to = 12;
+
+        // When
+        let report = verify_up_to(up_to);
+
+        // Then
+        assert!(report.is_ok(), "{}", report);
+        assert_eq!(report.checks(Identity::Cassini), 11);
+        assert_eq!(report.checks(Identity::Gcd), 13 * 14 / 2);
+        assert_eq!(report.checks(Identity::Sum), 11);
+        assert_eq!(report.checks(Identity::OddSum), 7);
+        assert_eq!(report.checks(Identity::EvenSum), 6);
+        assert!(report.counts.iter().all(|&count| count > 0));
+    }
+
+    #[test]
+    fn given_small_up_to_when_verify_then_no_out_of_range_indices() {
+        // Given / When
+        let reports: Vec<Report> = (0..4).map(verify_up_to).collect();
+
+        // Then
+        assert!(reports.iter().all(Report::is_ok));
+        assert_eq!(reports[0].checks(Identity::Cassini), 0);
+        assert_eq!(reports[0].checks(Identity::Gcd), 1);
+    }
+
+    #[test]
+    fn given_violation_when_display_then_names_params() {
+        // Given
+        let violation = Violation {
+            identity: Identity::Vajda,
+            args: vec![5, 2, 3],
+        };
+
+        // When
+        let text = violation.to_string();
+
+        // Then
+        assert_eq!(text, "vajda: n=5 i=2 j=3");
+    }
+
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn given_any_indices_when_vajda_and_gcd_then_hold(
+            n in 0u128..2000,
+            i in 0u128..500,
+            j in 0u128..500,
+        ) {
+            // Given / When / Then
+            prop_assert!(vajda(n, i, j));
+            prop_assert!(gcd_property(n, i));
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use std::io;
+use std::fmt;
+use std::io::{self, BufRead, Write};
 
 use crate::Logger;
 use crate::errors::InputError;
@@ -11,35 +12,74 @@ use crate::errors::InputError;
 /// # Возвращает
 ///
 /// * `Some(u128)` — корректное число
-/// * `None` — пользователь ввёл `q` (выход) или произошла ошибка чтения stdin
+/// * `None` — пользователь ввёл `q` (выход), stdin закончился или произошла ошибка чтения
 pub fn read_input(logger: &mut Logger) -> Option<u128> {
-    println!("Введите число для вычисления Фибоначчи:");
+    match read_input_from(&mut io::stdin().lock(), &mut io::stdout(), logger) {
+        Ok(n) => n,
+        Err(e) => {
+            logger.error(&format!("Ошибка чтения stdin: {}", e));
+            eprintln!("Ошибка чтения ввода: {}", e);
+            None
+        }
+    }
+}
+
+/// То же, что [`read_input()`], но с произвольными источником и приёмником.
+///
+/// Позволяет вести интерактивный цикл по сценарию в памяти.
+///
+/// # Возвращает
+///
+/// * `Ok(Some(u128))` — корректное число
+/// * `Ok(None)` — пользователь ввёл `q` (выход) или ввод закончился
+/// * `Err(_)` — ошибка чтения или записи
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::Logger;
+/// use fibonacci_stable::io::read_input_from;
+///
+/// let mut logger = Logger::new();
+/// let mut output = Vec::new();
+///
+/// let n = read_input_from(&mut "abc\n42\n".as_bytes(), &mut output, &mut logger).unwrap();
+///
+/// assert_eq!(n, Some(42));
+/// assert!(String::from_utf8(output).unwrap().contains("EN: Error!"));
+/// ```
+pub fn read_input_from(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    logger: &mut Logger,
+) -> io::Result<Option<u128>> {
+    writeln!(output, "Введите число для вычисления Фибоначчи:")?;
 
     loop {
-        let mut input = String::new();
+        let mut line = String::new();
 
-        match io::stdin().read_line(&mut input) {
-            Ok(_) => {}
-            Err(e) => {
-                logger.log(&format!("Ошибка чтения stdin: {}", e));
-                eprintln!("Ошибка чтения ввода: {}", e);
-                return None;
-            }
+        if input.read_line(&mut line)? == 0 {
+            logger.log("Конец ввода");
+            return Ok(None);
         }
 
-        let trimmed = input.trim();
+        let trimmed = line.trim();
 
         if trimmed.eq_ignore_ascii_case("q") {
-            return None;
+            return Ok(None);
         }
 
         logger.log(&format!("Ввод от пользователя: '{}'", trimmed));
 
-        match parse_input(&input) {
-            Ok(n) => return Some(n),
+        match parse_input(&line) {
+            Ok(n) => return Ok(Some(n)),
             Err(e) => {
-                logger.log(&format!("Некорректный ввод: '{}'. Ошибка: {}", trimmed, e));
-                println!("{}\nПопробуйте снова или введите 'q' для выхода:", e);
+                logger.error(&format!("Некорректный ввод: '{}'. Ошибка: {}", trimmed, e));
+                writeln!(
+                    output,
+                    "{}\nПопробуйте снова или введите 'q' для выхода:",
+                    e
+                )?;
             }
         }
     }
@@ -92,10 +132,17 @@ pub fn parse_input(input: &str) -> Result<u128, InputError> {
 }
 
 /// Выводит результат вычисления в формате `fibonacci(n) = result`.
-pub fn print_result(n: u128, result: u128) {
+///
+/// Принимает любое отображаемое значение: `u128`, [`BigUint`](crate::BigUint) и т. п.
+pub fn print_result(n: u128, result: impl fmt::Display) {
     println!("fibonacci({}) = {}", n, result);
 }
 
+/// То же, что [`print_result()`], но в произвольный приёмник.
+pub fn write_result(output: &mut impl Write, n: u128, result: impl fmt::Display) -> io::Result<()> {
+    writeln!(output, "fibonacci({}) = {}", n, result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -233,6 +280,70 @@ mod tests {
         assert_eq!(result, Ok(0));
     }
 
+    #[test]
+    fn given_invalid_then_valid_script_when_read_input_from_then_returns_number() {
+        // Given
+        let mut input = "abc\n-5\n7\n".as_bytes();
+        let mut output = Vec::new();
+        let mut logger = Logger::new();
+
+        // When
+        let result = read_input_from(&mut input, &mut output, &mut logger);
+
+        // Then
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(result.unwrap(), Some(7));
+        assert_eq!(output.matches("EN: Error!").count(), 2);
+        assert!(
+            logger
+                .entries()
+                .last()
+                .unwrap()
+                .contains("Ввод от пользователя: '7'")
+        );
+    }
+
+    #[test]
+    fn given_script_without_quit_when_read_input_from_then_stops_at_end_of_input() {
+        // Given
+        let mut input = "abc\n".as_bytes();
+        let mut output = Vec::new();
+        let mut logger = Logger::new();
+
+        // When
+        let result = read_input_from(&mut input, &mut output, &mut logger);
+
+        // Then
+        assert_eq!(result.unwrap(), None);
+        assert!(logger.entries().last().unwrap().contains("Конец ввода"));
+    }
+
+    #[test]
+    fn given_quit_when_read_input_from_then_returns_none() {
+        // Given
+        let mut input = "Q\n10\n".as_bytes();
+        let mut output = Vec::new();
+        let mut logger = Logger::new();
+
+        // When
+        let result = read_input_from(&mut input, &mut output, &mut logger);
+
+        // Then
+        assert_eq!(result.unwrap(), None);
+    }
+
+    #[test]
+    fn given_writer_when_write_result_then_formats_like_print_result() {
+        // Given
+        let mut output = Vec::new();
+
+        // When
+        write_result(&mut output, 10, 55).unwrap();
+
+        // Then
+        assert_eq!(String::from_utf8(output).unwrap(), "fibonacci(10) = 55\n");
+    }
+
     use proptest::prelude::*;
 
     proptest! {
@@ -6,9 +6,48 @@
 //! ## Основные возможности
 //!
 //! - Безопасное вычисление через [`fibonacci()`] — `try_fold` + `checked_add`, без паник
+//! - Вычисление на этапе компиляции через [`fibonacci_const()`] и таблицу [`fibonacci::FIBONACCI_TABLE`]
+//! - Вычисление в любом целочисленном типе через [`fibonacci_in()`] и трейт [`FibNum`]
+//! - Выбор политики переполнения через [`fibonacci_with_policy()`] и [`OverflowPolicy`]
+//! - Точное вычисление для любого индекса через [`fibonacci_big()`] и [`BigUint`]
+//! - Граница переполнения для любого типа через [`max_index()`]
+//! - Количество цифр и первые цифры F(n) для любого индекса через модуль [`approx`]
+//! - Прогноз размера и стоимости вычисления через [`fibonacci::estimate()`] и бюджеты [`Budget`]
+//! - Параллельные вычисления на `std::thread` через модуль [`parallel`]
+//! - Вычисление по модулю и последние цифры для любого индекса через [`modular::fibonacci_mod()`]
+//!   и [`modular::fibonacci_last_digits()`]
+//! - Простота F(n), разложение F(n) и ранг появления `α(p)` через модуль [`primes`]
+//! - Суммы, суммы квадратов и знакопеременные суммы по диапазону через модуль [`sums`]
+//! - Поиск Фибоначчи по срезам и шаги-числа Фибоначчи через модуль [`search`]
+//! - Степени Q-матрицы и многочлены Фибоначчи и Люка через модуль [`matrix`]
+//! - Проверка тождеств Кассини, Каталана, д'Оканя, Вайды, НОД и сумм через модуль [`identities`]
+//! - HTTP JSON API (`serve`) через модуль [`server`]
+//! - C ABI (`cdylib`/`staticlib`) с заголовком `include/fibonacci_stable.h` через модуль [`ffi`]
+//! - Модуль Python на PyO3 (feature `python`): `fibonacci_stable.fib(1000)` возвращает `int`
+//! - API для WebAssembly со строковыми аргументами и результатами (feature `wasm`) через модуль `wasm`
+//! - Строковый TCP-протокол с pipelining (`serve --protocol line`) через модуль [`line_protocol`]
+//! - Файл конфигурации `fibonacci_stable.toml` и переменные окружения через модуль [`config`]
 //! - Валидация ввода через [`parse_input()`] — обработка всех видов некорректного ввода
 //! - Двуязычные ошибки ([`InputError`], [`ComputationError`]) — RU + EN
 //! - Логирование через [`Logger`] с таймстемпами
+//! - Ядро без `std` (`#![no_std]`) для микроконтроллеров, см. «Features»
+//!
+//! ## Features
+//!
+//! - `std` (по умолчанию) — ввод-вывод, логирование, CLI, серверы, C ABI, потоки
+//!   и прогноз стоимости; включает `alloc`
+//! - `alloc` — [`BigUint`], [`fibonacci_big()`], `fibonacci_range()`, [`approx`], [`identities`], [`primes`], многочлены [`matrix`] и суммы `*_big` из [`sums`] без `std`
+//! - `clock` (по умолчанию) — таймстемпы в логах через `chrono`; включает `std`
+//! - `python`, `wasm` — привязки для Python и WebAssembly; включают `std`
+//! - `serde` — `Serialize`/`Deserialize` для ошибок, [`OverflowPolicy`], [`BigUint`],
+//!   прогноза и [`Logger`]; `u128` и [`BigUint`] — десятичными строками
+//!
+//! Без `std` доступны модули [`fibonacci`], [`errors`], [`matrix`], [`modular`], [`num`], [`search`] и [`sums`]:
+//!
+//! ```toml
+//! fibonacci_stable = { version = "0.1", default-features = false }            # только core
+//! fibonacci_stable = { version = "0.1", default-features = false, features = ["alloc"] }
+//! ```
 //!
 //! ## Пример
 //!
@@ -19,12 +58,69 @@
 //! assert_eq!(result, 55);
 //! ```
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod app;
+#[cfg(feature = "alloc")]
+pub mod approx;
+#[cfg(feature = "alloc")]
+pub mod bigint;
+#[cfg(feature = "std")]
+pub mod cli;
+#[cfg(feature = "std")]
+pub mod config;
+#[cfg(feature = "serde")]
+mod decimal;
 pub mod errors;
+#[cfg(feature = "std")]
+pub mod ffi;
 pub mod fibonacci;
+#[cfg(feature = "alloc")]
+pub mod identities;
+#[cfg(feature = "std")]
 pub mod io;
+#[cfg(feature = "std")]
+pub mod line_protocol;
+#[cfg(feature = "std")]
 pub mod logger;
+pub mod matrix;
+pub mod modular;
+pub mod num;
+#[cfg(feature = "std")]
+pub mod parallel;
+#[cfg(feature = "alloc")]
+pub mod primes;
+#[cfg(feature = "python")]
+mod python;
+pub mod search;
+#[cfg(feature = "std")]
+pub mod server;
+pub mod sums;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-pub use errors::{ComputationError, InputError};
-pub use fibonacci::fibonacci;
-pub use io::{parse_input, print_result, read_input};
+#[cfg(feature = "alloc")]
+pub use bigint::BigUint;
+#[cfg(feature = "std")]
+pub use cli::{
+    Budget, BudgetAction, Command, Mode, Options, Protocol, parse_args, parse_args_with,
+};
+#[cfg(feature = "alloc")]
+pub use errors::ArgsError;
+#[cfg(feature = "std")]
+pub use errors::ConfigError;
+pub use errors::{BudgetError, ComputationError, InputError};
+#[cfg(feature = "alloc")]
+pub use fibonacci::fibonacci_big;
+pub use fibonacci::{
+    OverflowPolicy, fibonacci, fibonacci_const, fibonacci_in, fibonacci_with_policy, max_index,
+};
+#[cfg(feature = "std")]
+pub use io::{parse_input, print_result, read_input, read_input_from, write_result};
+#[cfg(feature = "std")]
 pub use logger::Logger;
+pub use num::FibNum;
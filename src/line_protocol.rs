@@ -0,0 +1,264 @@
+//! Текстовый протокол поверх TCP для клиентов, которым важна задержка.
+//!
+//! Запускается как `serve --protocol line [--addr IP:PORT]`. Соединение постоянное:
+//! клиент отправляет запросы строками, сервер отвечает по одной строке на каждый
+//! запрос в том же порядке. Запросы можно отправлять пачкой, не дожидаясь ответов
+//! (pipelining): ответы буферизуются и отправляются, когда прочитаны все
+//! пришедшие запросы.
+//!
+//! # Запросы
+//!
+//! | Запрос | Ответ |
+//! |---|---|
+//! | `FIB <n>` | `OK <fib(n)>` |
+//! | `QUIT` | `BYE`, после чего сервер закрывает соединение |
+//!
+//! Строки завершаются `\n` (допускается `\r\n`), длина строки — не больше 8 КиБ.
+//! Аргумент `FIB` проверяется через [`parse_input()`](crate::parse_input), режим
+//! вычисления, политика переполнения и бюджет берутся из параметров сервера.
+//!
+//! # Ошибки
+//!
+//! `ERR <CODE>` или `ERR <CODE> <n>`, где `CODE` — код из
+//! [`InputError::code`](crate::InputError::code),
+//! [`ComputationError::code`](crate::ComputationError::code) или
+//! [`BudgetError::code`](crate::BudgetError::code), а `n` — индекс, на котором
+//! произошла ошибка вычисления:
+//!
+//! ```text
+//! FIB 42          → OK 267914296
+//! FIB 187         → ERR COMPUTATION_OVERFLOW 187
+//! FIB -1          → ERR NEGATIVE_NUMBER
+//! FIB             → ERR EMPTY_INPUT
+//! PING            → ERR UNKNOWN_COMMAND
+//! ```
+//!
+//! Пустая строка тоже получает ответ (`ERR EMPTY_INPUT`), поэтому ответы всегда
+//! соответствуют запросам один к одному. Слишком длинная строка получает
+//! `ERR LINE_TOO_LONG`, после чего соединение закрывается.
+
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::net::TcpListener;
+use std::time::Duration;
+
+use crate::cli::Options;
+use crate::errors::{BudgetError, InputError};
+use crate::io::parse_input;
+use crate::logger::Logger;
+use crate::server::{ApiError, accept_loop, compute};
+
+/// Наибольшая длина строки запроса, байт.
+const MAX_LINE_BYTES: usize = 8 * 1024;
+/// Сколько ждать следующий запрос, прежде чем закрыть простаивающее соединение.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Отвечает на один запрос протокола (строку без `\n`).
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::Options;
+/// use fibonacci_stable::line_protocol::respond;
+///
+/// assert_eq!(respond("FIB 42", &Options::default()), "OK 267914296");
+/// assert_eq!(respond("FIB 187", &Options::default()), "ERR COMPUTATION_OVERFLOW 187");
+/// assert_eq!(respond("FIB abc", &Options::default()), "ERR INVALID_CHARACTERS");
+/// ```
+pub fn respond(request: &str, options: &Options) -> String {
+    let request = request.trim_start();
+    let (command, argument) = request
+        .split_once(char::is_whitespace)
+        .unwrap_or((request, ""));
+
+    match command {
+        "FIB" => match parse_input(argument).map_err(ApiError::from) {
+            Ok(n) => compute(n, options),
+            Err(e) => Err(e),
+        }
+        .map_or_else(|e| error_reply(&e), |value| format!("OK {}", value)),
+        "" => error_reply(&ApiError::Input(InputError::EmptyInput)),
+        _ => "ERR UNKNOWN_COMMAND".to_string(),
+    }
+}
+
+fn error_reply(error: &ApiError) -> String {
+    let index = match error {
+        ApiError::Input(InputError::ComputationOverflow(n))
+        | ApiError::Budget(
+            BudgetError::Digits { n, .. }
+            | BudgetError::Memory { n, .. }
+            | BudgetError::Time { n, .. },
+        ) => Some(n),
+        _ => None,
+    };
+    match index {
+        Some(n) => format!("ERR {} {}", error.code(), n),
+        None => format!("ERR {}", error.code()),
+    }
+}
+
+/// Обслуживает одно постоянное соединение: читает запросы из `input`, пока он
+/// не закончится или не придёт `QUIT`, и пишет ответы в `output`.
+///
+/// Ответы сбрасываются в `output`, только когда во входном буфере не осталось
+/// прочитанных запросов, поэтому пачка запросов получает пачку ответов.
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::{Logger, Options};
+/// use fibonacci_stable::line_protocol::handle_connection;
+///
+/// let mut output = Vec::new();
+/// let mut logger = Logger::new();
+///
+/// handle_connection("FIB 10\nFIB 187\nQUIT\nFIB 1\n".as_bytes(), &mut output, &Options::default(), &mut logger)
+///     .unwrap();
+///
+/// assert_eq!(String::from_utf8(output).unwrap(), "OK 55\nERR COMPUTATION_OVERFLOW 187\nBYE\n");
+/// ```
+pub fn handle_connection(
+    input: impl Read,
+    output: impl Write,
+    options: &Options,
+    logger: &mut Logger,
+) -> io::Result<()> {
+    let mut input = BufReader::new(input);
+    let mut output = BufWriter::new(output);
+    let mut line = Vec::new();
+    let mut requests = 0u64;
+
+    loop {
+        line.clear();
+        let read = input
+            .by_ref()
+            .take(MAX_LINE_BYTES as u64)
+            .read_until(b'\n', &mut line)?;
+        if read == 0 {
+            break;
+        }
+        if read == MAX_LINE_BYTES && line.last() != Some(&b'\n') {
+            writeln!(output, "ERR LINE_TOO_LONG")?;
+            logger.error("Слишком длинная строка запроса, соединение закрыто");
+            break;
+        }
+
+        let request = String::from_utf8_lossy(&line);
+        let request = request.trim_end_matches(['\n', '\r']);
+        if request.trim() == "QUIT" {
+            writeln!(output, "BYE")?;
+            break;
+        }
+        requests += 1;
+        writeln!(output, "{}", respond(request, options))?;
+
+        if input.buffer().is_empty() {
+            output.flush()?;
+        }
+    }
+
+    output.flush()?;
+    logger.log(&format!("Соединение закрыто, запросов: {}", requests));
+    Ok(())
+}
+
+/// Принимает соединения на `listener` и обслуживает каждое в отдельном потоке.
+///
+/// Лимит соединений и обработка ошибок `accept()` — как у [`server::serve()`](crate::server::serve).
+pub fn serve(listener: TcpListener, options: Options) -> io::Result<()> {
+    accept_loop(listener, options, |stream, options, logger| {
+        stream.set_nodelay(true)?;
+        stream.set_read_timeout(Some(IDLE_TIMEOUT))?;
+        handle_connection(stream, stream, options, logger)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Mode;
+
+    fn session(script: &str, options: &Options) -> String {
+        let mut output = Vec::new();
+        let mut logger = Logger::new();
+        handle_connection(script.as_bytes(), &mut output, options, &mut logger).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn given_pipelined_requests_when_handle_connection_then_answers_in_order() {
+        // Given
+        let script = "FIB 0\nFIB 1\r\nFIB 2\nFIB 93\nFIB 186\n";
+
+        // When
+        let output = session(script, &Options::default());
+
+        // Then
+        assert_eq!(
+            output,
+            "OK 0\nOK 1\nOK 1\nOK 12200160415121876738\nOK 332825110087067562321196029789634457848\n"
+        );
+    }
+
+    #[test]
+    fn given_invalid_requests_when_respond_then_returns_error_codes() {
+        // Given
+        let options = Options::default();
+
+        // When / Then
+        assert_eq!(respond("FIB -1", &options), "ERR NEGATIVE_NUMBER");
+        assert_eq!(respond("FIB 1.5", &options), "ERR FLOAT_NUMBER");
+        assert_eq!(respond("FIB 1 2", &options), "ERR MULTIPLE_VALUES");
+        assert_eq!(respond("FIB", &options), "ERR EMPTY_INPUT");
+        assert_eq!(respond("", &options), "ERR EMPTY_INPUT");
+        assert_eq!(respond("fib 1", &options), "ERR UNKNOWN_COMMAND");
+        assert_eq!(respond("PING", &options), "ERR UNKNOWN_COMMAND");
+    }
+
+    #[test]
+    fn given_over_budget_when_respond_then_returns_budget_code_with_index() {
+        // Given
+        let mut options = Options {
+            mode: Mode::Big,
+            ..Options::default()
+        };
+        options.budget.max_digits = Some(10);
+
+        // When
+        let reply = respond("FIB 1000", &options);
+
+        // Then
+        assert_eq!(reply, "ERR BUDGET_DIGITS 1000");
+    }
+
+    #[test]
+    fn given_empty_and_non_utf8_lines_when_handle_connection_then_each_gets_reply() {
+        // Given
+        let mut script = b"\nFIB ".to_vec();
+        script.extend_from_slice(&[0xff, 0xfe, b'\n']);
+
+        // When
+        let mut output = Vec::new();
+        let mut logger = Logger::new();
+        handle_connection(&script[..], &mut output, &Options::default(), &mut logger).unwrap();
+
+        // Then
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "ERR EMPTY_INPUT\nERR INVALID_CHARACTERS\n"
+        );
+        assert!(logger.entries()[0].contains("запросов: 2"));
+    }
+
+    #[test]
+    fn given_too_long_line_when_handle_connection_then_closes_with_error() {
+        // Given
+        let script = format!("FIB {}\nFIB 1\n", "1".repeat(MAX_LINE_BYTES));
+
+        // When
+        let output = session(&script, &Options::default());
+
+        // Then
+        assert_eq!(output, "ERR LINE_TOO_LONG\n");
+    }
+}
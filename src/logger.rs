@@ -1,7 +1,10 @@
 /// Простой логгер с таймстемпами.
 ///
 /// Записывает сообщения в формате `[YYYY-MM-DD HH:MM:SS.mmm] сообщение`.
+/// Без feature `clock` (например, в сборке для WebAssembly, где нет системных часов)
+/// таймстемп не добавляется и запись равна сообщению.
 /// Используется для отслеживания действий пользователя и этапов вычисления.
+/// С feature `serde` сериализуется массивом записей.
 ///
 /// # Пример
 ///
@@ -12,8 +15,22 @@
 /// logger.log("Программа запущена");
 /// assert_eq!(logger.entries().len(), 1);
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Logger {
     entries: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    level: LogLevel,
+}
+
+/// Уровень логирования: какие записи сохраняет [`Logger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogLevel {
+    /// Все записи: действия пользователя, этапы вычисления и ошибки (по умолчанию).
+    #[default]
+    Info,
+    /// Только ошибки, записанные через [`Logger::error()`].
+    Error,
 }
 
 impl Default for Logger {
@@ -23,19 +40,54 @@ impl Default for Logger {
 }
 
 impl Logger {
-    /// Создаёт новый пустой логгер.
+    /// Создаёт новый пустой логгер уровня [`LogLevel::Info`].
     pub fn new() -> Self {
+        Self::with_level(LogLevel::Info)
+    }
+
+    /// Создаёт новый пустой логгер с заданным уровнем.
+    ///
+    /// ```
+    /// use fibonacci_stable::Logger;
+    /// use fibonacci_stable::logger::LogLevel;
+    ///
+    /// let mut logger = Logger::with_level(LogLevel::Error);
+    /// logger.log("Программа запущена");
+    /// logger.error("Пустой ввод");
+    /// assert_eq!(logger.entries().len(), 1);
+    /// ```
+    pub fn with_level(level: LogLevel) -> Self {
         Logger {
             entries: Vec::new(),
+            level,
         }
     }
 
-    /// Записывает сообщение с текущим таймстемпом.
+    /// Записывает сообщение, если уровень логгера — [`LogLevel::Info`].
     pub fn log(&mut self, message: &str) {
+        if self.level == LogLevel::Info {
+            self.push(message);
+        }
+    }
+
+    /// Записывает сообщение об ошибке при любом уровне логгера.
+    pub fn error(&mut self, message: &str) {
+        self.push(message);
+    }
+
+    /// Добавляет запись с текущим таймстемпом.
+    #[cfg(feature = "clock")]
+    fn push(&mut self, message: &str) {
         let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f");
         self.entries.push(format!("[{}] {}", now, message));
     }
 
+    /// Добавляет запись без таймстемпа (сборка без feature `clock`).
+    #[cfg(not(feature = "clock"))]
+    fn push(&mut self, message: &str) {
+        self.entries.push(message.to_string());
+    }
+
     /// Возвращает все записи лога.
     pub fn entries(&self) -> &[String] {
         &self.entries
@@ -69,6 +121,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "clock")]
     fn given_logger_when_log_message_then_entry_contains_timestamp() {
         // Given
         let mut logger = Logger::new();
@@ -105,6 +158,7 @@ mod tests {
 
     // 4. Формат записи соответствует паттерну [YYYY-MM-DD HH:MM:SS.mmm] сообщение
     #[test]
+    #[cfg(feature = "clock")]
     fn given_logger_when_log_then_entry_matches_format() {
         // Given
         let mut logger = Logger::new();
@@ -122,6 +176,50 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(not(feature = "clock"))]
+    fn given_logger_without_clock_when_log_then_entry_equals_message() {
+        // Given
+        let mut logger = Logger::new();
+
+        // When
+        logger.log("hello");
+
+        // Then
+        assert_eq!(logger.entries(), ["hello"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn given_logger_when_serialize_then_array_of_entries() {
+        // Given
+        let mut logger = Logger::new();
+        logger.log("первое");
+        logger.log("второе");
+
+        // When
+        let json = serde_json::to_string(&logger).unwrap();
+        let restored: Logger = serde_json::from_str(&json).unwrap();
+
+        // Then
+        assert!(json.starts_with("[\"") && json.ends_with("второе\"]"));
+        assert_eq!(restored.entries(), logger.entries());
+    }
+
+    #[test]
+    fn given_error_level_when_log_and_error_then_keeps_only_errors() {
+        // Given
+        let mut logger = Logger::with_level(LogLevel::Error);
+
+        // When
+        logger.log("Программа запущена");
+        logger.error("Некорректный ввод");
+
+        // Then
+        assert_eq!(logger.entries().len(), 1);
+        assert!(logger.entries()[0].ends_with("Некорректный ввод"));
+    }
+
     use proptest::prelude::*;
 
     proptest! {
@@ -1,40 +1,68 @@
-use fibonacci_stable::{InputError, Logger, fibonacci, print_result, read_input};
+use std::io;
+use std::net::TcpListener;
 
-fn main() {
-    let mut logger = Logger::new();
+use fibonacci_stable::app::run;
+use fibonacci_stable::config::Config;
+use fibonacci_stable::{
+    Command, Logger, Protocol, identities, line_protocol, parse_args_with, server,
+};
 
-    logger.log("Программа запущена");
+fn main() {
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
 
-    loop {
-        let n = match read_input(&mut logger) {
-            Some(n) => n,
-            None => {
-                logger.log("Пользователь вышел из программы");
-                println!("До свидания!");
-                break;
-            }
-        };
+    let options = match parse_args_with(&config, std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
 
-        logger.log(&format!("Старт вычисления fibonacci({})", n));
+    if let Command::Verify { up_to } = options.command {
+        let report = identities::verify_up_to(up_to);
+        print!("{}", report);
+        if !report.is_ok() {
+            std::process::exit(1);
+        }
+        return;
+    }
 
-        match fibonacci(n) {
-            Ok(result) => {
-                logger.log(&format!("Конец вычисления: fibonacci({}) = {}", n, result));
-                print_result(n, result);
-                logger.log("Результат выведен в терминал");
-                break;
-            }
-            Err(_) => {
-                let error = InputError::ComputationOverflow(n);
-                logger.log(&format!("{}", error));
-                println!("{}", error);
-                println!("Попробуйте снова или введите 'q' для выхода:");
+    if let Command::Serve { addr, protocol } = options.command {
+        let result = TcpListener::bind(addr).and_then(|listener| {
+            let local = listener.local_addr()?;
+            match protocol {
+                Protocol::Http => {
+                    println!("Сервер запущен: http://{}", local);
+                    server::serve(listener, options)
+                }
+                Protocol::Line => {
+                    println!("Сервер запущен: tcp://{} (строковый протокол)", local);
+                    line_protocol::serve(listener, options)
+                }
             }
+        });
+        if let Err(e) = result {
+            eprintln!("Ошибка сервера: {}", e);
+            std::process::exit(1);
         }
+        return;
     }
 
-    println!("\n--- Логи ---");
-    for entry in logger.entries() {
-        println!("{}", entry);
+    let mut logger = Logger::with_level(options.log_level);
+
+    if let Err(e) = run(
+        &options,
+        io::stdin().lock(),
+        io::stdout().lock(),
+        &mut logger,
+    ) {
+        eprintln!("Ошибка ввода-вывода: {}", e);
+        std::process::exit(1);
     }
 }
@@ -0,0 +1,448 @@
+//! Q-матрица Фибоначчи и многочлены Фибоначчи и Люка.
+//!
+//! Степени матрицы `Q = [[1, 1], [1, 0]]` дают соседние числа Фибоначчи:
+//! `Q^n = [[F(n+1), F(n)], [F(n), F(n−1)]]`. [`Mat2`] возводится в степень
+//! при любой [`OverflowPolicy`] и в кольце вычетов по модулю `m`.
+//!
+//! ```
+//! use fibonacci_stable::OverflowPolicy;
+//! use fibonacci_stable::matrix::Mat2;
+//!
+//! assert_eq!(
+//!     Mat2::Q.pow(10, OverflowPolicy::Checked),
+//!     Ok(Mat2([[89, 55], [55, 34]]))
+//! );
+//! assert_eq!(Mat2::Q.pow_mod(1_000_000_000_000, 1_000_000_007).unwrap().0[0][1], 730_695_249);
+//! ```
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::errors::ComputationError;
+use crate::fibonacci::OverflowPolicy;
+use crate::modular::{add_mod, mul_mod};
+
+/// Матрица 2×2 над `u128`, по строкам: `Mat2([[a, b], [c, d]])`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mat2(pub [[u128; 2]; 2]);
+
+impl Mat2 {
+    /// Единичная матрица.
+    pub const IDENTITY: Mat2 = Mat2([[1, 0], [0, 1]]);
+
+    /// Q-матрица Фибоначчи `[[1, 1], [1, 0]]`.
+    pub const Q: Mat2 = Mat2([[1, 1], [1, 0]]);
+
+    /// Произведение `self · rhs`; `None` при переполнении `u128`.
+    ///
+    /// ```
+    /// use fibonacci_stable::matrix::Mat2;
+    ///
+    /// assert_eq!(Mat2::Q.checked_mul(Mat2::Q), Some(Mat2([[2, 1], [1, 1]])));
+    /// assert_eq!(Mat2([[u128::MAX, 0], [0, 0]]).checked_mul(Mat2([[2, 0], [0, 0]])), None);
+    /// ```
+    pub fn checked_mul(self, rhs: Mat2) -> Option<Mat2> {
+        self.combine(rhs, |x, y, z, w| {
+            x.checked_mul(y)?.checked_add(z.checked_mul(w)?)
+        })
+    }
+
+    /// Произведение `self · rhs` с политикой переполнения.
+    ///
+    /// `None` возможен только для [`OverflowPolicy::Checked`]; `Wrapping` считает
+    /// по модулю 2^128, `Saturating` ограничивает каждый элемент `u128::MAX`.
+    pub fn mul_with_policy(self, rhs: Mat2, policy: OverflowPolicy) -> Option<Mat2> {
+        match policy {
+            OverflowPolicy::Checked => self.checked_mul(rhs),
+            OverflowPolicy::Wrapping => self.combine(rhs, |x, y, z, w| {
+                Some(x.wrapping_mul(y).wrapping_add(z.wrapping_mul(w)))
+            }),
+            OverflowPolicy::Saturating => self.combine(rhs, |x, y, z, w| {
+                Some(x.saturating_mul(y).saturating_add(z.saturating_mul(w)))
+            }),
+        }
+    }
+
+    /// Произведение `self · rhs` по модулю `m`.
+    ///
+    /// # Ошибки
+    ///
+    /// * `Err(ComputationError::ZeroModulus)` — если `m == 0`
+    pub fn mul_mod(self, rhs: Mat2, m: u128) -> Result<Mat2, ComputationError> {
+        if m == 0 {
+            return Err(ComputationError::ZeroModulus);
+        }
+        let (lhs, rhs) = (self.reduce(m), rhs.reduce(m));
+        Ok(lhs
+            .combine(rhs, |x, y, z, w| {
+                Some(add_mod(mul_mod(x, y, m), mul_mod(z, w, m), m))
+            })
+            .expect("арифметика по модулю не переполняется"))
+    }
+
+    /// Степень `self^n` с политикой переполнения за `O(log n)` умножений.
+    ///
+    /// Для [`OverflowPolicy::Checked`] ошибкой считается переполнение результата
+    /// или любой промежуточной степени `self^k`, `k < n`. Для `Saturating`
+    /// каждый элемент равен `min(точное значение, u128::MAX)`, если элементы
+    /// `self` неотрицательны — для `u128` это всегда так.
+    ///
+    /// # Ошибки
+    ///
+    /// * `Err(ComputationError::Overflow(n))` — переполнение при `Checked`
+    ///
+    /// # Примеры
+    ///
+    /// ```
+    /// use fibonacci_stable::OverflowPolicy;
+    /// use fibonacci_stable::matrix::Mat2;
+    ///
+    /// // Q^185 = [[F(186), F(185)], [F(185), F(184)]], а F(187) уже не помещается
+    /// assert!(Mat2::Q.pow(185, OverflowPolicy::Checked).is_ok());
+    /// assert!(Mat2::Q.pow(186, OverflowPolicy::Checked).is_err());
+    /// assert_eq!(
+    ///     Mat2::Q.pow(300, OverflowPolicy::Saturating).unwrap(),
+    ///     Mat2([[u128::MAX; 2]; 2])
+    /// );
+    /// ```
+    pub fn pow(self, n: u128, policy: OverflowPolicy) -> Result<Mat2, ComputationError> {
+        self.power(n, Mat2::IDENTITY, |x, y| x.mul_with_policy(y, policy))
+            .ok_or(ComputationError::Overflow(n))
+    }
+
+    /// Степень `self^n` по модулю `m` для любых `n` и `m`.
+    ///
+    /// # Ошибки
+    ///
+    /// * `Err(ComputationError::ZeroModulus)` — если `m == 0`
+    ///
+    /// # Примеры
+    ///
+    /// ```
+    /// use fibonacci_stable::matrix::Mat2;
+    ///
+    /// assert_eq!(Mat2::Q.pow_mod(10, 7), Ok(Mat2([[89 % 7, 55 % 7], [55 % 7, 34 % 7]])));
+    /// assert_eq!(Mat2::Q.pow_mod(0, 1), Ok(Mat2([[0; 2]; 2])));
+    /// assert!(Mat2::Q.pow_mod(10, 0).is_err());
+    /// ```
+    pub fn pow_mod(self, n: u128, m: u128) -> Result<Mat2, ComputationError> {
+        if m == 0 {
+            return Err(ComputationError::ZeroModulus);
+        }
+        let base = self.reduce(m);
+        Ok(base
+            .power(n, Mat2::IDENTITY.reduce(m), |x, y| x.mul_mod(y, m).ok())
+            .expect("арифметика по модулю не переполняется"))
+    }
+
+    /// Двоичное возведение в степень слева направо: промежуточные значения —
+    /// степени `self^k` с `k ≤ n`, лишнего последнего возведения в квадрат нет.
+    fn power(
+        self,
+        n: u128,
+        identity: Mat2,
+        mul: impl Fn(Mat2, Mat2) -> Option<Mat2>,
+    ) -> Option<Mat2> {
+        let mut result = identity;
+        for i in (0..u128::BITS - n.leading_zeros()).rev() {
+            result = mul(result, result)?;
+            if (n >> i) & 1 == 1 {
+                result = mul(result, self)?;
+            }
+        }
+        Some(result)
+    }
+
+    /// Произведение строк на столбцы: `dot(x, y, z, w)` считает `x·y + z·w`.
+    fn combine(
+        self,
+        rhs: Mat2,
+        dot: impl Fn(u128, u128, u128, u128) -> Option<u128>,
+    ) -> Option<Mat2> {
+        let ([[a, b], [c, d]], [[e, f], [g, h]]) = (self.0, rhs.0);
+        Some(Mat2([
+            [dot(a, e, b, g)?, dot(a, f, b, h)?],
+            [dot(c, e, d, g)?, dot(c, f, d, h)?],
+        ]))
+    }
+
+    /// Элементы по модулю `m`.
+    fn reduce(self, m: u128) -> Mat2 {
+        Mat2(self.0.map(|row| row.map(|x| x % m)))
+    }
+}
+
+impl fmt::Display for Mat2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [[a, b], [c, d]] = self.0;
+        write!(f, "[[{}, {}], [{}, {}]]", a, b, c, d)
+    }
+}
+
+/// Коэффициенты многочлена Фибоначчи `F_n(x)` по возрастанию степеней.
+///
+/// `F_0 = 0`, `F_1 = 1`, `F_n = x·F_{n−1} + F_{n−2}`; коэффициент при
+/// `x^(n−1−2k)` равен биномиальному `C(n−1−k, k)`, а `F_n(1) = F(n)`.
+/// Нулевой многочлен `F_0` — пустой вектор.
+///
+/// # Ошибки
+///
+/// * `Err(ComputationError::Overflow(n))` — если коэффициент не помещается в `u128`
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::matrix::fibonacci_polynomial;
+///
+/// // F_5(x) = x⁴ + 3x² + 1
+/// assert_eq!(fibonacci_polynomial(5), Ok(vec![1, 0, 3, 0, 1]));
+/// assert_eq!(fibonacci_polynomial(0), Ok(vec![]));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn fibonacci_polynomial(n: u128) -> Result<Vec<u128>, ComputationError> {
+    polynomial(n, Vec::new(), vec![1])
+}
+
+/// Коэффициенты многочлена Люка `L_n(x)` по возрастанию степеней.
+///
+/// `L_0 = 2`, `L_1 = x`, `L_n = x·L_{n−1} + L_{n−2}`; `L_n(1) = L(n)` —
+/// числа Люка, и `L_n = F_{n−1} + F_{n+1}`.
+///
+/// # Ошибки
+///
+/// * `Err(ComputationError::Overflow(n))` — если коэффициент не помещается в `u128`
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::matrix::lucas_polynomial;
+///
+/// // L_4(x) = x⁴ + 4x² + 2
+/// assert_eq!(lucas_polynomial(4), Ok(vec![2, 0, 4, 0, 1]));
+/// assert_eq!(lucas_polynomial(0), Ok(vec![2]));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn lucas_polynomial(n: u128) -> Result<Vec<u128>, ComputationError> {
+    polynomial(n, vec![2], vec![0, 1])
+}
+
+/// n-й член последовательности многочленов `P_k = x·P_{k−1} + P_{k−2}`.
+#[cfg(feature = "alloc")]
+fn polynomial(n: u128, first: Vec<u128>, second: Vec<u128>) -> Result<Vec<u128>, ComputationError> {
+    let (mut previous, mut current) = (first, second);
+    if n == 0 {
+        return Ok(previous);
+    }
+    for _ in 1..n {
+        let mut next = current.clone();
+        next.insert(0, 0);
+        for (coefficient, &term) in next.iter_mut().zip(&previous) {
+            *coefficient = coefficient
+                .checked_add(term)
+                .ok_or(ComputationError::Overflow(n))?;
+        }
+        (previous, current) = (current, next);
+    }
+    Ok(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fibonacci::{fibonacci, fibonacci_with_policy};
+    use crate::modular::fibonacci_mod;
+
+    #[test]
+    fn given_q_matrix_when_pow_checked_then_contains_neighbouring_fibonacci_numbers() {
+        for n in 1..=185u128 {
+            // Given / When
+            let result = Mat2::Q.pow(n, OverflowPolicy::Checked);
+
+            // Then
+            let (previous, current, next) = (
+                fibonacci(n - 1).unwrap(),
+                fibonacci(n).unwrap(),
+                fibonacci(n + 1).unwrap(),
+            );
+            assert_eq!(
+                result,
+                Ok(Mat2([[next, current], [current, previous]])),
+                "n={}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn given_zero_exponent_when_pow_then_identity() {
+        // Given
+        let m = Mat2([[5, 7], [11, 13]]);
+
+        // When / Then
+        for policy in OverflowPolicy::ALL {
+            assert_eq!(m.pow(0, policy), Ok(Mat2::IDENTITY));
+        }
+        assert_eq!(m.pow_mod(0, 10), Ok(Mat2::IDENTITY));
+    }
+
+    #[test]
+    fn given_overflowing_power_when_pow_checked_then_overflow_error() {
+        // Given
+        let n = 186;
+
+        // When
+        let result = Mat2::Q.pow(n, OverflowPolicy::Checked);
+
+        // Then
+        assert_eq!(result, Err(ComputationError::Overflow(186)));
+    }
+
+    #[test]
+    fn given_overflowing_power_when_pow_saturating_then_only_large_entries_saturate() {
+        // Given: Q^186 = [[F(187), F(186)], [F(186), F(185)]], F(187) > u128::MAX
+        let n = 186;
+
+        // When
+        let result = Mat2::Q.pow(n, OverflowPolicy::Saturating).unwrap();
+
+        // Then
+        let (previous, current) = (fibonacci(185).unwrap(), fibonacci(186).unwrap());
+        assert_eq!(result, Mat2([[u128::MAX, current], [current, previous]]));
+    }
+
+    #[test]
+    fn given_zero_modulus_when_mul_mod_or_pow_mod_then_error() {
+        // Given / When / Then
+        assert_eq!(
+            Mat2::Q.mul_mod(Mat2::Q, 0),
+            Err(ComputationError::ZeroModulus)
+        );
+        assert_eq!(Mat2::Q.pow_mod(5, 0), Err(ComputationError::ZeroModulus));
+    }
+
+    #[test]
+    fn given_matrix_when_display_then_rows_in_brackets() {
+        // Given / When / Then
+        assert_eq!(Mat2::Q.to_string(), "[[1, 1], [1, 0]]");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn given_fibonacci_polynomials_when_evaluated_at_one_then_fibonacci_numbers() {
+        for n in 0..=120u128 {
+            // Given / When
+            let coefficients = fibonacci_polynomial(n).unwrap();
+
+            // Then
+            assert_eq!(
+                coefficients.iter().sum::<u128>(),
+                fibonacci(n).unwrap(),
+                "n={}",
+                n
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn given_fibonacci_polynomial_when_generated_then_coefficients_are_binomial() {
+        // Given
+        let n = 30usize;
+        let binomial = |m: u128, k: u128| (0..k).fold(1u128, |c, i| c * (m - i) / (i + 1));
+
+        // When
+        let coefficients = fibonacci_polynomial(n as u128).unwrap();
+
+        // Then
+        assert_eq!(coefficients.len(), n);
+        for (power, &coefficient) in coefficients.iter().enumerate() {
+            let expected = if (n - 1 - power).is_multiple_of(2) {
+                let k = ((n - 1 - power) / 2) as u128;
+                binomial(n as u128 - 1 - k, k)
+            } else {
+                0
+            };
+            assert_eq!(coefficient, expected, "x^{}", power);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn given_lucas_polynomial_when_compared_then_sum_of_neighbouring_fibonacci_polynomials() {
+        for n in 1..=100u128 {
+            // Given
+            let previous = fibonacci_polynomial(n - 1).unwrap();
+            let next = fibonacci_polynomial(n + 1).unwrap();
+
+            // When
+            let lucas = lucas_polynomial(n).unwrap();
+
+            // Then
+            let mut expected = next;
+            for (coefficient, term) in expected.iter_mut().zip(previous) {
+                *coefficient += term;
+            }
+            assert_eq!(lucas, expected, "n={}", n);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn given_huge_degree_when_fibonacci_polynomial_then_overflow_error() {
+        // Given
+        let n = 1_000;
+
+        // When
+        let result = fibonacci_polynomial(n);
+
+        // Then
+        assert_eq!(result, Err(ComputationError::Overflow(1_000)));
+    }
+
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn given_any_power_when_pow_wrapping_then_matches_wrapping_fibonacci(n in 1u128..) {
+            // Given / When
+            let result = Mat2::Q.pow(n, OverflowPolicy::Wrapping).unwrap();
+
+            // Then
+            let fib = fibonacci_with_policy(n, OverflowPolicy::Wrapping).unwrap();
+            prop_assert_eq!(result.0[0][1], fib);
+            prop_assert_eq!(result.0[1][0], fib);
+        }
+
+        #[test]
+        fn given_any_power_and_modulus_when_pow_mod_then_matches_fibonacci_mod(
+            n: u128,
+            m in 1u128..,
+        ) {
+            // Given / When
+            let result = Mat2::Q.pow_mod(n, m).unwrap();
+
+            // Then
+            prop_assert_eq!(Ok(result.0[0][1]), fibonacci_mod(n, m));
+        }
+
+        #[test]
+        fn given_two_exponents_when_pow_mod_then_powers_multiply(
+            a in 0u128..1 << 64,
+            b in 0u128..1 << 64,
+            m in 1u128..1 << 64,
+        ) {
+            // Given
+            let base = Mat2([[3, 1], [4, 1]]);
+
+            // When
+            let product = base.pow_mod(a, m).unwrap().mul_mod(base.pow_mod(b, m).unwrap(), m);
+
+            // Then
+            prop_assert_eq!(product, base.pow_mod(a + b, m));
+        }
+    }
+}
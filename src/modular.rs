@@ -0,0 +1,342 @@
+use core::fmt;
+
+use crate::errors::ComputationError;
+use crate::fibonacci::{FIBONACCI_TABLE, FIBONACCI_TABLE_LEN};
+
+/// Вычисляет fib(n) mod m для любого индекса и модуля `u128`.
+///
+/// Быстрое удвоение в кольце вычетов: `O(log n)` умножений по модулю, без
+/// переполнения для любых `n` и `m`.
+///
+/// # Ошибки
+///
+/// * `Err(ComputationError::ZeroModulus)` — если `m == 0`
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::modular::fibonacci_mod;
+///
+/// assert_eq!(fibonacci_mod(10, 7), Ok(55 % 7));
+/// assert_eq!(fibonacci_mod(1_000_000_000_000, 1_000_000_007), Ok(730_695_249));
+/// assert!(fibonacci_mod(10, 0).is_err());
+/// ```
+pub fn fibonacci_mod(n: u128, m: u128) -> Result<u128, ComputationError> {
+    if m == 0 {
+        return Err(ComputationError::ZeroModulus);
+    }
+    Ok(fibonacci_pair_mod(n, m).0)
+}
+
+/// `(fib(n) mod m, fib(n+1) mod m)` для `m > 0`; fib(n+1) доступно и при `n = u128::MAX`.
+pub(crate) fn fibonacci_pair_mod(n: u128, m: u128) -> (u128, u128) {
+    let bits = u128::BITS - n.leading_zeros();
+
+    // (a, b) = (fib(k) mod m, fib(k+1) mod m), где k — старшие биты n.
+    let (mut a, mut b) = (0, 1 % m);
+    for i in (0..bits).rev() {
+        let diff = sub_mod(add_mod(b, b, m), a, m);
+        let even = mul_mod(a, diff, m);
+        let odd = add_mod(mul_mod(a, a, m), mul_mod(b, b, m), m);
+
+        (a, b) = if (n >> i) & 1 == 1 {
+            (odd, add_mod(even, odd, m))
+        } else {
+            (even, odd)
+        };
+    }
+    (a, b)
+}
+
+/// Наибольшее `k` для [`fibonacci_last_digits()`]: 10^38 — наибольшая степень 10 в `u128`.
+pub const MAX_LAST_DIGITS: u32 = 38;
+
+/// Последние `k` десятичных цифр F(n), то есть fib(n) mod 10^k.
+///
+/// Индекс сначала сокращается по периоду Пизано π(10^k), поэтому цифры
+/// доступны для любого `n`, даже когда F(n) невозможно записать целиком.
+///
+/// Возвращает `None`, если `k` равно 0 или больше [`MAX_LAST_DIGITS`].
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::modular::fibonacci_last_digits;
+///
+/// assert_eq!(fibonacci_last_digits(100, 5), Some(15_075)); // F(100) = 354224848179261915075
+/// assert_eq!(fibonacci_last_digits(10, 5), Some(55));
+/// assert_eq!(fibonacci_last_digits(10, 0), None);
+/// ```
+pub fn fibonacci_last_digits(n: u128, k: u32) -> Option<u128> {
+    if k == 0 || k > MAX_LAST_DIGITS {
+        return None;
+    }
+    fibonacci_mod(n % pisano_period_pow10(k), 10u128.pow(k)).ok()
+}
+
+/// Последние цифры F(n) для вывода.
+///
+/// Выводится как `…0012345`: нули в начале сохраняются до `digits` знаков, а
+/// многоточие означает, что у F(n) есть и более старшие цифры.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LastDigits {
+    /// fib(n) mod 10^`digits`.
+    pub trailing: u128,
+    /// Количество запрошенных цифр.
+    pub digits: u32,
+    /// `true`, если `trailing` — всё число F(n).
+    pub exact: bool,
+}
+
+impl fmt::Display for LastDigits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.exact {
+            write!(f, "{}", self.trailing)
+        } else {
+            write!(
+                f,
+                "…{:0width$}",
+                self.trailing,
+                width = self.digits as usize
+            )
+        }
+    }
+}
+
+/// Последние `k` цифр F(n) вместе с признаком того, что это всё число.
+///
+/// Возвращает `None`, если `k` равно 0 или больше [`MAX_LAST_DIGITS`].
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::modular::fibonacci_tail;
+///
+/// assert_eq!(fibonacci_tail(10, 5).unwrap().to_string(), "55");
+/// assert_eq!(fibonacci_tail(60, 5).unwrap().to_string(), "…55920"); // F(60) = 1548008755920
+/// assert_eq!(fibonacci_tail(1_000_000_000_000_000_000, 3).unwrap().to_string(), "…875");
+/// ```
+pub fn fibonacci_tail(n: u128, k: u32) -> Option<LastDigits> {
+    let trailing = fibonacci_last_digits(n, k)?;
+    // F(n) < 10^k ≤ 10^38 помещается в u128, то есть есть в таблице.
+    let exact = n < FIBONACCI_TABLE_LEN as u128 && FIBONACCI_TABLE[n as usize] < 10u128.pow(k);
+    Some(LastDigits {
+        trailing,
+        digits: k,
+        exact,
+    })
+}
+
+/// Период Пизано π(10^k): 60, 300 и 15·10^(k−1) при k ≥ 3.
+fn pisano_period_pow10(k: u32) -> u128 {
+    match k {
+        1 => 60,
+        2 => 300,
+        _ => 15 * 10u128.pow(k - 1),
+    }
+}
+
+/// (a + b) mod m для a, b < m.
+pub(crate) fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    let (sum, overflow) = a.overflowing_add(b);
+    if overflow || sum >= m {
+        sum.wrapping_sub(m)
+    } else {
+        sum
+    }
+}
+
+/// (a − b) mod m для a, b < m.
+pub(crate) fn sub_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= b { a - b } else { a + (m - b) }
+}
+
+/// (a · b) mod m для a, b < m.
+///
+/// При m < 2^64 произведение помещается в `u128`; иначе — умножение сложением
+/// с удвоением, чтобы не выходить за 128 бит.
+pub(crate) fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    if m <= u64::MAX as u128 {
+        return a * b % m;
+    }
+    let (mut result, mut a, mut b) = (0, a, b);
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    result
+}
+
+/// Полное произведение `a · b` в 256 битах: `(старшие, младшие)`.
+#[cfg(feature = "alloc")]
+pub(crate) fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_low, a_high) = (a & MASK, a >> 64);
+    let (b_low, b_high) = (b & MASK, b >> 64);
+
+    let low = a_low * b_low;
+    let mid1 = a_low * b_high;
+    let mid2 = a_high * b_low;
+    let high = a_high * b_high;
+
+    let (mid, mid_carry) = mid1.overflowing_add(mid2);
+    let (low, low_carry) = low.overflowing_add(mid << 64);
+    let high = high + (mid >> 64) + ((mid_carry as u128) << 64) + low_carry as u128;
+    (high, low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fibonacci::fibonacci;
+
+    #[test]
+    fn given_fitting_indices_when_fibonacci_mod_then_matches_remainder() {
+        // Given
+        for n in 0u128..=186 {
+            for m in [
+                1u128,
+                2,
+                10,
+                97,
+                1_000_000_007,
+                u64::MAX as u128 + 13,
+                u128::MAX,
+            ] {
+                // When
+                let result = fibonacci_mod(n, m);
+
+                // Then
+                assert_eq!(result, Ok(fibonacci(n).unwrap() % m), "n={}, m={}", n, m);
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn given_big_index_when_fibonacci_mod_then_matches_big_remainder() {
+        // Given: fib(1000) mod 10^20 — последние 20 цифр
+        let digits = crate::fibonacci::fibonacci_big(1000).to_string();
+        let expected: u128 = digits[digits.len() - 20..].parse().unwrap();
+
+        // When
+        let result = fibonacci_mod(1000, 10u128.pow(20));
+
+        // Then
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn given_zero_modulus_when_fibonacci_mod_then_returns_error() {
+        // Given
+        let m = 0;
+
+        // When
+        let result = fibonacci_mod(5, m);
+
+        // Then
+        assert_eq!(result, Err(ComputationError::ZeroModulus));
+    }
+
+    #[test]
+    fn given_modulus_near_u128_max_when_mul_mod_then_no_overflow() {
+        // Given
+        let m = u128::MAX;
+        let a = u128::MAX - 1;
+
+        // When
+        let result = mul_mod(a, a, m);
+
+        // Then: (−1)·(−1) = 1 mod m
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn given_every_power_of_ten_when_pisano_period_then_sequence_restarts() {
+        for k in 1..=MAX_LAST_DIGITS {
+            // Given
+            let (period, m) = (pisano_period_pow10(k), 10u128.pow(k));
+
+            // When
+            let restart = (fibonacci_mod(period, m), fibonacci_mod(period + 1, m));
+
+            // Then
+            assert_eq!(restart, (Ok(0), Ok(1)), "k={}", k);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn given_big_index_when_fibonacci_last_digits_then_matches_big_suffix() {
+        // Given
+        let digits = crate::fibonacci::fibonacci_big(12_345).to_string();
+
+        for k in 1..=MAX_LAST_DIGITS {
+            // When
+            let result = fibonacci_last_digits(12_345, k);
+
+            // Then
+            let expected = digits[digits.len() - k as usize..].parse().unwrap();
+            assert_eq!(result, Some(expected), "k={}", k);
+        }
+    }
+
+    #[test]
+    fn given_zero_or_too_many_digits_when_fibonacci_last_digits_then_none() {
+        // Given / When / Then
+        assert_eq!(fibonacci_last_digits(10, 0), None);
+        assert_eq!(fibonacci_last_digits(10, MAX_LAST_DIGITS + 1), None);
+        assert_eq!(fibonacci_tail(10, 0), None);
+    }
+
+    #[test]
+    fn given_short_and_long_values_when_fibonacci_tail_then_pads_only_truncated() {
+        // Given: F(25) = 75025, F(30) = 832040
+        let cases = [
+            (25, 3, "…025"),
+            (25, 5, "75025"),
+            (30, 38, "832040"),
+            (u128::MAX, 1, "…0"),
+        ];
+
+        for (n, k, expected) in cases {
+            // When
+            let tail = fibonacci_tail(n, k).unwrap();
+
+            // Then
+            assert_eq!(tail.to_string(), expected, "n={}, k={}", n, k);
+        }
+    }
+
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn given_any_index_when_fibonacci_mod_2_pow_64_then_matches_wrapping_u64(n: u128) {
+            // Given
+            let m = 1u128 << 64;
+
+            // When
+            let result = fibonacci_mod(n, m).unwrap();
+
+            // Then
+            let wrapped = crate::fibonacci_in::<std::num::Wrapping<u64>>(n).unwrap();
+            prop_assert_eq!(result, wrapped.0 as u128);
+        }
+
+        #[test]
+        fn given_any_index_when_fibonacci_last_digits_then_matches_fibonacci_mod(n: u128, k in 1..=MAX_LAST_DIGITS) {
+            // Given
+            let m = 10u128.pow(k);
+
+            // When
+            let result = fibonacci_last_digits(n, k);
+
+            // Then
+            prop_assert_eq!(result, fibonacci_mod(n, m).ok());
+        }
+    }
+}
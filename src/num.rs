@@ -0,0 +1,352 @@
+use core::num::{Saturating, Wrapping};
+
+use crate::errors::ComputationError;
+use crate::fibonacci::FIBONACCI_TABLE_LEN;
+
+/// Числовой тип, в котором можно вычислять числа Фибоначчи.
+///
+/// Реализован для всех примитивных целых типов (`u8` ..= `u128`, `i8` ..= `i128`,
+/// `usize`, `isize`), а также для [`Wrapping<T>`] и [`Saturating<T>`] над ними.
+/// Для собственного типа достаточно задать нули/единицы и три операции,
+/// возвращающие `None` при переполнении.
+///
+/// # Семантика переполнения
+///
+/// * примитивные типы — `Err(ComputationError::Overflow(n))`, если результат не помещается в тип
+/// * [`Wrapping<T>`] — результат по модулю `2^BITS`, ошибки не бывает
+/// * [`Saturating<T>`] — `T::MAX`, если результат не помещается в тип, ошибки не бывает
+///
+/// # Пример собственного типа
+///
+/// ```
+/// use fibonacci_stable::{FibNum, fibonacci_in, max_index};
+///
+/// /// Вычет по модулю 10 — последняя цифра числа.
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct LastDigit(u8);
+///
+/// impl FibNum for LastDigit {
+///     const ZERO: Self = LastDigit(0);
+///     const ONE: Self = LastDigit(1);
+///
+///     fn checked_add(self, rhs: Self) -> Option<Self> {
+///         Some(LastDigit((self.0 + rhs.0) % 10))
+///     }
+///     fn checked_sub(self, rhs: Self) -> Option<Self> {
+///         Some(LastDigit((self.0 + 10 - rhs.0) % 10))
+///     }
+///     fn checked_mul(self, rhs: Self) -> Option<Self> {
+///         Some(LastDigit(self.0 * rhs.0 % 10))
+///     }
+/// }
+///
+/// // fib(100) = 354224848179261915075
+/// assert_eq!(fibonacci_in::<LastDigit>(100), Ok(LastDigit(5)));
+/// // Вычеты не переполняются.
+/// assert_eq!(max_index::<LastDigit>(), u128::MAX);
+/// ```
+pub trait FibNum: Copy {
+    /// fib(0).
+    const ZERO: Self;
+    /// fib(1).
+    const ONE: Self;
+
+    /// Сложение; `None` при переполнении.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Вычитание; `None` при переполнении.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Умножение; `None` при переполнении.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Наибольший индекс `n`, для которого fib(n) помещается в тип.
+    ///
+    /// Реализация по умолчанию идёт по последовательности до первого переполнения.
+    /// Если тип выдерживает больше шагов, чем `u128` (186), считается, что он
+    /// не переполняется вовсе, и возвращается `u128::MAX` — как для [`Wrapping<T>`],
+    /// [`Saturating<T>`] и вычетов. Типы шире `u128` должны переопределять этот метод.
+    fn max_index() -> u128 {
+        const U128_MAX_INDEX: u128 = FIBONACCI_TABLE_LEN as u128 - 1;
+
+        let (mut a, mut b) = (Self::ZERO, Self::ONE);
+        let mut n = 1;
+        while let Some(next) = b.checked_add(a) {
+            (a, b) = (b, next);
+            n += 1;
+            if n > U128_MAX_INDEX {
+                return u128::MAX;
+            }
+        }
+        n
+    }
+
+    /// Вычисляет fib(n) в этом типе.
+    ///
+    /// Реализация по умолчанию — быстрое удвоение за `O(log n)` операций:
+    ///
+    /// * fib(2k) = fib(k) · (2·fib(k+1) − fib(k))
+    /// * fib(2k+1) = fib(k)² + fib(k+1)²
+    ///
+    /// Промежуточные значения не превышают fib(n), поэтому `Err` возвращается
+    /// только тогда, когда сам результат не помещается в тип.
+    fn fibonacci_of(n: u128) -> Result<Self, ComputationError> {
+        if n == 0 {
+            return Ok(Self::ZERO);
+        }
+
+        let overflow = || ComputationError::Overflow(n);
+        let bits = u128::BITS - n.leading_zeros();
+
+        // (a, b) = (fib(k), fib(k+1)), где k — старшие биты n.
+        let (mut a, mut b) = (Self::ZERO, Self::ONE);
+        for i in (1..bits).rev() {
+            let twice_b = b.checked_add(b).ok_or_else(overflow)?;
+            let even = a
+                .checked_mul(twice_b.checked_sub(a).ok_or_else(overflow)?)
+                .ok_or_else(overflow)?;
+            let odd = a
+                .checked_mul(a)
+                .and_then(|a2| b.checked_mul(b).and_then(|b2| a2.checked_add(b2)))
+                .ok_or_else(overflow)?;
+
+            (a, b) = if (n >> i) & 1 == 1 {
+                (odd, even.checked_add(odd).ok_or_else(overflow)?)
+            } else {
+                (even, odd)
+            };
+        }
+
+        // Последний шаг считает только fib(n), чтобы не переполниться на fib(n+1).
+        if n & 1 == 1 {
+            a.checked_mul(a)
+                .and_then(|a2| b.checked_mul(b).and_then(|b2| a2.checked_add(b2)))
+        } else {
+            b.checked_add(b)
+                .and_then(|twice_b| twice_b.checked_sub(a))
+                .and_then(|diff| a.checked_mul(diff))
+        }
+        .ok_or_else(overflow)
+    }
+}
+
+macro_rules! impl_fib_num {
+    ($($t:ty),*) => {$(
+        impl FibNum for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        }
+
+        impl FibNum for Wrapping<$t> {
+            const ZERO: Self = Wrapping(0);
+            const ONE: Self = Wrapping(1);
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                Some(self + rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                Some(self - rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                Some(self * rhs)
+            }
+
+            fn max_index() -> u128 {
+                u128::MAX
+            }
+        }
+
+        impl FibNum for Saturating<$t> {
+            const ZERO: Self = Saturating(0);
+            const ONE: Self = Saturating(1);
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                Some(self + rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                Some(self - rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                Some(self * rhs)
+            }
+
+            fn max_index() -> u128 {
+                u128::MAX
+            }
+
+            // Насыщение в промежуточных шагах удвоения исказило бы результат,
+            // поэтому считаем точно и насыщаем только ответ.
+            fn fibonacci_of(n: u128) -> Result<Self, ComputationError> {
+                Ok(Saturating(<$t>::fibonacci_of(n).unwrap_or(<$t>::MAX)))
+            }
+        }
+    )*};
+}
+
+impl_fib_num!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fibonacci::fibonacci;
+
+    #[test]
+    fn given_u128_when_fibonacci_of_then_matches_fibonacci() {
+        // Given
+        for n in 0u128..=200 {
+            // When
+            let result = u128::fibonacci_of(n);
+
+            // Then
+            assert_eq!(result, fibonacci(n), "n={}", n);
+        }
+    }
+
+    #[test]
+    fn given_boundaries_when_fibonacci_of_then_overflows_exactly_after_limit() {
+        // Given: последние индексы, помещающиеся в тип
+        // When / Then
+        assert_eq!(u8::fibonacci_of(13), Ok(233));
+        assert!(u8::fibonacci_of(14).is_err());
+        assert_eq!(i8::fibonacci_of(11), Ok(89));
+        assert!(i8::fibonacci_of(12).is_err());
+        assert_eq!(u32::fibonacci_of(47), Ok(2971215073));
+        assert!(u32::fibonacci_of(48).is_err());
+        assert_eq!(u64::fibonacci_of(93), Ok(12200160415121876738));
+        assert!(u64::fibonacci_of(94).is_err());
+        assert_eq!(i64::fibonacci_of(92), Ok(7540113804746346429));
+        assert!(i64::fibonacci_of(93).is_err());
+        assert!(i128::fibonacci_of(184).is_ok());
+        assert!(i128::fibonacci_of(185).is_err());
+    }
+
+    #[test]
+    fn given_overflow_when_fibonacci_of_then_error_contains_input() {
+        // Given
+        let input = 1000;
+
+        // When
+        let result = u64::fibonacci_of(input);
+
+        // Then
+        assert_eq!(result, Err(ComputationError::Overflow(1000)));
+    }
+
+    #[test]
+    fn given_wrapping_when_fibonacci_of_then_returns_value_modulo_2_pow_bits() {
+        // Given: fib(100) mod 2^64
+        let expected = (fibonacci(100).unwrap() % (1u128 << 64)) as u64;
+
+        // When
+        let result = Wrapping::<u64>::fibonacci_of(100);
+
+        // Then
+        assert_eq!(result, Ok(Wrapping(expected)));
+    }
+
+    #[test]
+    fn given_huge_index_when_wrapping_fibonacci_of_then_returns_without_iterating() {
+        // Given: период fib mod 2^8 равен 3·2^7 = 384
+        let n = u128::MAX - (u128::MAX % 384) + 100;
+
+        // When
+        let result = Wrapping::<u8>::fibonacci_of(n);
+
+        // Then
+        assert_eq!(result, Wrapping::<u8>::fibonacci_of(100));
+    }
+
+    #[test]
+    fn given_saturating_when_fibonacci_of_overflows_then_returns_max() {
+        // Given / When / Then
+        assert_eq!(Saturating::<u8>::fibonacci_of(13), Ok(Saturating(233)));
+        assert_eq!(Saturating::<u8>::fibonacci_of(14), Ok(Saturating(u8::MAX)));
+        assert_eq!(
+            Saturating::<u128>::fibonacci_of(u128::MAX),
+            Ok(Saturating(u128::MAX))
+        );
+    }
+
+    #[test]
+    fn given_primitive_types_when_max_index_then_returns_last_fitting_index() {
+        // Given / When / Then
+        assert_eq!(u8::max_index(), 13);
+        assert_eq!(i8::max_index(), 11);
+        assert_eq!(u16::max_index(), 24);
+        assert_eq!(u32::max_index(), 47);
+        assert_eq!(i32::max_index(), 46);
+        assert_eq!(u64::max_index(), 93);
+        assert_eq!(i64::max_index(), 92);
+        assert_eq!(u128::max_index(), 186);
+        assert_eq!(i128::max_index(), 184);
+    }
+
+    #[test]
+    fn given_non_overflowing_types_when_max_index_then_returns_u128_max() {
+        // Given / When / Then
+        assert_eq!(Wrapping::<u8>::max_index(), u128::MAX);
+        assert_eq!(Saturating::<u64>::max_index(), u128::MAX);
+    }
+
+    #[test]
+    fn given_custom_type_without_overflow_when_default_max_index_then_returns_u128_max() {
+        // Given: вычет по модулю 7 без переопределённого max_index
+        #[derive(Clone, Copy)]
+        struct Mod7(u8);
+
+        impl FibNum for Mod7 {
+            const ZERO: Self = Mod7(0);
+            const ONE: Self = Mod7(1);
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                Some(Mod7((self.0 + rhs.0) % 7))
+            }
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                Some(Mod7((self.0 + 7 - rhs.0) % 7))
+            }
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                Some(Mod7(self.0 * rhs.0 % 7))
+            }
+        }
+
+        // When
+        let result = Mod7::max_index();
+
+        // Then
+        assert_eq!(result, u128::MAX);
+    }
+
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn given_any_index_when_wrapping_u32_then_matches_truncated_u128(n in 0u128..=186) {
+            // Given
+            let exact = fibonacci(n).unwrap();
+
+            // When
+            let result = Wrapping::<u32>::fibonacci_of(n).unwrap();
+
+            // Then
+            prop_assert_eq!(result.0, exact as u32);
+        }
+    }
+}
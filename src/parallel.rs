@@ -0,0 +1,221 @@
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use crate::bigint::BigUint;
+use crate::errors::ComputationError;
+use crate::fibonacci::{OverflowPolicy, fibonacci_big_by, fibonacci_with_policy};
+
+/// Начиная с этого размера fib(n) (в битах) три произведения шага удвоения
+/// считаются в отдельных потоках.
+const PARALLEL_STEP_BITS: u64 = 64 * 512;
+
+/// Количество потоков по умолчанию — число доступных ядер.
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Применяет `f` ко всем индексам на `threads` потоках.
+///
+/// Порядок результатов совпадает с порядком `indices` независимо от числа потоков
+/// и того, какой поток что посчитал. Потоки забирают индексы по одному,
+/// поэтому дорогие и дешёвые индексы распределяются равномерно.
+/// `threads` ≤ 1 — вычисление в текущем потоке.
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::parallel::map_indices;
+///
+/// let squares = map_indices(&[3, 1, 2], 4, |n| n * n);
+/// assert_eq!(squares, vec![9, 1, 4]);
+/// ```
+pub fn map_indices<T, F>(indices: &[u128], threads: usize, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(u128) -> T + Sync,
+{
+    let workers = threads.min(indices.len());
+    if workers <= 1 {
+        return indices.iter().map(|&n| f(n)).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut slots: Vec<Option<T>> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        match indices.get(i) {
+                            Some(&n) => done.push((i, f(n))),
+                            None => return done,
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        let mut slots: Vec<Option<T>> = (0..indices.len()).map(|_| None).collect();
+        for handle in handles {
+            for (i, value) in handle.join().expect("рабочий поток не паникует")
+            {
+                slots[i] = Some(value);
+            }
+        }
+        slots
+    });
+
+    slots
+        .iter_mut()
+        .map(|slot| {
+            slot.take()
+                .expect("каждый индекс обработан ровно одним потоком")
+        })
+        .collect()
+}
+
+/// Вычисляет числа Фибоначчи в `u128` для многих индексов параллельно.
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::OverflowPolicy;
+/// use fibonacci_stable::parallel::fibonacci_many;
+///
+/// let results = fibonacci_many(&[10, 187, 0], OverflowPolicy::Checked, 2);
+/// assert_eq!(results[0], Ok(55));
+/// assert!(results[1].is_err());
+/// assert_eq!(results[2], Ok(0));
+/// ```
+pub fn fibonacci_many(
+    indices: &[u128],
+    policy: OverflowPolicy,
+    threads: usize,
+) -> Vec<Result<u128, ComputationError>> {
+    map_indices(indices, threads, |n| fibonacci_with_policy(n, policy))
+}
+
+/// Точно вычисляет fib(x), распределяя умножения по `threads` потокам.
+///
+/// На каждом шаге быстрого удвоения три независимых произведения считаются
+/// параллельно, а каждое из них — параллельной Карацубой
+/// ([`BigUint::mul_parallel`]). Результат совпадает с [`fibonacci_big()`](crate::fibonacci_big).
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::fibonacci_big;
+/// use fibonacci_stable::parallel::fibonacci_big_parallel;
+///
+/// assert_eq!(fibonacci_big_parallel(50_000, 4), fibonacci_big(50_000));
+/// ```
+pub fn fibonacci_big_parallel(x: u128, threads: usize) -> BigUint {
+    if threads <= 1 {
+        return fibonacci_big_by(x, |a, diff, b| (a * diff, a * a, b * b));
+    }
+    let share = (threads / 3).max(1);
+    fibonacci_big_by(x, |a, diff, b| {
+        if b.bits() < PARALLEL_STEP_BITS {
+            return (a * diff, a * a, b * b);
+        }
+        thread::scope(|scope| {
+            let even = scope.spawn(|| a.mul_parallel(diff, share));
+            let a2 = scope.spawn(|| a.mul_parallel(a, share));
+            let b2 = b.mul_parallel(b, share);
+            (
+                even.join().expect("поток умножения не паникует"),
+                a2.join().expect("поток умножения не паникует"),
+                b2,
+            )
+        })
+    })
+}
+
+/// Точно вычисляет числа Фибоначчи для многих индексов параллельно.
+///
+/// Индексы распределяются по потокам, каждое значение считается в одном потоке.
+pub fn fibonacci_big_many(indices: &[u128], threads: usize) -> Vec<BigUint> {
+    map_indices(indices, threads, crate::fibonacci::fibonacci_big)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fibonacci::{fibonacci, fibonacci_big};
+
+    #[test]
+    fn given_many_indices_when_fibonacci_many_then_order_matches_input() {
+        // Given
+        let indices: Vec<u128> = (0..=200).rev().collect();
+
+        // When
+        let results = fibonacci_many(&indices, OverflowPolicy::Checked, 8);
+
+        // Then
+        let expected: Vec<_> = indices.iter().map(|&n| fibonacci(n)).collect();
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn given_different_thread_counts_when_map_indices_then_results_identical() {
+        // Given
+        let indices: Vec<u128> = (0..100).map(|i| (i * 37) % 101).collect();
+
+        // When
+        let single = map_indices(&indices, 1, |n| n * 3);
+        let multi = map_indices(&indices, 7, |n| n * 3);
+        let more_threads_than_work = map_indices(&indices[..3], 64, |n| n * 3);
+
+        // Then
+        assert_eq!(single, multi);
+        assert_eq!(more_threads_than_work, single[..3].to_vec());
+    }
+
+    #[test]
+    fn given_empty_input_when_map_indices_then_returns_empty() {
+        // Given
+        let indices: [u128; 0] = [];
+
+        // When
+        let results = map_indices(&indices, 4, |n| n);
+
+        // Then
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn given_big_indices_when_fibonacci_big_many_then_matches_sequential() {
+        // Given
+        let indices = [1000u128, 5, 20_000, 187];
+
+        // When
+        let results = fibonacci_big_many(&indices, 3);
+
+        // Then
+        let expected: Vec<_> = indices.iter().map(|&n| fibonacci_big(n)).collect();
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn given_wide_result_when_fibonacci_big_parallel_then_matches_sequential() {
+        // Given: fib(200000) — около 2170 разрядов, шаги идут параллельно
+        let input = 200_000;
+
+        // When
+        let parallel = fibonacci_big_parallel(input, 6);
+
+        // Then
+        assert_eq!(parallel, fibonacci_big(input));
+    }
+
+    #[test]
+    fn given_machine_when_default_threads_then_at_least_one() {
+        // Given / When
+        let threads = default_threads();
+
+        // Then
+        assert!(threads >= 1);
+    }
+}
@@ -0,0 +1,650 @@
+//! Простота и разложение чисел Фибоначчи.
+//!
+//! * [`is_prime()`] — тест Миллера — Рабина по первым 13 простым основаниям,
+//!   детерминированный для `n < 3.3·10^24`; для больших `n` дополнен сильным
+//!   тестом Люка (BPSW), контрпримеров к которому не известно;
+//! * [`is_fibonacci_prime()`] — простота F(n);
+//! * [`factor_fibonacci()`] — разложение F(n): простые делители F(d) для `d | n`
+//!   делят и F(n), поэтому методом Полларда (ро-алгоритм) раскладывается
+//!   только остаток из «примитивных» делителей;
+//! * [`alpha()`] — ранг появления: наименьшее `k > 0`, для которого `p | F(k)`.
+//!
+//! Умножение по модулю до 2^128 — в форме Монтгомери, без переполнения.
+//!
+//! ```
+//! use fibonacci_stable::primes::{alpha, factor_fibonacci, is_fibonacci_prime};
+//!
+//! assert_eq!(is_fibonacci_prime(83), Ok(true));
+//! assert_eq!(factor_fibonacci(12), Ok(vec![(2, 4), (3, 2)])); // F(12) = 144
+//! assert_eq!(alpha(7), Some(8)); // F(8) = 21
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::errors::ComputationError;
+use crate::fibonacci::fibonacci;
+use crate::modular::{fibonacci_mod, mul_mod, widening_mul};
+
+/// Основания теста Миллера — Рабина: первые 13 простых.
+const BASES: [u128; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// Наименьшее составное число, проходящее тест по всем [`BASES`] (ψ₁₃,
+/// Соренсон и Вебстер, 2015). Ниже него тест Миллера — Рабина детерминирован.
+const DETERMINISTIC_BOUND: u128 = 3_317_044_064_679_887_385_961_981;
+
+/// Проверяет, простое ли `n`.
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::primes::is_prime;
+///
+/// assert!(is_prime(2) && is_prime(1_000_000_007));
+/// assert!(is_prime(u128::MAX - 158)); // 2^128 − 159
+/// assert!(!is_prime(561)); // число Кармайкла
+/// ```
+pub fn is_prime(n: u128) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in BASES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    let mont = Montgomery::new(n);
+    if !BASES.iter().all(|&base| mont.strong_probable_prime(base)) {
+        return false;
+    }
+    n < DETERMINISTIC_BOUND || mont.strong_lucas_probable_prime()
+}
+
+/// Проверяет, простое ли F(n).
+///
+/// # Ошибки
+///
+/// * `Err(ComputationError::Overflow(n))` — если F(n) не помещается в `u128` (`n > 186`)
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::primes::is_fibonacci_prime;
+///
+/// let indices: Vec<u128> = (0..=186)
+///     .filter(|&n| is_fibonacci_prime(n).unwrap())
+///     .collect();
+/// assert_eq!(indices, [3, 4, 5, 7, 11, 13, 17, 23, 29, 43, 47, 83, 131, 137]);
+/// assert!(is_fibonacci_prime(187).is_err());
+/// ```
+pub fn is_fibonacci_prime(n: u128) -> Result<bool, ComputationError> {
+    fibonacci(n).map(is_prime)
+}
+
+/// Раскладывает `n` на простые множители: пары `(p, степень)` по возрастанию `p`.
+///
+/// Для `n ≤ 1` возвращает пустой список.
+///
+/// ```
+/// use fibonacci_stable::primes::factor;
+///
+/// assert_eq!(factor(360), vec![(2, 3), (3, 2), (5, 1)]);
+/// ```
+pub fn factor(n: u128) -> Vec<(u128, u32)> {
+    // 0 делится на 2 бесконечно много раз.
+    if n <= 1 {
+        return Vec::new();
+    }
+    let mut primes = Vec::new();
+    collect_primes(n, &mut primes);
+    primes.sort_unstable();
+    let mut factors: Vec<(u128, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((last, count)) if *last == p => *count += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+/// Раскладывает F(n) на простые множители: пары `(p, степень)` по возрастанию `p`.
+///
+/// Простые делители F(n/q) для простых `q | n` находятся рекурсивно и делят
+/// F(n); остаток содержит только делители, впервые появляющиеся в F(n),
+/// и раскладывается ро-алгоритмом Полларда. Для F(0) = 0, F(1) = F(2) = 1
+/// возвращает пустой список.
+///
+/// # Ошибки
+///
+/// * `Err(ComputationError::Overflow(n))` — если F(n) не помещается в `u128` (`n > 186`)
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::primes::factor_fibonacci;
+///
+/// // F(60) = 1548008755920 = 2^4 · 3^2 · 5 · 11 · 31 · 41 · 61 · 2521
+/// let factors = factor_fibonacci(60).unwrap();
+/// assert_eq!(
+///     factors,
+///     [(2, 4), (3, 2), (5, 1), (11, 1), (31, 1), (41, 1), (61, 1), (2521, 1)]
+/// );
+/// ```
+pub fn factor_fibonacci(n: u128) -> Result<Vec<(u128, u32)>, ComputationError> {
+    let mut rest = fibonacci(n)?;
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut known: Vec<u128> = Vec::new();
+    for (q, _) in factor(n) {
+        for (p, _) in factor_fibonacci(n / q)? {
+            if !known.contains(&p) {
+                known.push(p);
+            }
+        }
+    }
+
+    let mut factors = Vec::new();
+    for p in known {
+        let mut count = 0;
+        while rest.is_multiple_of(p) {
+            rest /= p;
+            count += 1;
+        }
+        factors.push((p, count));
+    }
+    factors.extend(factor(rest));
+    factors.sort_unstable();
+    Ok(factors)
+}
+
+/// Ранг появления простого `p`: наименьшее `k > 0`, для которого `p | F(k)`.
+///
+/// `α(p)` делит `p − (5/p)`, где `(5/p)` — символ Лежандра, поэтому достаточно
+/// разложить `p ± 1` и отбросить лишние множители. Для `p = 5` ранг равен 5.
+/// Возвращает `None`, если `p` не простое.
+///
+/// ```
+/// use fibonacci_stable::primes::alpha;
+///
+/// assert_eq!(alpha(2), Some(3));
+/// assert_eq!(alpha(11), Some(10));
+/// assert_eq!(alpha(2521), Some(60));
+/// assert_eq!(alpha(12), None);
+/// ```
+pub fn alpha(p: u128) -> Option<u128> {
+    if !is_prime(p) {
+        return None;
+    }
+    if p == 5 {
+        return Some(5);
+    }
+    let mut k = if matches!(p % 5, 1 | 4) { p - 1 } else { p + 1 };
+    for (q, _) in factor(k) {
+        while k.is_multiple_of(q) && fibonacci_mod(k / q, p) == Ok(0) {
+            k /= q;
+        }
+    }
+    Some(k)
+}
+
+/// Добавляет в `primes` простые делители `n` с повторениями.
+fn collect_primes(mut n: u128, primes: &mut Vec<u128>) {
+    for p in BASES {
+        while n.is_multiple_of(p) {
+            primes.push(p);
+            n /= p;
+        }
+    }
+    if n == 1 {
+        return;
+    }
+    if is_prime(n) {
+        primes.push(n);
+        return;
+    }
+    let divisor = pollard_rho(n);
+    collect_primes(divisor, primes);
+    collect_primes(n / divisor, primes);
+}
+
+/// Нетривиальный делитель нечётного составного `n` (ро-алгоритм Полларда — Брента).
+fn pollard_rho(n: u128) -> u128 {
+    /// Число шагов между вычислениями НОД.
+    const BATCH: u64 = 128;
+
+    let mont = Montgomery::new(n);
+    for c in 1.. {
+        let c = mont.to_mont(c);
+        let step = |x| mont.add(mont.mul(x, x), c);
+        let (mut x, mut y, mut saved) = (0, mont.to_mont(2), 0);
+        let (mut product, mut divisor, mut range) = (mont.to_mont(1), 1, 1);
+
+        while divisor == 1 {
+            x = y;
+            for _ in 0..range {
+                y = step(y);
+            }
+            let mut done = 0;
+            while done < range && divisor == 1 {
+                saved = y;
+                for _ in 0..BATCH.min(range - done) {
+                    y = step(y);
+                    product = mont.mul(product, x.abs_diff(y));
+                }
+                divisor = gcd(product, n);
+                done += BATCH;
+            }
+            range *= 2;
+        }
+        if divisor == n {
+            // Произведение за пачку обнулилось — повторяем её по одному шагу.
+            loop {
+                saved = step(saved);
+                divisor = gcd(x.abs_diff(saved), n);
+                if divisor != 1 {
+                    break;
+                }
+            }
+        }
+        if divisor != n {
+            return divisor;
+        }
+    }
+    unreachable!("перебор констант c бесконечен")
+}
+
+/// Наибольший общий делитель (бинарный алгоритм).
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    if a == 0 || b == 0 {
+        return a | b;
+    }
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+    loop {
+        b >>= b.trailing_zeros();
+        if a > b {
+            core::mem::swap(&mut a, &mut b);
+        }
+        b -= a;
+        if b == 0 {
+            return a << shift;
+        }
+    }
+}
+
+/// Символ Якоби `(a/n)` для нечётного `n`.
+fn jacobi(mut a: u128, mut n: u128) -> i8 {
+    a %= n;
+    let mut result = 1;
+    while a != 0 {
+        let twos = a.trailing_zeros();
+        a >>= twos;
+        if twos % 2 == 1 && matches!(n % 8, 3 | 5) {
+            result = -result;
+        }
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        (a, n) = (n % a, a);
+    }
+    if n == 1 { result } else { 0 }
+}
+
+/// Арифметика по нечётному модулю `n` в форме Монтгомери: `x` хранится как `x·2^128 mod n`.
+struct Montgomery {
+    n: u128,
+    /// `−n⁻¹ mod 2^128`.
+    n_neg_inv: u128,
+    /// `2^256 mod n` — множитель для перевода в форму Монтгомери.
+    r2: u128,
+}
+
+impl Montgomery {
+    fn new(n: u128) -> Self {
+        debug_assert!(n % 2 == 1);
+        // Итерации Ньютона: каждая удваивает число верных бит обратного (3 → 192).
+        let mut inv = n;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u128.wrapping_sub(n.wrapping_mul(inv)));
+        }
+        let r = (u128::MAX % n + 1) % n;
+        Montgomery {
+            n,
+            n_neg_inv: inv.wrapping_neg(),
+            r2: mul_mod(r, r, n),
+        }
+    }
+
+    fn to_mont(&self, x: u128) -> u128 {
+        self.mul(x % self.n, self.r2)
+    }
+
+    /// `a · b · 2^-128 mod n`.
+    fn mul(&self, a: u128, b: u128) -> u128 {
+        let (high, low) = widening_mul(a, b);
+        let m = low.wrapping_mul(self.n_neg_inv);
+        let (mn_high, mn_low) = widening_mul(m, self.n);
+        // low + mn_low ≡ 0 (mod 2^128): перенос есть, если low ≠ 0.
+        let carry = (low.overflowing_add(mn_low).1) as u128;
+        let (sum, overflow) = high.overflowing_add(mn_high);
+        let (sum, overflow2) = sum.overflowing_add(carry);
+        if overflow || overflow2 || sum >= self.n {
+            sum.wrapping_sub(self.n)
+        } else {
+            sum
+        }
+    }
+
+    fn add(&self, a: u128, b: u128) -> u128 {
+        let (sum, overflow) = a.overflowing_add(b);
+        if overflow || sum >= self.n {
+            sum.wrapping_sub(self.n)
+        } else {
+            sum
+        }
+    }
+
+    fn sub(&self, a: u128, b: u128) -> u128 {
+        if a >= b { a - b } else { a + (self.n - b) }
+    }
+
+    /// `x / 2 mod n`.
+    fn half(&self, x: u128) -> u128 {
+        if x.is_multiple_of(2) {
+            x / 2
+        } else {
+            x / 2 + self.n / 2 + 1
+        }
+    }
+
+    fn pow(&self, base: u128, mut exp: u128) -> u128 {
+        let (mut result, mut base) = (self.to_mont(1), base);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Сильный тест Ферма (раунд Миллера — Рабина) по основанию `base`.
+    fn strong_probable_prime(&self, base: u128) -> bool {
+        let n = self.n;
+        let s = (n - 1).trailing_zeros();
+        let (one, minus_one) = (self.to_mont(1), self.to_mont(n - 1));
+        let mut x = self.pow(self.to_mont(base), (n - 1) >> s);
+        if x == one || x == minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = self.mul(x, x);
+            if x == minus_one {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Сильный тест Люка с параметрами Селфриджа: `P = 1`, `Q = (1 − D)/4`,
+    /// где `D` — первое из 5, −7, 9, −11, … с `(D/n) = −1`.
+    fn strong_lucas_probable_prime(&self) -> bool {
+        let n = self.n;
+        if is_square(n) {
+            return false;
+        }
+        let mut d: i128 = 5;
+        loop {
+            let d_mod = if d > 0 {
+                d as u128
+            } else {
+                n - d.unsigned_abs()
+            };
+            match jacobi(d_mod, n) {
+                -1 => break,
+                0 if d.unsigned_abs() != n => return false,
+                _ => d = if d > 0 { -(d + 2) } else { -d + 2 },
+            }
+        }
+        let reduce = |value: i128| {
+            let residue = value.unsigned_abs() % n;
+            let residue = self.to_mont(residue);
+            if value < 0 {
+                self.sub(0, residue)
+            } else {
+                residue
+            }
+        };
+        let (d, q) = (reduce(d), reduce((1 - d) / 4));
+        let two = self.to_mont(2);
+
+        // n + 1 = k · 2^s; U_k, V_k и Q^k — удвоением по битам k.
+        let s = (n + 1).trailing_zeros();
+        let k = (n + 1) >> s;
+        let (mut u, mut v, mut qk) = (0, two, self.to_mont(1));
+        for i in (0..u128::BITS - k.leading_zeros()).rev() {
+            u = self.mul(u, v);
+            v = self.sub(self.mul(v, v), self.add(qk, qk));
+            qk = self.mul(qk, qk);
+            if (k >> i) & 1 == 1 {
+                (u, v) = (
+                    self.half(self.add(u, v)),
+                    self.half(self.add(self.mul(d, u), v)),
+                );
+                qk = self.mul(qk, q);
+            }
+        }
+        if u == 0 || v == 0 {
+            return true;
+        }
+        for _ in 1..s {
+            v = self.sub(self.mul(v, v), self.add(qk, qk));
+            qk = self.mul(qk, qk);
+            if v == 0 {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Проверяет, является ли `n` полным квадратом.
+fn is_square(n: u128) -> bool {
+    let root = n.isqrt();
+    root * root == n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Решето Эратосфена до `limit` включительно.
+    fn sieve(limit: usize) -> Vec<bool> {
+        let mut prime = vec![true; limit + 1];
+        prime[0] = false;
+        prime[1] = false;
+        for i in 2..=limit {
+            if prime[i] {
+                for j in (i * i..=limit).step_by(i) {
+                    prime[j] = false;
+                }
+            }
+        }
+        prime
+    }
+
+    #[test]
+    fn given_small_numbers_when_is_prime_then_matches_sieve() {
+        // Given
+        let prime = sieve(20_000);
+
+        for (n, &expected) in prime.iter().enumerate() {
+            // When / Then
+            assert_eq!(is_prime(n as u128), expected, "n={}", n);
+        }
+    }
+
+    #[test]
+    fn given_strong_pseudoprimes_when_is_prime_then_composite() {
+        // Given: числа Кармайкла и сильные псевдопростые по первым основаниям
+        let composites = [
+            561,
+            3_215_031_751,
+            3_825_123_056_546_413_051,
+            318_665_857_834_031_151_167_461,
+            DETERMINISTIC_BOUND,
+            ((1u128 << 61) - 1) * ((1u128 << 61) - 1),
+            u128::MAX,
+        ];
+
+        for n in composites {
+            // When / Then
+            assert!(!is_prime(n), "n={}", n);
+        }
+    }
+
+    #[test]
+    fn given_large_primes_when_is_prime_then_prime() {
+        // Given: числа Мерсенна 2^89 − 1, 2^107 − 1, 2^127 − 1 и 2^128 − 159
+        let primes = [
+            (1u128 << 61) - 1,
+            (1u128 << 89) - 1,
+            (1u128 << 107) - 1,
+            (1u128 << 127) - 1,
+            u128::MAX - 158,
+        ];
+
+        for p in primes {
+            // When / Then
+            assert!(is_prime(p), "p={}", p);
+        }
+    }
+
+    #[test]
+    fn given_all_fitting_indices_when_factor_fibonacci_then_product_of_primes() {
+        for n in 1..=186 {
+            // Given
+            let value = fibonacci(n).unwrap();
+
+            // When
+            let factors = factor_fibonacci(n).unwrap();
+
+            // Then
+            let product = factors.iter().fold(1u128, |acc, &(p, k)| acc * p.pow(k));
+            assert_eq!(product, value, "n={}", n);
+            assert!(
+                factors.iter().all(|&(p, k)| is_prime(p) && k > 0),
+                "n={}",
+                n
+            );
+            assert!(factors.windows(2).all(|w| w[0].0 < w[1].0), "n={}", n);
+        }
+    }
+
+    #[test]
+    fn given_zero_or_one_when_factor_then_empty() {
+        // Given / When / Then
+        assert_eq!(factor(0), Vec::new());
+        assert_eq!(factor(1), Vec::new());
+    }
+
+    #[test]
+    fn given_overflowing_index_when_factor_fibonacci_then_overflow_error() {
+        // Given / When
+        let result = factor_fibonacci(187);
+
+        // Then
+        assert_eq!(result, Err(ComputationError::Overflow(187)));
+    }
+
+    #[test]
+    fn given_small_primes_when_alpha_then_matches_first_divisible_index() {
+        // Given
+        let prime = sieve(3000);
+
+        for p in (2..=3000).filter(|&p| prime[p]) {
+            // When
+            let rank = alpha(p as u128).unwrap();
+
+            // Then: перебор F(k) mod p до первого нуля
+            let first = (1..).find(|&k| fibonacci_mod(k, p as u128) == Ok(0));
+            assert_eq!(Some(rank), first, "p={}", p);
+        }
+    }
+
+    #[test]
+    fn given_large_prime_when_alpha_then_divides_and_is_minimal() {
+        // Given: 2^127 − 1 ≡ 2 (mod 5), значит α(p) | p + 1
+        let p = (1u128 << 127) - 1;
+
+        // When
+        let rank = alpha(p).unwrap();
+
+        // Then
+        assert_eq!(fibonacci_mod(rank, p), Ok(0));
+        assert_eq!((p + 1) % rank, 0);
+        for (q, _) in factor(rank) {
+            assert_ne!(fibonacci_mod(rank / q, p), Ok(0), "q={}", q);
+        }
+    }
+
+    #[test]
+    fn given_moduli_when_montgomery_mul_then_matches_mul_mod() {
+        // Given
+        let moduli = [
+            3,
+            1_000_000_007,
+            (1u128 << 89) - 1,
+            u128::MAX - 158,
+            u128::MAX,
+        ];
+        let values = [0, 1, 2, 12345678901234567890, u128::MAX / 3, u128::MAX - 1];
+
+        for n in moduli {
+            let mont = Montgomery::new(n);
+            for a in values {
+                for b in values {
+                    // When
+                    let product = mont.mul(mont.to_mont(a), mont.to_mont(b));
+
+                    // Then
+                    let expected = mul_mod(a % n, b % n, n);
+                    assert_eq!(product, mont.to_mont(expected), "{} * {} mod {}", a, b, n);
+                }
+            }
+        }
+    }
+
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn given_any_u64_when_is_prime_then_matches_trial_division(n in 0u128..10_000_000_000) {
+            // Given
+            let trial = n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0);
+
+            // When / Then
+            prop_assert_eq!(is_prime(n), trial);
+        }
+
+        #[test]
+        fn given_product_of_two_values_when_factor_then_product_restored(
+            a in 1u128..1 << 36,
+            b in 1u128..1 << 36,
+        ) {
+            // Given: множители до 2^36 — ро-алгоритму хватает ~2^18 шагов
+            let n = a * b;
+
+            // When
+            let factors = factor(n);
+
+            // Then
+            let product = factors.iter().fold(1u128, |acc, &(p, k)| acc * p.pow(k));
+            prop_assert_eq!(product, n);
+            prop_assert!(factors.iter().all(|&(p, _)| is_prime(p)));
+        }
+    }
+}
@@ -0,0 +1,220 @@
+//! Модуль Python `fibonacci_stable` на PyO3 (feature `python`).
+//!
+//! Сборка и установка в текущее окружение — через maturin (`pyproject.toml`):
+//!
+//! ```text
+//! maturin develop --release
+//! ```
+//!
+//! ```python
+//! import fibonacci_stable
+//!
+//! fibonacci_stable.fib(1000)                  # точное значение, int, до MAX_BIG_DIGITS цифр
+//! fibonacci_stable.fibonacci(186)             # в пределах u128, иначе ComputationOverflowError
+//! fibonacci_stable.fibonacci_range(5, 8)      # [5, 8, 13, 21]
+//! fibonacci_stable.parse_input(" 42 ")        # 42
+//! for value in fibonacci_stable.FibIter(10):  # 55, 89, 144, ...
+//!     ...
+//! ```
+//!
+//! Ошибки ввода поднимают исключения — наследники `FibonacciError(ValueError)` —
+//! по одному на вариант [`InputError`], с двуязычным сообщением из его `Display`.
+//! Результат больше [`MAX_BIG_DIGITS`] цифр — `BudgetExceededError`, тоже наследник
+//! `FibonacciError`.
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyOverflowError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyFloat, PyInt};
+
+use crate::bigint::BigUint;
+use crate::cli::Budget;
+use crate::errors::{BudgetError, InputError};
+use crate::fibonacci::{
+    Backend, Calibration, OverflowPolicy, estimate_with, fibonacci_big,
+    fibonacci_range as range_u128,
+};
+use crate::io::parse_input as parse;
+
+/// Наибольшее количество цифр точного результата [`fib`] и [`FibIter`]: больший
+/// индекс исчерпал бы память процесса вместо исключения.
+const MAX_BIG_DIGITS: u128 = 1_000_000;
+
+create_exception!(
+    fibonacci_stable,
+    FibonacciError,
+    PyValueError,
+    "Базовое исключение для ошибок ввода."
+);
+create_exception!(fibonacci_stable, EmptyInputError, FibonacciError);
+create_exception!(fibonacci_stable, NegativeNumberError, FibonacciError);
+create_exception!(fibonacci_stable, FloatNumberError, FibonacciError);
+create_exception!(fibonacci_stable, InvalidCharactersError, FibonacciError);
+create_exception!(fibonacci_stable, MultipleValuesError, FibonacciError);
+create_exception!(fibonacci_stable, NumberTooLargeError, FibonacciError);
+create_exception!(fibonacci_stable, ComputationOverflowError, FibonacciError);
+create_exception!(fibonacci_stable, InvalidRangeError, FibonacciError);
+create_exception!(fibonacci_stable, BudgetExceededError, FibonacciError);
+
+impl From<InputError> for PyErr {
+    fn from(error: InputError) -> Self {
+        let message = error.to_string();
+        match error {
+            InputError::EmptyInput => EmptyInputError::new_err(message),
+            InputError::NegativeNumber => NegativeNumberError::new_err(message),
+            InputError::FloatNumber => FloatNumberError::new_err(message),
+            InputError::InvalidCharacters => InvalidCharactersError::new_err(message),
+            InputError::MultipleValues => MultipleValuesError::new_err(message),
+            InputError::NumberTooLarge => NumberTooLargeError::new_err(message),
+            InputError::ComputationOverflow(_) => ComputationOverflowError::new_err(message),
+            InputError::InvalidRange { .. } => InvalidRangeError::new_err(message),
+        }
+    }
+}
+
+impl From<BudgetError> for PyErr {
+    fn from(error: BudgetError) -> Self {
+        BudgetExceededError::new_err(error.to_string())
+    }
+}
+
+/// Проверяет, что fib(n) не длиннее [`MAX_BIG_DIGITS`] цифр. Время не оценивается.
+fn check_digits(n: u128) -> PyResult<()> {
+    let budget = Budget {
+        max_digits: Some(MAX_BIG_DIGITS),
+        max_memory_bytes: None,
+        ..Budget::default()
+    };
+    Ok(budget.check(n, &estimate_with(n, Backend::Big, &Calibration::SIZE_ONLY))?)
+}
+
+/// Индекс из объекта Python с теми же ошибками, что у [`parse_input()`](crate::parse_input).
+fn index(value: &Bound<'_, PyAny>) -> PyResult<u128> {
+    if value.is_instance_of::<PyFloat>() {
+        return Err(InputError::FloatNumber.into());
+    }
+    if !value.is_instance_of::<PyInt>() {
+        let name = value.get_type().name()?;
+        return Err(PyTypeError::new_err(format!(
+            "RU: Ошибка! Ожидается int, получено {}\nEN: Error! Expected int, got {}",
+            name, name
+        )));
+    }
+    value.extract::<u128>().or_else(|_| {
+        Err(if value.lt(0)? {
+            InputError::NegativeNumber
+        } else {
+            InputError::NumberTooLarge
+        }
+        .into())
+    })
+}
+
+/// Преобразует [`BigUint`] в `int` через `int.from_bytes`: без ограничения
+/// `sys.set_int_max_str_digits` и без квадратичного разбора строки.
+fn to_py_int<'py>(py: Python<'py>, value: &BigUint) -> PyResult<Bound<'py, PyAny>> {
+    py.get_type::<PyInt>().call_method1(
+        "from_bytes",
+        (PyBytes::new(py, &value.to_bytes_le()), "little"),
+    )
+}
+
+/// fib(n) — точное значение; `BudgetExceededError`, если в нём больше
+/// [`MAX_BIG_DIGITS`] цифр.
+#[pyfunction]
+fn fib<'py>(py: Python<'py>, n: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let n = index(n)?;
+    check_digits(n)?;
+    let value = py.detach(|| fibonacci_big(n));
+    to_py_int(py, &value)
+}
+
+/// fib(n) в пределах `u128`; `ComputationOverflowError` для n > 186.
+#[pyfunction]
+fn fibonacci(n: &Bound<'_, PyAny>) -> PyResult<u128> {
+    let n = index(n)?;
+    crate::fibonacci::fibonacci(n).map_err(|_| InputError::ComputationOverflow(n).into())
+}
+
+/// Список fib(start) ..= fib(end) в пределах `u128`; пустой, если `start > end`.
+#[pyfunction]
+fn fibonacci_range(start: &Bound<'_, PyAny>, end: &Bound<'_, PyAny>) -> PyResult<Vec<u128>> {
+    let (start, end) = (index(start)?, index(end)?);
+    range_u128(start..=end, OverflowPolicy::Checked).map_err(|e| match e {
+        crate::ComputationError::Overflow(n) => InputError::ComputationOverflow(n).into(),
+        e => PyValueError::new_err(e.to_string()),
+    })
+}
+
+/// Проверяет строку как [`parse_input()`](crate::parse_input) и возвращает число.
+#[pyfunction]
+fn parse_input(input: &str) -> PyResult<u128> {
+    Ok(parse(input)?)
+}
+
+/// Бесконечный итератор точных значений fib(start), fib(start + 1), ...
+///
+/// Ограничение [`MAX_BIG_DIGITS`] проверяется только для начальных значений.
+#[pyclass(module = "fibonacci_stable")]
+struct FibIter {
+    current: BigUint,
+    next: BigUint,
+}
+
+#[pymethods]
+impl FibIter {
+    #[new]
+    #[pyo3(signature = (start = None))]
+    fn new(py: Python<'_>, start: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let start = start.map(index).transpose()?.unwrap_or(0);
+        let after = start.checked_add(1).ok_or_else(|| {
+            PyOverflowError::new_err(format!(
+                "RU: Ошибка! Индекс {} + 1 не помещается в u128\nEN: Error! Index {} + 1 does not fit in u128",
+                start, start
+            ))
+        })?;
+        check_digits(after)?;
+        let (current, next) = py.detach(|| (fibonacci_big(start), fibonacci_big(after)));
+        Ok(FibIter { current, next })
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__<'py>(&mut self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let value = to_py_int(py, &self.current)?;
+        let after = &self.current + &self.next;
+        self.current = std::mem::replace(&mut self.next, after);
+        Ok(value)
+    }
+}
+
+#[pymodule]
+fn fibonacci_stable(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add_function(wrap_pyfunction!(fib, m)?)?;
+    m.add_function(wrap_pyfunction!(fibonacci, m)?)?;
+    m.add_function(wrap_pyfunction!(fibonacci_range, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_input, m)?)?;
+    m.add_class::<FibIter>()?;
+
+    m.add("FibonacciError", py.get_type::<FibonacciError>())?;
+    m.add("EmptyInputError", py.get_type::<EmptyInputError>())?;
+    m.add("NegativeNumberError", py.get_type::<NegativeNumberError>())?;
+    m.add("FloatNumberError", py.get_type::<FloatNumberError>())?;
+    m.add(
+        "InvalidCharactersError",
+        py.get_type::<InvalidCharactersError>(),
+    )?;
+    m.add("MultipleValuesError", py.get_type::<MultipleValuesError>())?;
+    m.add("NumberTooLargeError", py.get_type::<NumberTooLargeError>())?;
+    m.add(
+        "ComputationOverflowError",
+        py.get_type::<ComputationOverflowError>(),
+    )?;
+    m.add("InvalidRangeError", py.get_type::<InvalidRangeError>())?;
+    m.add("BudgetExceededError", py.get_type::<BudgetExceededError>())?;
+    m.add("MAX_BIG_DIGITS", MAX_BIG_DIGITS)?;
+    Ok(())
+}
@@ -0,0 +1,239 @@
+//! Поиск Фибоначчи по отсортированным срезам и шаги-числа Фибоначчи.
+//!
+//! [`fibonacci_search()`] делит интервал не пополам, а в отношении соседних
+//! чисел Фибоначчи: `F(k) − 1 = (F(k−1) − 1) + 1 + (F(k−2) − 1)`. Позиции
+//! проверок получаются сложением и вычитанием, а не делением, и сдвигаются к
+//! началу среза. Результат совпадает по смыслу с [`slice::binary_search`].
+//!
+//! ```
+//! use fibonacci_stable::search::{fibonacci_search, fibonacci_steps};
+//!
+//! let primes = [2, 3, 5, 7, 11, 13, 17, 19];
+//! assert_eq!(fibonacci_search(&primes, &11), Ok(4));
+//! assert_eq!(fibonacci_search(&primes, &12), Err(5));
+//!
+//! let steps: Vec<u8> = fibonacci_steps().collect();
+//! assert_eq!(steps, [1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144, 233]);
+//! ```
+
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+
+use crate::fibonacci::FIBONACCI_TABLE;
+use crate::num::FibNum;
+
+/// Ищет `target` в отсортированном по возрастанию срезе.
+///
+/// Как и [`slice::binary_search`], возвращает `Ok(i)` с индексом найденного
+/// элемента (при повторах — любого из них) или `Err(i)` — позицию, куда `target`
+/// можно вставить, сохранив порядок. Сравнений не больше `log_φ n + 1`,
+/// то есть около `1.44·log₂ n`.
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::search::fibonacci_search;
+///
+/// let sorted = [1, 3, 5, 7, 9];
+/// assert_eq!(fibonacci_search(&sorted, &7), Ok(3));
+/// assert_eq!(fibonacci_search(&sorted, &0), Err(0));
+/// assert_eq!(fibonacci_search(&sorted, &10), Err(5));
+/// assert_eq!(fibonacci_search(&[] as &[i32], &1), Err(0));
+/// ```
+pub fn fibonacci_search<T: Ord>(slice: &[T], target: &T) -> Result<usize, usize> {
+    fibonacci_search_by(slice, |probe| probe.cmp(target))
+}
+
+/// Поиск Фибоначчи с функцией сравнения, как [`slice::binary_search_by`].
+///
+/// `compare` возвращает порядок элемента относительно искомого: `Less`, если
+/// элемент меньше, и `Greater`, если больше. Срез должен быть упорядочен
+/// согласно `compare`.
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::search::fibonacci_search_by;
+///
+/// let words = ["apple", "banana", "cherry"];
+/// assert_eq!(fibonacci_search_by(&words, |w| w.len().cmp(&6).then(w.cmp(&"banana"))), Ok(1));
+/// ```
+pub fn fibonacci_search_by<T>(
+    slice: &[T],
+    mut compare: impl FnMut(&T) -> Ordering,
+) -> Result<usize, usize> {
+    let len = slice.len() as u128;
+    // Интервал неизвестных элементов [low, low + F(k) − 1); позиции за концом
+    // среза считаются бесконечно большими. F(186) > usize::MAX, поэтому k есть всегда.
+    let mut k = FIBONACCI_TABLE
+        .iter()
+        .position(|&f| f > len)
+        .expect("F(186) больше длины любого среза");
+    let mut low = 0usize;
+    while k > 2 {
+        let probe = low as u128 + FIBONACCI_TABLE[k - 1] - 1;
+        let ordering = if probe < len {
+            compare(&slice[probe as usize])
+        } else {
+            Ordering::Greater
+        };
+        match ordering {
+            Ordering::Less => {
+                low = probe as usize + 1;
+                k -= 2;
+            }
+            Ordering::Greater => k -= 1,
+            Ordering::Equal => return Ok(probe as usize),
+        }
+    }
+    Err(low)
+}
+
+/// Итератор по числам Фибоначчи 1, 2, 3, 5, 8, … в типе `T`, см. [`fibonacci_steps()`].
+#[derive(Debug, Clone)]
+pub struct FibonacciSteps<T> {
+    /// Следующие два шага; `None` — шаг не помещается в `T`.
+    current: Option<T>,
+    next: Option<T>,
+}
+
+impl<T: FibNum> Iterator for FibonacciSteps<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let step = self.current?;
+        let following = self.next.and_then(|next| next.checked_add(step));
+        self.current = self.next;
+        self.next = following;
+        Some(step)
+    }
+}
+
+impl<T: FibNum> FusedIterator for FibonacciSteps<T> {}
+
+/// Возрастающие шаги-числа Фибоначчи F(2), F(3), … = 1, 2, 3, 5, 8, … в типе `T`.
+///
+/// Следующий шаг считается через [`FibNum::checked_add`]: для примитивных типов
+/// итератор заканчивается на наибольшем числе Фибоначчи, которое помещается в
+/// тип; для [`Wrapping`](core::num::Wrapping) и [`Saturating`](core::num::Saturating)
+/// он бесконечен.
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::search::fibonacci_steps;
+///
+/// // Экспоненциальный поиск с шагами Фибоначчи: первая граница не меньше 40.
+/// assert_eq!(fibonacci_steps::<u32>().find(|&step| step >= 40), Some(55));
+/// assert_eq!(fibonacci_steps::<u128>().count(), 185);
+/// assert_eq!(fibonacci_steps::<u64>().last(), Some(12_200_160_415_121_876_738));
+/// ```
+pub fn fibonacci_steps<T: FibNum>() -> FibonacciSteps<T> {
+    FibonacciSteps {
+        current: Some(T::ONE),
+        next: T::ONE.checked_add(T::ONE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fibonacci::fibonacci_in;
+
+    #[test]
+    fn given_every_target_when_fibonacci_search_then_matches_binary_search() {
+        for len in 0..60 {
+            // Given: нечётные числа, чтобы искать и присутствующие, и отсутствующие
+            let slice: Vec<u32> = (0..len).map(|i| 2 * i + 1).collect();
+
+            for target in 0..=2 * len + 1 {
+                // When
+                let result = fibonacci_search(&slice, &target);
+
+                // Then
+                assert_eq!(
+                    result,
+                    slice.binary_search(&target),
+                    "len={}, target={}",
+                    len,
+                    target
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn given_probe_counter_when_fibonacci_search_then_comparisons_are_logarithmic() {
+        // Given
+        let slice: Vec<u64> = (0..1_000_000).collect();
+        let mut comparisons = 0;
+
+        // When
+        let result = fibonacci_search_by(&slice, |probe| {
+            comparisons += 1;
+            probe.cmp(&777_777)
+        });
+
+        // Then: log_φ(10^6) + 1 ≈ 29.7
+        assert_eq!(result, Ok(777_777));
+        assert!(comparisons <= 29, "comparisons={}", comparisons);
+    }
+
+    #[test]
+    fn given_primitive_types_when_fibonacci_steps_then_end_at_max_index() {
+        // Given / When
+        let u8_steps = fibonacci_steps::<u8>().count() as u128;
+        let u64_steps = fibonacci_steps::<u64>().count() as u128;
+        let i32_last = fibonacci_steps::<i32>().last();
+
+        // Then: шаги — F(2) ..= F(max_index)
+        assert_eq!(u8_steps, crate::max_index::<u8>() - 1);
+        assert_eq!(u64_steps, crate::max_index::<u64>() - 1);
+        assert_eq!(i32_last, Some(fibonacci_in::<i32>(46).unwrap()));
+    }
+
+    #[test]
+    fn given_exhausted_steps_when_next_then_stays_none() {
+        // Given
+        let mut steps = fibonacci_steps::<u8>();
+        steps.by_ref().for_each(drop);
+
+        // When / Then
+        assert_eq!(steps.next(), None);
+        assert_eq!(steps.next(), None);
+    }
+
+    #[test]
+    fn given_wrapping_type_when_fibonacci_steps_then_infinite() {
+        // Given
+        let steps = fibonacci_steps::<core::num::Wrapping<u8>>();
+
+        // When
+        let taken = steps.take(1_000).count();
+
+        // Then
+        assert_eq!(taken, 1_000);
+    }
+
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn given_sorted_slice_with_duplicates_when_fibonacci_search_then_consistent_with_binary_search(
+            mut values in proptest::collection::vec(0u16..500, 0..300),
+            target in 0u16..500,
+        ) {
+            // Given
+            values.sort_unstable();
+
+            // When
+            let result = fibonacci_search(&values, &target);
+
+            // Then: при повторах индекс может отличаться, но указывает на искомое
+            match (result, values.binary_search(&target)) {
+                (Ok(i), Ok(_)) => prop_assert_eq!(values[i], target),
+                (found, expected) => prop_assert_eq!(found, expected),
+            }
+        }
+    }
+}
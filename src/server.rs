@@ -0,0 +1,792 @@
+use std::fmt::Write as _;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use crate::app::{APPROX_DIGITS, last_digits};
+use crate::approx::fibonacci_approx;
+use crate::cli::{BudgetAction, Mode, Options};
+use crate::errors::{BudgetError, ComputationError, InputError};
+use crate::fibonacci::{
+    Estimate, fibonacci_big, fibonacci_index_of, fibonacci_range, fibonacci_with_policy,
+};
+use crate::io::parse_input;
+use crate::logger::Logger;
+use crate::modular::fibonacci_mod;
+use crate::parallel::fibonacci_big_parallel;
+
+/// Наибольшее количество значений в ответе `GET /fib?from=&to=`.
+pub const MAX_RANGE_LEN: u128 = 1000;
+
+/// Наибольшая длина строки запроса или заголовка, байт.
+const MAX_LINE_BYTES: u64 = 8 * 1024;
+/// Наибольшее количество заголовков в запросе.
+const MAX_HEADERS: usize = 100;
+/// Сколько ждать запрос от клиента, прежде чем закрыть соединение.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Лимит цифр в ответе по умолчанию, если `--max-digits` не задан: десятичный
+/// вывод квадратичен, и 100 000 цифр занимают около 0,1 с.
+pub const DEFAULT_SERVER_MAX_DIGITS: u128 = 100_000;
+/// Лимит времени на запрос по умолчанию, если `--max-time` не задан.
+pub const DEFAULT_SERVER_MAX_TIME: Duration = Duration::from_secs(1);
+
+/// Наибольшее количество одновременно обслуживаемых соединений; остальные
+/// закрываются сразу после `accept()`.
+pub const MAX_CONNECTIONS: usize = 256;
+/// Пауза после ошибки `accept()`: при `EMFILE` ошибка повторяется, пока
+/// не закроется какое-нибудь соединение.
+const ACCEPT_ERROR_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Ответ API: HTTP-статус и JSON-тело.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    /// HTTP-статус, например `200`.
+    pub status: u16,
+    /// Тело ответа в JSON.
+    pub body: String,
+}
+
+/// Ошибка запроса к API с HTTP-статусом и машиночитаемым кодом.
+#[derive(Debug, PartialEq)]
+pub(crate) enum ApiError {
+    /// Некорректный параметр запроса — `400`.
+    Input(InputError),
+    /// Ошибка вычисления — `422`. Переполнение сюда не попадает: оно
+    /// передаётся как [`InputError::ComputationOverflow`], см. `From<ComputationError>`.
+    Computation(ComputationError),
+    /// Вычисление превышает бюджет сервера — `422`.
+    Budget(BudgetError),
+    /// Неизвестный путь — `404`.
+    NotFound(String),
+    /// Метод, отличный от `GET`, — `405`.
+    MethodNotAllowed(String),
+    /// Запрос не удалось разобрать как HTTP — `400`.
+    BadRequest,
+}
+
+impl ApiError {
+    fn status(&self) -> u16 {
+        match self {
+            ApiError::Input(InputError::ComputationOverflow(_)) => 422,
+            ApiError::Input(_) | ApiError::BadRequest => 400,
+            ApiError::NotFound(_) => 404,
+            ApiError::MethodNotAllowed(_) => 405,
+            ApiError::Computation(_) | ApiError::Budget(_) => 422,
+        }
+    }
+
+    pub(crate) fn code(&self) -> &'static str {
+        match self {
+            ApiError::Input(e) => e.code(),
+            ApiError::Computation(e) => e.code(),
+            ApiError::Budget(e) => e.code(),
+            ApiError::NotFound(_) => "NOT_FOUND",
+            ApiError::MethodNotAllowed(_) => "METHOD_NOT_ALLOWED",
+            ApiError::BadRequest => "BAD_REQUEST",
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            ApiError::Input(e) => e.to_string(),
+            ApiError::Computation(e) => e.bilingual_message(),
+            ApiError::Budget(e) => e.to_string(),
+            ApiError::NotFound(path) => format!(
+                "RU: Ошибка! Неизвестный путь '{}'\nEN: Error! Unknown path '{}'",
+                path, path
+            ),
+            ApiError::MethodNotAllowed(method) => format!(
+                "RU: Ошибка! Метод {} не поддерживается, используйте GET\nEN: Error! Method {} is not allowed, use GET",
+                method, method
+            ),
+            ApiError::BadRequest => {
+                "RU: Ошибка! Некорректный HTTP-запрос\nEN: Error! Malformed HTTP request"
+                    .to_string()
+            }
+        }
+    }
+
+    fn into_response(self) -> Response {
+        Response {
+            status: self.status(),
+            body: format!(
+                "{{\"error\":{{\"code\":{},\"message\":{}}}}}",
+                json_string(self.code()),
+                json_string(&self.message())
+            ),
+        }
+    }
+}
+
+impl From<InputError> for ApiError {
+    fn from(error: InputError) -> Self {
+        ApiError::Input(error)
+    }
+}
+
+/// Переполнение сообщается так же, как в CLI: `COMPUTATION_OVERFLOW` с границей
+/// индекса для `u128` в двуязычном сообщении.
+impl From<ComputationError> for ApiError {
+    fn from(error: ComputationError) -> Self {
+        match error {
+            ComputationError::Overflow(n) => ApiError::Input(InputError::ComputationOverflow(n)),
+            error => ApiError::Computation(error),
+        }
+    }
+}
+
+/// Обрабатывает один запрос к API.
+///
+/// Маршруты (все числа в ответах — строки, чтобы JSON-клиенты не теряли точность):
+///
+/// * `GET /fib/{n}` → `{"n":"10","value":"55"}`
+/// * `GET /fib?from=5&to=8` → `{"from":"5","to":"8","values":["5","8","13","21"]}`
+/// * `GET /index-of/{v}` → `{"value":"55","index":"10"}`; `"index":null`, если `v` не число Фибоначчи
+/// * `GET /mod/{n}/{m}` → `{"n":"10","m":"7","value":"6"}`
+///
+/// Ошибки возвращаются как `{"error":{"code":"NEGATIVE_NUMBER","message":"RU: ...\nEN: ..."}}`
+/// с кодом из [`InputError::code`], [`ComputationError::code`] или [`BudgetError::code`].
+/// Режим вычисления, политика переполнения и бюджет берутся из `options`.
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::Options;
+/// use fibonacci_stable::server::handle;
+///
+/// let ok = handle("GET", "/fib/10", &Options::default());
+/// assert_eq!(ok.status, 200);
+/// assert_eq!(ok.body, r#"{"n":"10","value":"55"}"#);
+///
+/// let overflow = handle("GET", "/fib/187", &Options::default());
+/// assert_eq!(overflow.status, 422);
+/// assert!(overflow.body.contains(r#""code":"COMPUTATION_OVERFLOW""#));
+/// ```
+pub fn handle(method: &str, target: &str, options: &Options) -> Response {
+    match route(method, target, options) {
+        Ok(body) => Response { status: 200, body },
+        Err(error) => error.into_response(),
+    }
+}
+
+fn route(method: &str, target: &str, options: &Options) -> Result<String, ApiError> {
+    if method != "GET" {
+        return Err(ApiError::MethodNotAllowed(method.to_string()));
+    }
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+
+    match segments[..] {
+        ["fib", n] => {
+            let n = parse_input(n)?;
+            Ok(format!(
+                "{{\"n\":\"{}\",\"value\":\"{}\"}}",
+                n,
+                compute(n, options)?
+            ))
+        }
+        ["fib"] => {
+            let from = parse_input(query_param(query, "from"))?;
+            let to = parse_input(query_param(query, "to"))?;
+            if from > to || to - from >= MAX_RANGE_LEN {
+                return Err(InputError::InvalidRange {
+                    from,
+                    to,
+                    max_len: MAX_RANGE_LEN,
+                }
+                .into());
+            }
+            let values = compute_range(from, to, options)?;
+            let values: Vec<String> = values.iter().map(|v| format!("\"{}\"", v)).collect();
+            Ok(format!(
+                "{{\"from\":\"{}\",\"to\":\"{}\",\"values\":[{}]}}",
+                from,
+                to,
+                values.join(",")
+            ))
+        }
+        ["index-of", value] => {
+            let value = parse_input(value)?;
+            let index = fibonacci_index_of(value)
+                .map_or_else(|| "null".to_string(), |n| format!("\"{}\"", n));
+            Ok(format!("{{\"value\":\"{}\",\"index\":{}}}", value, index))
+        }
+        ["mod", n, m] => {
+            let n = parse_input(n)?;
+            let m = parse_input(m)?;
+            Ok(format!(
+                "{{\"n\":\"{}\",\"m\":\"{}\",\"value\":\"{}\"}}",
+                n,
+                m,
+                fibonacci_mod(n, m)?
+            ))
+        }
+        _ => Err(ApiError::NotFound(path.to_string())),
+    }
+}
+
+/// Значение параметра `key` из строки запроса; пустая строка, если его нет.
+fn query_param<'a>(query: &'a str, key: &str) -> &'a str {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find_map(|(k, v)| (k == key).then_some(v))
+        .unwrap_or("")
+}
+
+/// Проверяет бюджет; при `--on-budget warn` превышение не мешает вычислению.
+fn check_budget(n: u128, options: &Options) -> Result<(), ApiError> {
+    check_estimate(
+        n,
+        &options.budget.estimate(n, options.mode.backend()),
+        options,
+    )
+}
+
+/// Проверяет бюджет для всего диапазона: цифры, время и строки ответа
+/// суммируются по всем значениям, память вычисления — наибольшая из них.
+fn check_range_budget(from: u128, to: u128, options: &Options) -> Result<(), ApiError> {
+    let mut total = Estimate {
+        bits: 0,
+        decimal_digits: 0,
+        memory_bytes: 0,
+        time: Duration::ZERO,
+    };
+    let mut peak_memory = 0;
+    for n in from..=to {
+        let e = options.budget.estimate(n, options.mode.backend());
+        total.bits = total.bits.max(e.bits);
+        total.decimal_digits = total.decimal_digits.saturating_add(e.decimal_digits);
+        total.time = total.time.saturating_add(e.time);
+        peak_memory = peak_memory.max(e.memory_bytes);
+    }
+    total.memory_bytes = peak_memory.saturating_add(total.decimal_digits);
+    check_estimate(to, &total, options)
+}
+
+fn check_estimate(n: u128, estimate: &Estimate, options: &Options) -> Result<(), ApiError> {
+    match options.budget.check(n, estimate) {
+        Err(e) if options.budget.action == BudgetAction::Refuse => Err(ApiError::Budget(e)),
+        _ => Ok(()),
+    }
+}
+
+/// Вычисляет fib(n) в режиме и с бюджетом из `options`.
+pub(crate) fn compute(n: u128, options: &Options) -> Result<String, ApiError> {
+    check_budget(n, options)?;
+    Ok(match options.mode {
+        Mode::U128 => fibonacci_with_policy(n, options.overflow)?.to_string(),
+        Mode::Big => fibonacci_big_parallel(n, options.thread_count()).to_string(),
+        Mode::Approx => approximate(n),
+        Mode::LastDigits(k) => last_digits(n, k),
+    })
+}
+
+fn compute_range(from: u128, to: u128, options: &Options) -> Result<Vec<String>, ApiError> {
+    check_range_budget(from, to, options)?;
+    Ok(match options.mode {
+        Mode::U128 => fibonacci_range(from..=to, options.overflow)?
+            .iter()
+            .map(u128::to_string)
+            .collect(),
+        Mode::Big => (from..=to).map(|n| fibonacci_big(n).to_string()).collect(),
+        Mode::Approx => (from..=to).map(approximate).collect(),
+        Mode::LastDigits(k) => (from..=to).map(|n| last_digits(n, k)).collect(),
+    })
+}
+
+/// Первые [`APPROX_DIGITS`] цифр и порядок fib(n) для режима `--approx`.
+fn approximate(n: u128) -> String {
+    fibonacci_approx(n, APPROX_DIGITS)
+        .expect("APPROX_DIGITS не больше MAX_LEADING_DIGITS")
+        .to_string()
+}
+
+/// Экранирует строку для JSON.
+pub(crate) fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Unprocessable Entity",
+    }
+}
+
+/// Читает один HTTP-запрос из `input`, отвечает в `output` и логирует запрос и ответ.
+///
+/// Тело запроса игнорируется, соединение после ответа закрывается
+/// (`Connection: close`).
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::{Logger, Options};
+/// use fibonacci_stable::server::handle_connection;
+///
+/// let mut output = Vec::new();
+/// let mut logger = Logger::new();
+/// let request = "GET /index-of/55 HTTP/1.1\r\nHost: localhost\r\n\r\n";
+///
+/// handle_connection(request.as_bytes(), &mut output, &Options::default(), &mut logger).unwrap();
+///
+/// let response = String::from_utf8(output).unwrap();
+/// assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+/// assert!(response.ends_with(r#"{"value":"55","index":"10"}"#));
+/// ```
+pub fn handle_connection(
+    mut input: impl BufRead,
+    mut output: impl Write,
+    options: &Options,
+    logger: &mut Logger,
+) -> io::Result<()> {
+    let mut request_line = String::new();
+    input
+        .by_ref()
+        .take(MAX_LINE_BYTES)
+        .read_line(&mut request_line)?;
+    for _ in 0..MAX_HEADERS {
+        let mut header = String::new();
+        let read = input.by_ref().take(MAX_LINE_BYTES).read_line(&mut header)?;
+        if read == 0 || header.trim_end().is_empty() {
+            break;
+        }
+    }
+
+    let response = match request_line.split_whitespace().collect::<Vec<_>>()[..] {
+        [method, target, version] if version.starts_with("HTTP/") => {
+            logger.log(&format!("Запрос: {} {}", method, target));
+            handle(method, target, options)
+        }
+        _ => {
+            logger.log(&format!(
+                "Некорректный запрос: '{}'",
+                request_line.trim_end()
+            ));
+            ApiError::BadRequest.into_response()
+        }
+    };
+    logger.log(&format!(
+        "Ответ: {} {}",
+        response.status,
+        reason(response.status)
+    ));
+
+    write!(
+        output,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        response.body.len(),
+        response.body
+    )?;
+    output.flush()
+}
+
+/// Принимает соединения на `listener` и обслуживает каждое в отдельном потоке.
+///
+/// Лог каждого соединения выводится в stdout после ответа. Лишние соединения
+/// сверх [`MAX_CONNECTIONS`] закрываются сразу; ошибки `accept()` логируются.
+/// Без `--max-digits`/`--max-time` действуют [`DEFAULT_SERVER_MAX_DIGITS`] и
+/// [`DEFAULT_SERVER_MAX_TIME`].
+pub fn serve(listener: TcpListener, options: Options) -> io::Result<()> {
+    accept_loop(listener, options, |stream, options, logger| {
+        stream.set_read_timeout(Some(READ_TIMEOUT))?;
+        handle_connection(BufReader::new(stream), stream, options, logger)
+    })
+}
+
+/// Принимает соединения и вызывает `handler` для каждого в отдельном потоке
+/// со своим [`Logger`]; лог соединения выводится в stdout, когда оно закрыто.
+///
+/// Ошибка `accept()` (например, `EMFILE` или `ECONNABORTED`) логируется и не
+/// останавливает сервер. Одновременно обслуживается не больше
+/// [`MAX_CONNECTIONS`] соединений. Незаданные лимиты цифр и времени заменяются
+/// на [`DEFAULT_SERVER_MAX_DIGITS`] и [`DEFAULT_SERVER_MAX_TIME`].
+pub(crate) fn accept_loop(
+    listener: TcpListener,
+    options: Options,
+    handler: fn(&TcpStream, &Options, &mut Logger) -> io::Result<()>,
+) -> io::Result<()> {
+    accept_loop_with(
+        listener,
+        with_server_budget(options),
+        handler,
+        MAX_CONNECTIONS,
+    )
+}
+
+/// Дополняет бюджет лимитами сервера по умолчанию: один запрос не должен
+/// занимать поток соединения на минуты.
+fn with_server_budget(mut options: Options) -> Options {
+    let budget = &mut options.budget;
+    budget.max_digits.get_or_insert(DEFAULT_SERVER_MAX_DIGITS);
+    budget.max_time.get_or_insert(DEFAULT_SERVER_MAX_TIME);
+    options
+}
+
+fn accept_loop_with(
+    listener: TcpListener,
+    options: Options,
+    handler: fn(&TcpStream, &Options, &mut Logger) -> io::Result<()>,
+    max_connections: usize,
+) -> io::Result<()> {
+    let options = Arc::new(options);
+    let active = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        let mut logger = Logger::new();
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                logger.error(&format!("Ошибка accept(): {}", e));
+                print_log(&logger);
+                thread::sleep(ACCEPT_ERROR_BACKOFF);
+                continue;
+            }
+        };
+        let Some(slot) = ConnectionSlot::acquire(&active, max_connections) else {
+            logger.error(&format!(
+                "Соединение отклонено: уже открыто {} соединений",
+                max_connections
+            ));
+            print_log(&logger);
+            continue;
+        };
+        let options = Arc::clone(&options);
+        thread::spawn(move || {
+            let _slot = slot;
+            let result = stream
+                .peer_addr()
+                .map(|peer| logger.log(&format!("Соединение от {}", peer)))
+                .and_then(|()| handler(&stream, &options, &mut logger));
+            if let Err(e) = result {
+                logger.error(&format!("Ошибка соединения: {}", e));
+            }
+            print_log(&logger);
+        });
+    }
+    Ok(())
+}
+
+/// Выводит записи лога в stdout одним блоком.
+fn print_log(logger: &Logger) {
+    let mut stdout = io::stdout().lock();
+    for entry in logger.entries() {
+        let _ = writeln!(stdout, "{}", entry);
+    }
+}
+
+/// Место в счётчике открытых соединений; освобождается при удалении.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    /// Занимает место, если открыто меньше `limit` соединений.
+    fn acquire(active: &Arc<AtomicUsize>, limit: usize) -> Option<Self> {
+        active
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                (n < limit).then_some(n + 1)
+            })
+            .ok()
+            .map(|_| ConnectionSlot(Arc::clone(active)))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OverflowPolicy;
+
+    #[test]
+    fn given_range_query_when_handle_then_returns_values() {
+        // Given
+        let target = "/fib?from=5&to=8";
+
+        // When
+        let response = handle("GET", target, &Options::default());
+
+        // Then
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            response.body,
+            r#"{"from":"5","to":"8","values":["5","8","13","21"]}"#
+        );
+    }
+
+    #[test]
+    fn given_reversed_or_long_range_when_handle_then_returns_invalid_range() {
+        // Given / When
+        let reversed = handle("GET", "/fib?from=8&to=5", &Options::default());
+        let too_long = handle("GET", "/fib?from=0&to=5000", &Options::default());
+
+        // Then
+        for response in [reversed, too_long] {
+            assert_eq!(response.status, 400);
+            assert!(response.body.contains(r#""code":"INVALID_RANGE""#));
+        }
+    }
+
+    #[test]
+    fn given_missing_range_param_when_handle_then_returns_empty_input() {
+        // Given
+        let target = "/fib?from=5";
+
+        // When
+        let response = handle("GET", target, &Options::default());
+
+        // Then
+        assert_eq!(response.status, 400);
+        assert!(response.body.contains(r#""code":"EMPTY_INPUT""#));
+    }
+
+    #[test]
+    fn given_invalid_number_when_handle_then_returns_input_error_code() {
+        // Given / When
+        let negative = handle("GET", "/fib/-5", &Options::default());
+        let float = handle("GET", "/mod/3.5/7", &Options::default());
+
+        // Then
+        assert_eq!(negative.status, 400);
+        assert!(negative.body.contains(r#""code":"NEGATIVE_NUMBER""#));
+        assert!(float.body.contains(r#""code":"FLOAT_NUMBER""#));
+    }
+
+    #[test]
+    fn given_non_fibonacci_value_when_index_of_then_index_is_null() {
+        // Given
+        let target = "/index-of/4";
+
+        // When
+        let response = handle("GET", target, &Options::default());
+
+        // Then
+        assert_eq!(response.body, r#"{"value":"4","index":null}"#);
+    }
+
+    #[test]
+    fn given_mod_route_when_handle_then_returns_remainder_or_zero_modulus() {
+        // Given / When
+        let ok = handle("GET", "/mod/1000/1000000007", &Options::default());
+        let zero = handle("GET", "/mod/10/0", &Options::default());
+
+        // Then
+        assert_eq!(
+            ok.body,
+            format!(
+                r#"{{"n":"1000","m":"1000000007","value":"{}"}}"#,
+                fibonacci_mod(1000, 1_000_000_007).unwrap()
+            )
+        );
+        assert_eq!(zero.status, 422);
+        assert!(zero.body.contains(r#""code":"ZERO_MODULUS""#));
+        assert!(
+            zero.body
+                .contains("EN: Error! The modulus must be greater than zero")
+        );
+    }
+
+    #[test]
+    fn given_options_when_handle_then_uses_mode_and_policy() {
+        // Given
+        let wrapping = Options {
+            overflow: OverflowPolicy::Wrapping,
+            ..Options::default()
+        };
+        let big = Options {
+            mode: Mode::Big,
+            ..Options::default()
+        };
+
+        // When
+        let wrapped = handle("GET", "/fib/187", &wrapping);
+        let exact = handle("GET", "/fib/187", &big);
+
+        // Then
+        assert_eq!(wrapped.status, 200);
+        assert_eq!(
+            exact.body,
+            format!(r#"{{"n":"187","value":"{}"}}"#, fibonacci_big(187))
+        );
+    }
+
+    #[test]
+    fn given_range_within_per_value_limit_when_handle_then_budget_checks_whole_range() {
+        // Given: каждое из fib(90..=99) короче 100 цифр, но вместе — длиннее
+        let mut options = Options {
+            mode: Mode::Big,
+            ..Options::default()
+        };
+        options.budget.max_digits = Some(100);
+
+        // When
+        let single = handle("GET", "/fib/99", &options);
+        let range = handle("GET", "/fib?from=90&to=99", &options);
+
+        // Then
+        assert_eq!(single.status, 200);
+        assert_eq!(range.status, 422);
+        assert!(range.body.contains(r#""code":"BUDGET_DIGITS""#));
+    }
+
+    #[test]
+    fn given_options_without_limits_when_with_server_budget_then_adds_defaults_only() {
+        // Given
+        let mut explicit = Options::default();
+        explicit.budget.max_digits = Some(10);
+
+        // When
+        let defaults = with_server_budget(Options::default()).budget;
+        let kept = with_server_budget(explicit).budget;
+
+        // Then
+        assert_eq!(defaults.max_digits, Some(DEFAULT_SERVER_MAX_DIGITS));
+        assert_eq!(defaults.max_time, Some(DEFAULT_SERVER_MAX_TIME));
+        assert_eq!(kept.max_digits, Some(10));
+        assert_eq!(kept.max_time, Some(DEFAULT_SERVER_MAX_TIME));
+    }
+
+    #[test]
+    fn given_over_budget_when_handle_then_returns_budget_code() {
+        // Given
+        let mut options = Options {
+            mode: Mode::Big,
+            ..Options::default()
+        };
+        options.budget.max_digits = Some(10);
+
+        // When
+        let response = handle("GET", "/fib/1000", &options);
+
+        // Then
+        assert_eq!(response.status, 422);
+        assert!(response.body.contains(r#""code":"BUDGET_DIGITS""#));
+    }
+
+    #[test]
+    fn given_unknown_path_or_method_when_handle_then_returns_404_or_405() {
+        // Given / When
+        let unknown = handle("GET", "/lucas/10", &Options::default());
+        let post = handle("POST", "/fib/10", &Options::default());
+
+        // Then
+        assert_eq!(unknown.status, 404);
+        assert!(unknown.body.contains(r#""code":"NOT_FOUND""#));
+        assert_eq!(post.status, 405);
+        assert!(post.body.contains(r#""code":"METHOD_NOT_ALLOWED""#));
+    }
+
+    #[test]
+    fn given_limit_when_acquire_connection_slots_then_frees_on_drop() {
+        // Given
+        let active = Arc::new(AtomicUsize::new(0));
+
+        // When
+        let first = ConnectionSlot::acquire(&active, 1);
+        let second = ConnectionSlot::acquire(&active, 1);
+        drop(first);
+        let third = ConnectionSlot::acquire(&active, 1);
+
+        // Then
+        assert!(second.is_none());
+        assert!(third.is_some());
+        assert_eq!(active.load(Ordering::Acquire), 1);
+    }
+
+    #[test]
+    fn given_connection_limit_when_extra_client_connects_then_closed_and_first_still_served() {
+        // Given: обработчик ждёт байт от клиента и отвечает "ok"
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            accept_loop_with(
+                listener,
+                Options::default(),
+                |mut stream, _, _| {
+                    stream.read_exact(&mut [0u8; 1])?;
+                    stream.write_all(b"ok")
+                },
+                1,
+            )
+        });
+        let mut first = TcpStream::connect(addr).unwrap();
+
+        // When
+        let mut extra = TcpStream::connect(addr).unwrap();
+        extra.set_read_timeout(Some(READ_TIMEOUT)).unwrap();
+        let extra_read = extra.read(&mut [0u8; 1]);
+        first.write_all(b"x").unwrap();
+        let mut reply = String::new();
+        first.read_to_string(&mut reply).unwrap();
+
+        // Then
+        assert!(
+            matches!(extra_read, Ok(0))
+                || extra_read.is_err_and(|e| e.kind() == io::ErrorKind::ConnectionReset)
+        );
+        assert_eq!(reply, "ok");
+    }
+
+    #[test]
+    fn given_garbage_when_handle_connection_then_bad_request_and_logged() {
+        // Given
+        let mut output = Vec::new();
+        let mut logger = Logger::new();
+
+        // When
+        handle_connection(
+            "hello\r\n\r\n".as_bytes(),
+            &mut output,
+            &Options::default(),
+            &mut logger,
+        )
+        .unwrap();
+
+        // Then
+        let response = String::from_utf8(output).unwrap();
+        assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"));
+        assert!(response.contains(r#""code":"BAD_REQUEST""#));
+        assert!(logger.entries()[0].contains("Некорректный запрос: 'hello'"));
+        assert!(logger.entries()[1].contains("Ответ: 400"));
+    }
+
+    #[test]
+    fn given_message_with_quotes_and_newlines_when_json_string_then_escaped() {
+        // Given
+        let message = "RU: 'x'\nEN: \"y\"\t\\\u{1}";
+
+        // When
+        let escaped = json_string(message);
+
+        // Then
+        assert_eq!(escaped, r#""RU: 'x'\nEN: \"y\"\t\\\u0001""#);
+    }
+}
@@ -0,0 +1,512 @@
+//! Суммы чисел Фибоначчи по диапазону индексов в замкнутой форме.
+//!
+//! Вместо сложения `fibonacci(i)` по одному используются тождества:
+//!
+//! * `F(a) + … + F(b) = F(b+2) − F(a+1)`;
+//! * `F(a)² + … + F(b)² = F(b)·F(b+1) − F(a−1)·F(a)`;
+//! * `F(a) − F(a+1) + … ± F(b)`: `Σ (−1)^i F(i) = (−1)^b F(b−1) − (−1)^(a−1) F(a−2)`,
+//!   где `F(−1) = 1`.
+//!
+//! Для каждой суммы есть точная версия в `u128` (ошибка — только если сама сумма
+//! не помещается в тип), версия по модулю для любых индексов и версия в
+//! [`BigUint`]. Знакопеременная сумма возвращается в `i128`. Пустой диапазон
+//! (`start > end`) даёт 0.
+//!
+//! ```
+//! use fibonacci_stable::sums::{fibonacci_square_sum, fibonacci_sum, fibonacci_sum_mod};
+//!
+//! assert_eq!(fibonacci_sum(0..=10), Ok(143));         // F(12) − 1
+//! assert_eq!(fibonacci_square_sum(1..=10), Ok(4895)); // F(10)·F(11)
+//! assert_eq!(fibonacci_sum(185..=186), Err(fibonacci_stable::ComputationError::Overflow(186)));
+//! assert!(fibonacci_sum_mod(0..=u128::MAX, 1_000_000_007).is_ok());
+//! ```
+
+use core::ops::RangeInclusive;
+
+#[cfg(feature = "alloc")]
+use crate::bigint::BigUint;
+use crate::errors::ComputationError;
+#[cfg(feature = "alloc")]
+use crate::fibonacci::fibonacci_big;
+use crate::fibonacci::{FIBONACCI_TABLE, fibonacci};
+use crate::modular::{add_mod, fibonacci_pair_mod, mul_mod, sub_mod};
+
+/// Сумма `F(a) + … + F(b)` для диапазона `a..=b`.
+///
+/// # Ошибки
+///
+/// * `Err(ComputationError::Overflow(b))` — если сумма не помещается в `u128`
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::sums::fibonacci_sum;
+///
+/// assert_eq!(fibonacci_sum(5..=8), Ok(5 + 8 + 13 + 21));
+/// assert_eq!(fibonacci_sum(186..=186), Ok(332825110087067562321196029789634457848));
+/// assert!(fibonacci_sum(185..=186).is_err());
+/// ```
+pub fn fibonacci_sum(range: RangeInclusive<u128>) -> Result<u128, ComputationError> {
+    let (a, b) = (*range.start(), *range.end());
+    if a > b {
+        return Ok(0);
+    }
+    // Сумма не меньше F(b), так что дальше b ≤ 186 и индексы таблицы не переполняются.
+    let last = fibonacci(b)?;
+    if a == b {
+        return Ok(last);
+    }
+    let overflow = || ComputationError::Overflow(b);
+    // F(b+2) − F(a+1) = F(b+1) + (F(b) − F(a+1)), где F(a+1) ≤ F(b) при a < b.
+    let next = *FIBONACCI_TABLE.get(b as usize + 1).ok_or_else(overflow)?;
+    next.checked_add(last - FIBONACCI_TABLE[a as usize + 1])
+        .ok_or_else(overflow)
+}
+
+/// Сумма `F(a) + … + F(b)` по модулю `m` для любых индексов.
+///
+/// # Ошибки
+///
+/// * `Err(ComputationError::ZeroModulus)` — если `m == 0`
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::sums::fibonacci_sum_mod;
+///
+/// assert_eq!(fibonacci_sum_mod(5..=8, 10), Ok(47 % 10));
+/// assert_eq!(fibonacci_sum_mod(0..=999, 1_000_000_007), Ok(107_579_938));
+/// ```
+pub fn fibonacci_sum_mod(range: RangeInclusive<u128>, m: u128) -> Result<u128, ComputationError> {
+    if m == 0 {
+        return Err(ComputationError::ZeroModulus);
+    }
+    let (a, b) = (*range.start(), *range.end());
+    if a > b {
+        return Ok(0);
+    }
+    let (last, next) = fibonacci_pair_mod(b, m);
+    let (_, first_next) = fibonacci_pair_mod(a, m);
+    Ok(sub_mod(add_mod(last, next, m), first_next, m))
+}
+
+/// Сумма `F(a) + … + F(b)` в [`BigUint`].
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::sums::fibonacci_sum_big;
+///
+/// assert_eq!(
+///     fibonacci_sum_big(0..=186).to_string(),
+///     "871347450517368352816615810882615488380"
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn fibonacci_sum_big(range: RangeInclusive<u128>) -> BigUint {
+    let (a, b) = (*range.start(), *range.end());
+    if a > b {
+        return BigUint::zero();
+    }
+    fibonacci_big(b + 2)
+        .checked_sub(&fibonacci_big(a + 1))
+        .expect("F(b+2) ≥ F(a+1) при a ≤ b")
+}
+
+/// Сумма квадратов `F(a)² + … + F(b)²` для диапазона `a..=b`.
+///
+/// # Ошибки
+///
+/// * `Err(ComputationError::Overflow(b))` — если сумма не помещается в `u128`
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::sums::fibonacci_square_sum;
+///
+/// assert_eq!(fibonacci_square_sum(3..=5), Ok(4 + 9 + 25));
+/// assert!(fibonacci_square_sum(0..=94).is_err());
+/// ```
+pub fn fibonacci_square_sum(range: RangeInclusive<u128>) -> Result<u128, ComputationError> {
+    let (a, b) = (*range.start(), *range.end());
+    if a > b {
+        return Ok(0);
+    }
+    // Сумма не меньше F(b)², так что дальше b ≤ 93 и F(b)·F(b+1) < 2^128.
+    let last = fibonacci(b)?;
+    last.checked_mul(last)
+        .ok_or(ComputationError::Overflow(b))?;
+    let prefix = |n: u128| FIBONACCI_TABLE[n as usize] * FIBONACCI_TABLE[n as usize + 1];
+    Ok(prefix(b) - if a == 0 { 0 } else { prefix(a - 1) })
+}
+
+/// Сумма квадратов `F(a)² + … + F(b)²` по модулю `m` для любых индексов.
+///
+/// # Ошибки
+///
+/// * `Err(ComputationError::ZeroModulus)` — если `m == 0`
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::sums::fibonacci_square_sum_mod;
+///
+/// assert_eq!(fibonacci_square_sum_mod(3..=5, 7), Ok(38 % 7));
+/// ```
+pub fn fibonacci_square_sum_mod(
+    range: RangeInclusive<u128>,
+    m: u128,
+) -> Result<u128, ComputationError> {
+    if m == 0 {
+        return Err(ComputationError::ZeroModulus);
+    }
+    let (a, b) = (*range.start(), *range.end());
+    if a > b {
+        return Ok(0);
+    }
+    let prefix = |n: u128| {
+        let (current, next) = fibonacci_pair_mod(n, m);
+        mul_mod(current, next, m)
+    };
+    let before = if a == 0 { 0 } else { prefix(a - 1) };
+    Ok(sub_mod(prefix(b), before, m))
+}
+
+/// Сумма квадратов `F(a)² + … + F(b)²` в [`BigUint`].
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::sums::fibonacci_square_sum_big;
+///
+/// assert_eq!(fibonacci_square_sum_big(0..=100).to_string(), "203023208030065646654504166904697594722575");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn fibonacci_square_sum_big(range: RangeInclusive<u128>) -> BigUint {
+    let (a, b) = (*range.start(), *range.end());
+    if a > b {
+        return BigUint::zero();
+    }
+    let prefix = |n: u128| &fibonacci_big(n) * &fibonacci_big(n + 1);
+    let total = prefix(b);
+    if a == 0 {
+        return total;
+    }
+    total
+        .checked_sub(&prefix(a - 1))
+        .expect("префиксные суммы квадратов не убывают")
+}
+
+/// Знакопеременная сумма `(−1)^a F(a) + … + (−1)^b F(b)` для диапазона `a..=b`.
+///
+/// # Ошибки
+///
+/// * `Err(ComputationError::Overflow(b))` — если сумма не помещается в `i128`
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::sums::fibonacci_alternating_sum;
+///
+/// assert_eq!(fibonacci_alternating_sum(0..=5), Ok(0 - 1 + 1 - 2 + 3 - 5));
+/// assert_eq!(fibonacci_alternating_sum(4..=5), Ok(3 - 5));
+/// ```
+pub fn fibonacci_alternating_sum(range: RangeInclusive<u128>) -> Result<i128, ComputationError> {
+    let (a, b) = (*range.start(), *range.end());
+    if a > b {
+        return Ok(0);
+    }
+    let overflow = || ComputationError::Overflow(b);
+    // |сумма| ≥ F(b−2), поэтому при b ≥ 188, когда F(b−1) не помещается в u128,
+    // она не помещается и в i128.
+    let (last_negative, last) = signed_previous(b).ok_or_else(overflow)?;
+    let (first_negative, first) = if a == 0 {
+        (false, 1)
+    } else {
+        signed_previous(a - 1).ok_or_else(overflow)?
+    };
+
+    // (±last) − (±first) в виде знака и модуля.
+    let (negative, magnitude) = if last_negative != first_negative {
+        (last_negative, last.checked_add(first).ok_or_else(overflow)?)
+    } else if last >= first {
+        (last_negative, last - first)
+    } else {
+        (!last_negative, first - last)
+    };
+    if negative {
+        0i128.checked_sub_unsigned(magnitude)
+    } else {
+        i128::try_from(magnitude).ok()
+    }
+    .ok_or_else(overflow)
+}
+
+/// Знакопеременная сумма `(−1)^a F(a) + … + (−1)^b F(b)` по модулю `m` для любых индексов.
+///
+/// # Ошибки
+///
+/// * `Err(ComputationError::ZeroModulus)` — если `m == 0`
+///
+/// # Примеры
+///
+/// ```
+/// use fibonacci_stable::sums::fibonacci_alternating_sum_mod;
+///
+/// assert_eq!(fibonacci_alternating_sum_mod(0..=5, 10), Ok(10 - 4)); // −4 mod 10
+/// ```
+pub fn fibonacci_alternating_sum_mod(
+    range: RangeInclusive<u128>,
+    m: u128,
+) -> Result<u128, ComputationError> {
+    if m == 0 {
+        return Err(ComputationError::ZeroModulus);
+    }
+    let (a, b) = (*range.start(), *range.end());
+    if a > b {
+        return Ok(0);
+    }
+    // (−1)^n F(n−1) mod m для n ≥ 0.
+    let signed_previous = |n: u128| {
+        let previous = if n == 0 {
+            1 % m
+        } else {
+            fibonacci_pair_mod(n - 1, m).0
+        };
+        if n % 2 == 1 {
+            sub_mod(0, previous, m)
+        } else {
+            previous
+        }
+    };
+    let first = if a == 0 {
+        1 % m
+    } else {
+        signed_previous(a - 1)
+    };
+    Ok(sub_mod(signed_previous(b), first, m))
+}
+
+/// `(−1)^n F(n−1)` в виде знака и модуля, где `F(−1) = 1`; `None`, если модуль
+/// не помещается в `u128`.
+fn signed_previous(n: u128) -> Option<(bool, u128)> {
+    let magnitude = if n == 0 { 1 } else { fibonacci(n - 1).ok()? };
+    Some((n % 2 == 1, magnitude))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modular::fibonacci_mod;
+
+    #[test]
+    fn given_empty_range_when_summed_then_zero() {
+        // Given
+        #[allow(clippy::reversed_empty_ranges)]
+        let range = 10..=5;
+
+        // When / Then
+        assert_eq!(fibonacci_sum(range.clone()), Ok(0));
+        assert_eq!(fibonacci_square_sum(range.clone()), Ok(0));
+        assert_eq!(fibonacci_alternating_sum(range.clone()), Ok(0));
+        assert_eq!(fibonacci_sum_mod(range.clone(), 7), Ok(0));
+        assert_eq!(fibonacci_square_sum_mod(range.clone(), 7), Ok(0));
+        assert_eq!(fibonacci_alternating_sum_mod(range.clone(), 7), Ok(0));
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(fibonacci_sum_big(range.clone()), BigUint::zero());
+            assert_eq!(fibonacci_square_sum_big(range), BigUint::zero());
+        }
+    }
+
+    #[test]
+    fn given_zero_modulus_when_summed_mod_then_error() {
+        // Given / When / Then
+        assert_eq!(
+            fibonacci_sum_mod(0..=5, 0),
+            Err(ComputationError::ZeroModulus)
+        );
+        assert_eq!(
+            fibonacci_square_sum_mod(0..=5, 0),
+            Err(ComputationError::ZeroModulus)
+        );
+        assert_eq!(
+            fibonacci_alternating_sum_mod(0..=5, 0),
+            Err(ComputationError::ZeroModulus)
+        );
+    }
+
+    #[test]
+    fn given_sums_at_u128_boundary_when_checked_then_error_only_if_sum_overflows() {
+        // Given: F(184) + F(185) = F(186) помещается, F(185) + F(186) = F(187) — нет
+        let (f183, f184, f186) = (
+            FIBONACCI_TABLE[183],
+            FIBONACCI_TABLE[184],
+            FIBONACCI_TABLE[186],
+        );
+
+        // When / Then
+        assert_eq!(fibonacci_sum(184..=185), Ok(f186));
+        assert_eq!(fibonacci_sum(186..=186), Ok(f186));
+        assert_eq!(
+            fibonacci_sum(185..=186),
+            Err(ComputationError::Overflow(186))
+        );
+        assert_eq!(
+            fibonacci_sum(187..=187),
+            Err(ComputationError::Overflow(187))
+        );
+        assert_eq!(
+            fibonacci_sum(0..=u128::MAX),
+            Err(ComputationError::Overflow(u128::MAX))
+        );
+        assert_eq!(
+            fibonacci_square_sum(93..=93),
+            Ok(FIBONACCI_TABLE[93].pow(2))
+        );
+        assert_eq!(
+            fibonacci_square_sum(94..=94),
+            Err(ComputationError::Overflow(94))
+        );
+        assert_eq!(fibonacci_alternating_sum(185..=186), Ok(f184 as i128));
+        assert_eq!(
+            fibonacci_alternating_sum(186..=186),
+            Err(ComputationError::Overflow(186))
+        );
+        assert_eq!(
+            fibonacci_alternating_sum(186..=187),
+            Err(ComputationError::Overflow(187))
+        );
+        assert_eq!(fibonacci_alternating_sum(184..=184), Ok(f184 as i128));
+        assert_eq!(
+            fibonacci_alternating_sum(183..=184),
+            Ok(f184 as i128 - f183 as i128)
+        );
+        assert_eq!(
+            fibonacci_alternating_sum(185..=185),
+            Err(ComputationError::Overflow(185))
+        );
+    }
+
+    #[test]
+    fn given_max_index_when_summed_mod_then_no_index_overflow() {
+        // Given
+        let (a, m) = (u128::MAX - 2, 1_000_000_007);
+
+        // When
+        let result = fibonacci_sum_mod(a..=u128::MAX, m);
+
+        // Then
+        let naive = (a..=u128::MAX).fold(0, |acc, i| (acc + fibonacci_mod(i, m).unwrap()) % m);
+        assert_eq!(result, Ok(naive));
+    }
+
+    use proptest::prelude::*;
+
+    /// Наивная сумма `terms(i)` по диапазону в `u128`; `None` при переполнении.
+    fn naive(range: RangeInclusive<u128>, term: impl Fn(u128) -> Option<u128>) -> Option<u128> {
+        range
+            .map(term)
+            .try_fold(0u128, |acc, value| acc.checked_add(value?))
+    }
+
+    proptest! {
+        #[test]
+        fn given_range_when_fibonacci_sum_then_matches_naive_checked_sum(
+            a in 0u128..200,
+            b in 0u128..200,
+        ) {
+            // Given / When
+            let result = fibonacci_sum(a..=b).ok();
+
+            // Then
+            prop_assert_eq!(result, naive(a..=b, |i| fibonacci(i).ok()));
+        }
+
+        #[test]
+        fn given_range_when_fibonacci_square_sum_then_matches_naive_checked_sum(
+            a in 0u128..120,
+            b in 0u128..120,
+        ) {
+            // Given / When
+            let result = fibonacci_square_sum(a..=b).ok();
+
+            // Then
+            let square = |i| fibonacci(i).ok().and_then(|f: u128| f.checked_mul(f));
+            prop_assert_eq!(result, naive(a..=b, square));
+        }
+
+        #[test]
+        fn given_range_when_fibonacci_alternating_sum_then_matches_naive_signed_sum(
+            a in 0u128..=180,
+            b in 0u128..=180,
+        ) {
+            // Given / When
+            let result = fibonacci_alternating_sum(a..=b);
+
+            // Then
+            let expected: i128 = (a..=b)
+                .map(|i| {
+                    let value = fibonacci(i).unwrap() as i128;
+                    if i % 2 == 1 { -value } else { value }
+                })
+                .sum();
+            prop_assert_eq!(result, Ok(expected));
+        }
+
+        #[test]
+        fn given_any_range_and_modulus_when_summed_mod_then_matches_naive_sums(
+            a: u128,
+            len in 0u128..40,
+            m in 1u128..1 << 64,
+        ) {
+            // Given
+            let b = a.saturating_add(len);
+            let terms: Vec<u128> = (a..=b).map(|i| fibonacci_mod(i, m).unwrap()).collect();
+
+            // When
+            let sum = fibonacci_sum_mod(a..=b, m);
+            let square_sum = fibonacci_square_sum_mod(a..=b, m);
+            let alternating_sum = fibonacci_alternating_sum_mod(a..=b, m);
+
+            // Then
+            let (mut expected_sum, mut expected_squares, mut expected_alternating) = (0, 0, 0);
+            for (i, &term) in (a..=b).zip(&terms) {
+                expected_sum = add_mod(expected_sum, term, m);
+                expected_squares = add_mod(expected_squares, mul_mod(term, term, m), m);
+                expected_alternating = if i % 2 == 1 {
+                    sub_mod(expected_alternating, term, m)
+                } else {
+                    add_mod(expected_alternating, term, m)
+                };
+            }
+            prop_assert_eq!(sum, Ok(expected_sum));
+            prop_assert_eq!(square_sum, Ok(expected_squares));
+            prop_assert_eq!(alternating_sum, Ok(expected_alternating));
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn given_range_when_summed_big_then_matches_naive_big_sums(
+            a in 0u128..1_000,
+            len in 0u128..40,
+        ) {
+            // Given
+            let b = a + len;
+
+            // When
+            let sum = fibonacci_sum_big(a..=b);
+            let square_sum = fibonacci_square_sum_big(a..=b);
+
+            // Then
+            let (mut expected_sum, mut expected_squares) = (BigUint::zero(), BigUint::zero());
+            for i in a..=b {
+                let term = fibonacci_big(i);
+                expected_squares = &expected_squares + &(&term * &term);
+                expected_sum = &expected_sum + &term;
+            }
+            prop_assert_eq!(sum, expected_sum);
+            prop_assert_eq!(square_sum, expected_squares);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! API для WebAssembly (feature `wasm`, цель `wasm32-unknown-unknown`).
+//!
+//! `u128` не передаётся в JavaScript, поэтому аргументы и результаты — десятичные
+//! строки. Ошибки выбрасываются как `Error` с двуязычным сообщением (RU + EN).
+//! Сборка без feature `clock` не тянет `chrono` и системные часы:
+//!
+//! ```text
+//! cargo rustc --release --target wasm32-unknown-unknown --no-default-features --features wasm --lib --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/fibonacci_stable.wasm
+//! ```
+//!
+//! ```js
+//! import init, { fibonacci, fibonacciBig, fibonacciMod, parseInput } from "./pkg/fibonacci_stable.js";
+//!
+//! await init();
+//! fibonacci("186");           // "332825110087067562321196029789634457848"
+//! fibonacciBig("1000");       // 209 цифр
+//! fibonacciMod("1000000000000", "1000000007");  // "730695249"
+//! parseInput("-5");           // Error: RU: Ошибка! Отрицательные числа не поддерживаются ...
+//! ```
+
+use std::fmt::Display;
+
+use wasm_bindgen::prelude::*;
+
+use crate::cli::Budget;
+use crate::errors::InputError;
+use crate::fibonacci::{Backend, Calibration, estimate_with};
+use crate::io::parse_input;
+
+/// Наибольшее количество цифр результата [`fibonacci_big`]: вычисление идёт
+/// в основном потоке страницы и не должно её подвешивать.
+///
+/// Дороже всего десятичный вывод — он квадратичен по длине числа. Нативно в
+/// release fib(478 000) (около 100 000 цифр) вычисляется и выводится примерно
+/// за 0,13 с, 200 000 цифр — уже за 0,5 с; в wasm заметно медленнее.
+pub const MAX_BIG_DIGITS: u128 = 100_000;
+
+fn js_error(error: impl Display) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+/// fib(n) в `u128`; ошибка переполнения для n > 186.
+#[wasm_bindgen]
+pub fn fibonacci(n: &str) -> Result<String, JsError> {
+    checked(n).map_err(js_error)
+}
+
+/// Точное fib(n), не больше [`MAX_BIG_DIGITS`] цифр.
+#[wasm_bindgen(js_name = fibonacciBig)]
+pub fn fibonacci_big(n: &str) -> Result<String, JsError> {
+    big(n).map_err(js_error)
+}
+
+/// fib(n) mod m для любых `n` и `m` в пределах `u128`.
+#[wasm_bindgen(js_name = fibonacciMod)]
+pub fn fibonacci_mod(n: &str, m: &str) -> Result<String, JsError> {
+    modular(n, m).map_err(js_error)
+}
+
+/// Проверяет ввод как [`parse_input()`] и возвращает число строкой.
+#[wasm_bindgen(js_name = parseInput)]
+pub fn parse(input: &str) -> Result<String, JsError> {
+    parse_input(input).map(|n| n.to_string()).map_err(js_error)
+}
+
+fn checked(n: &str) -> Result<String, InputError> {
+    let n = parse_input(n)?;
+    crate::fibonacci::fibonacci(n)
+        .map(|value| value.to_string())
+        .map_err(|_| InputError::ComputationOverflow(n))
+}
+
+fn big(n: &str) -> Result<String, String> {
+    let n = parse_input(n).map_err(|e| e.to_string())?;
+    let budget = Budget {
+        max_digits: Some(MAX_BIG_DIGITS),
+        max_memory_bytes: None,
+        ..Budget::default()
+    };
+    budget
+        // В `wasm32-unknown-unknown` нет часов, а для лимита цифр время не нужно.
+        .check(n, &estimate_with(n, Backend::Big, &Calibration::SIZE_ONLY))
+        .map_err(|e| e.to_string())?;
+    Ok(crate::fibonacci::fibonacci_big(n).to_string())
+}
+
+fn modular(n: &str, m: &str) -> Result<String, String> {
+    let n = parse_input(n).map_err(|e| e.to_string())?;
+    let m = parse_input(m).map_err(|e| e.to_string())?;
+    crate::modular::fibonacci_mod(n, m)
+        .map(|value| value.to_string())
+        .map_err(|e| e.bilingual_message())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_decimal_string_when_checked_then_returns_decimal_string() {
+        // Given / When
+        let ok = checked(" 186 ");
+        let overflow = checked("187");
+
+        // Then
+        assert_eq!(ok.as_deref(), Ok("332825110087067562321196029789634457848"));
+        assert_eq!(overflow, Err(InputError::ComputationOverflow(187)));
+    }
+
+    #[test]
+    fn given_index_when_big_then_exact_and_limited() {
+        // Given / When
+        let exact = big("1000").unwrap();
+        let too_big = big("10000000").unwrap_err();
+        // fib(480 000) — 100 314 цифр, чуть больше лимита
+        let just_over = big("480000").unwrap_err();
+
+        // Then
+        assert_eq!(exact.len(), 209);
+        assert!(exact.ends_with("849228875"));
+        assert!(too_big.contains("EN: Error! Computing fibonacci(10000000) exceeds the budget"));
+        assert!(just_over.contains(&MAX_BIG_DIGITS.to_string()));
+    }
+
+    #[test]
+    fn given_invalid_input_when_modular_then_bilingual_message() {
+        // Given / When
+        let ok = modular("1000000000000", "1000000007");
+        let negative = modular("-1", "7").unwrap_err();
+        let zero = modular("10", "0").unwrap_err();
+
+        // Then
+        assert_eq!(ok.as_deref(), Ok("730695249"));
+        assert!(negative.contains("RU: Ошибка!") && negative.contains("EN: Error!"));
+        assert_eq!(
+            zero,
+            "RU: Ошибка! Модуль должен быть больше нуля\nEN: Error! The modulus must be greater than zero"
+        );
+    }
+}
@@ -0,0 +1,67 @@
+/* Проверка C ABI: компилируется и запускается тестом tests/ffi.rs. */
+#include <stdio.h>
+#include <stdint.h>
+
+#include "fibonacci_stable.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                       \
+    do {                                                                  \
+        if (!(cond)) {                                                    \
+            fprintf(stderr, "%s:%d: CHECK failed: %s\n", __FILE__, __LINE__, #cond); \
+            failures++;                                                   \
+        }                                                                 \
+    } while (0)
+
+static FibU128 make(uint64_t lo, uint64_t hi) {
+    FibU128 value = {lo, hi};
+    return value;
+}
+
+static int equal(FibU128 a, FibU128 b) {
+    return a.lo == b.lo && a.hi == b.hi;
+}
+
+int main(void) {
+    FibU128 out;
+
+    /* fib_u128: сверка с итеративным сложением на __int128 для 0..=186 */
+    unsigned __int128 prev = 0, cur = 1;
+    for (uint64_t n = 0; n <= 186; n++) {
+        unsigned __int128 expected = prev;
+        CHECK(fib_u128(make(n, 0), &out) == FIB_STATUS_OK);
+        CHECK(equal(out, make((uint64_t)expected, (uint64_t)(expected >> 64))));
+        unsigned __int128 next = prev + cur;
+        prev = cur;
+        cur = next;
+    }
+    CHECK(fib_u128(make(187, 0), &out) == FIB_STATUS_OVERFLOW);
+    CHECK(fib_u128(make(0, 1), &out) == FIB_STATUS_OVERFLOW);
+    CHECK(fib_u128(make(10, 0), NULL) == FIB_STATUS_NULL_POINTER);
+
+    /* fib_mod */
+    CHECK(fib_mod(make(10, 0), make(7, 0), &out) == FIB_STATUS_OK);
+    CHECK(equal(out, make(55 % 7, 0)));
+    CHECK(fib_mod(make(1000000000000ULL, 0), make(1000000007, 0), &out) == FIB_STATUS_OK);
+    CHECK(equal(out, make(730695249, 0)));
+    CHECK(fib_mod(make(10, 0), make(0, 0), &out) == FIB_STATUS_ZERO_MODULUS);
+
+    /* fib_parse */
+    CHECK(fib_parse("  42 ", &out) == FIB_STATUS_OK);
+    CHECK(equal(out, make(42, 0)));
+    CHECK(fib_parse("340282366920938463463374607431768211455", &out) == FIB_STATUS_OK);
+    CHECK(equal(out, make(UINT64_MAX, UINT64_MAX)));
+    CHECK(fib_parse("", &out) == FIB_STATUS_EMPTY_INPUT);
+    CHECK(fib_parse("-5", &out) == FIB_STATUS_NEGATIVE_NUMBER);
+    CHECK(fib_parse("3.14", &out) == FIB_STATUS_FLOAT_NUMBER);
+    CHECK(fib_parse("abc", &out) == FIB_STATUS_INVALID_CHARACTERS);
+    CHECK(fib_parse("1 2", &out) == FIB_STATUS_MULTIPLE_VALUES);
+    CHECK(fib_parse("340282366920938463463374607431768211456", &out) == FIB_STATUS_NUMBER_TOO_LARGE);
+    CHECK(fib_parse(NULL, &out) == FIB_STATUS_NULL_POINTER);
+
+    if (failures == 0) {
+        printf("ok\n");
+    }
+    return failures == 0 ? 0 : 1;
+}
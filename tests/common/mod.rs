@@ -0,0 +1,41 @@
+//! Общие помощники интеграционных тестов.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+pub fn manifest_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Каталог сборки тестов (`target/debug`).
+pub fn target_dir() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    // target/debug/deps/<test>-<hash> → target/debug
+    exe.parent().unwrap().parent().unwrap().to_path_buf()
+}
+
+/// Собирает библиотеку как `crate_type` (`staticlib`, `cdylib`) с заданными features
+/// и возвращает путь к файлу `file_name`.
+///
+/// В `Cargo.toml` нет `[lib] crate-type`, иначе крейт не собирался бы без `std`,
+/// поэтому `cdylib` и `staticlib` собираются отдельно через `cargo rustc`
+/// в собственный каталог, чтобы не ждать блокировку основной сборки.
+pub fn build_library(crate_type: &str, features: &str, file_name: &str) -> PathBuf {
+    let target = target_dir().join(format!("{}-build", crate_type));
+    let output = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+        .current_dir(manifest_dir())
+        .args(["rustc", "--lib", "--crate-type", crate_type])
+        .args(["--no-default-features", "--features", features])
+        .arg("--target-dir")
+        .arg(&target)
+        .output()
+        .expect("не удалось запустить cargo");
+    assert!(
+        output.status.success(),
+        "cargo rustc --crate-type {}:\n{}",
+        crate_type,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    target.join("debug").join(file_name)
+}
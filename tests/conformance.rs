@@ -0,0 +1,435 @@
+//! Проверка всех алгоритмов и бэкендов крейта по эталонному корпусу.
+//!
+//! Корпус лежит в `tests/conformance/` и сгенерирован скриптом
+//! `tests/conformance/generate.py` независимо от кода крейта:
+//!
+//! * `fibonacci.txt` — точные F(n) для n = 0..=186 и выборочных больших индексов;
+//! * `modular.txt` — F(n) mod m для индексов до `u128::MAX` и модулей до `u128::MAX`.
+//!
+//! Новый способ вычисления F(n) должен добавить сюда свою проверку.
+#![cfg(feature = "std")]
+
+use std::num::{Saturating, Wrapping};
+
+use fibonacci_stable::approx::{MAX_LEADING_DIGITS, fibonacci_digits, fibonacci_leading_digits};
+use fibonacci_stable::ffi::{FibStatus, FibU128, fib_mod, fib_u128};
+use fibonacci_stable::fibonacci::{FIBONACCI_TABLE, fibonacci_index_of, fibonacci_range};
+use fibonacci_stable::line_protocol::respond;
+use fibonacci_stable::matrix::Mat2;
+use fibonacci_stable::modular::{
+    MAX_LAST_DIGITS, fibonacci_last_digits, fibonacci_mod, fibonacci_tail,
+};
+use fibonacci_stable::parallel::{fibonacci_big_many, fibonacci_big_parallel, fibonacci_many};
+use fibonacci_stable::{
+    ComputationError, FibNum, Mode, Options, OverflowPolicy, fibonacci, fibonacci_big,
+    fibonacci_const, fibonacci_in, fibonacci_with_policy,
+};
+
+/// Точное значение F(n) из `fibonacci.txt`.
+struct Exact {
+    n: u128,
+    value: String,
+}
+
+impl Exact {
+    /// Значение, если оно помещается в `u128` (n ≤ 186).
+    fn small(&self) -> Option<u128> {
+        self.value.parse().ok()
+    }
+}
+
+/// Значение F(n) mod m из `modular.txt`.
+struct Modular {
+    n: u128,
+    m: u128,
+    value: u128,
+}
+
+/// Строки корпуса без комментариев, разбитые на поля.
+fn records(corpus: &str) -> impl Iterator<Item = Vec<&str>> {
+    corpus
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.split(' ').collect())
+}
+
+fn exact_corpus() -> Vec<Exact> {
+    records(include_str!("conformance/fibonacci.txt"))
+        .map(|fields| Exact {
+            n: fields[0].parse().unwrap(),
+            value: fields[1].to_string(),
+        })
+        .collect()
+}
+
+fn modular_corpus() -> Vec<Modular> {
+    records(include_str!("conformance/modular.txt"))
+        .map(|fields| Modular {
+            n: fields[0].parse().unwrap(),
+            m: fields[1].parse().unwrap(),
+            value: fields[2].parse().unwrap(),
+        })
+        .collect()
+}
+
+/// Последние 128 бит десятичного числа, посчитанные независимо от [`BigUint`](fibonacci_stable::BigUint).
+fn low_128_bits(decimal: &str) -> u128 {
+    decimal.bytes().fold(0u128, |acc, digit| {
+        acc.wrapping_mul(10).wrapping_add((digit - b'0') as u128)
+    })
+}
+
+#[test]
+fn given_corpus_when_loaded_then_covers_every_u128_value_and_big_indices() {
+    // Given / When
+    let exact = exact_corpus();
+    let modular = modular_corpus();
+
+    // Then
+    let small: Vec<u128> = exact
+        .iter()
+        .filter_map(|e| e.small().map(|_| e.n))
+        .collect();
+    assert_eq!(small, (0..=186).collect::<Vec<_>>());
+    assert!(exact.iter().any(|e| e.n >= 50_000));
+    assert!(modular.iter().any(|r| r.n == u128::MAX && r.m == u128::MAX));
+}
+
+#[test]
+fn given_u128_corpus_when_u128_algorithms_then_match() {
+    for entry in exact_corpus() {
+        let Some(expected) = entry.small() else {
+            continue;
+        };
+        let n = entry.n;
+        let mut out = FibU128::default();
+
+        // When
+        // SAFETY: `out` — локальная переменная, доступная для записи.
+        let status = unsafe { fib_u128(n.into(), &mut out) };
+
+        // Then
+        assert_eq!(fibonacci(n), Ok(expected), "fibonacci({})", n);
+        for policy in [
+            OverflowPolicy::Checked,
+            OverflowPolicy::Wrapping,
+            OverflowPolicy::Saturating,
+        ] {
+            assert_eq!(
+                fibonacci_with_policy(n, policy),
+                Ok(expected),
+                "{:?}, n={}",
+                policy,
+                n
+            );
+        }
+        assert_eq!(
+            fibonacci_const(n as u32),
+            Some(expected),
+            "fibonacci_const({})",
+            n
+        );
+        assert_eq!(
+            FIBONACCI_TABLE[n as usize], expected,
+            "FIBONACCI_TABLE[{}]",
+            n
+        );
+        // Q^n содержит F(n + 1), поэтому для F(186) берём Q^(n−1) = [[F(n), F(n−1)], …].
+        if n > 0 {
+            assert_eq!(
+                Mat2::Q
+                    .pow(n - 1, OverflowPolicy::Checked)
+                    .map(|q| q.0[0][0]),
+                Ok(expected),
+                "Q^{}",
+                n - 1
+            );
+        }
+        assert_eq!(
+            (status, u128::from(out)),
+            (FibStatus::Ok, expected),
+            "fib_u128({})",
+            n
+        );
+        // 1 = F(1) = F(2): fibonacci_index_of возвращает меньший индекс.
+        let index = if n == 2 { 1 } else { n };
+        assert_eq!(
+            fibonacci_index_of(expected),
+            Some(index),
+            "fibonacci_index_of(F({}))",
+            n
+        );
+    }
+}
+
+/// Сверяет `fibonacci_in::<T>` с корпусом: значение, если оно помещается в `T`,
+/// иначе переполнение.
+fn check_primitive<T>(corpus: &[Exact])
+where
+    T: FibNum + TryFrom<u128> + PartialEq + core::fmt::Debug,
+{
+    for entry in corpus {
+        let expected = match entry.small().map(T::try_from) {
+            Some(Ok(value)) => Ok(value),
+            _ => Err(ComputationError::Overflow(entry.n)),
+        };
+        assert_eq!(
+            fibonacci_in::<T>(entry.n),
+            expected,
+            "fibonacci_in::<{}>({})",
+            core::any::type_name::<T>(),
+            entry.n
+        );
+    }
+}
+
+#[test]
+fn given_corpus_when_fibonacci_in_primitive_types_then_match_or_overflow() {
+    // Given
+    let corpus = exact_corpus();
+
+    // When / Then
+    check_primitive::<u8>(&corpus);
+    check_primitive::<u16>(&corpus);
+    check_primitive::<u32>(&corpus);
+    check_primitive::<u64>(&corpus);
+    check_primitive::<u128>(&corpus);
+    check_primitive::<usize>(&corpus);
+    check_primitive::<i8>(&corpus);
+    check_primitive::<i16>(&corpus);
+    check_primitive::<i32>(&corpus);
+    check_primitive::<i64>(&corpus);
+    check_primitive::<i128>(&corpus);
+    check_primitive::<isize>(&corpus);
+}
+
+#[test]
+fn given_corpus_when_overflow_policies_beyond_u128_then_match() {
+    for entry in exact_corpus().iter().filter(|e| e.small().is_none()) {
+        let n = entry.n;
+
+        // When / Then
+        assert_eq!(
+            fibonacci_with_policy(n, OverflowPolicy::Checked),
+            Err(ComputationError::Overflow(n))
+        );
+        assert_eq!(
+            fibonacci_with_policy(n, OverflowPolicy::Wrapping),
+            Ok(low_128_bits(&entry.value)),
+            "Wrapping, n={}",
+            n
+        );
+        assert_eq!(
+            fibonacci_with_policy(n, OverflowPolicy::Saturating),
+            Ok(u128::MAX),
+            "Saturating, n={}",
+            n
+        );
+        assert_eq!(
+            Mat2::Q.pow(n, OverflowPolicy::Wrapping).map(|q| q.0[0][1]),
+            Ok(low_128_bits(&entry.value)),
+            "Q^{} с Wrapping",
+            n
+        );
+        assert_eq!(
+            fibonacci_in::<Saturating<u64>>(n),
+            Ok(Saturating(u64::MAX)),
+            "Saturating<u64>, n={}",
+            n
+        );
+    }
+}
+
+#[test]
+fn given_corpus_when_batch_algorithms_then_match() {
+    // Given
+    let corpus = exact_corpus();
+    let small: Vec<u128> = corpus.iter().filter_map(Exact::small).collect();
+    let indices: Vec<u128> = corpus.iter().map(|e| e.n).collect();
+
+    // When
+    let range = fibonacci_range(0..=186, OverflowPolicy::Checked);
+    let many = fibonacci_many(&indices[..small.len()], OverflowPolicy::Checked, 3);
+    let big_many = fibonacci_big_many(&indices, 3);
+
+    // Then
+    assert_eq!(range, Ok(small.clone()));
+    assert_eq!(many, small.into_iter().map(Ok).collect::<Vec<_>>());
+    for (entry, value) in corpus.iter().zip(big_many) {
+        assert_eq!(
+            value.to_string(),
+            entry.value,
+            "fibonacci_big_many, n={}",
+            entry.n
+        );
+    }
+}
+
+#[test]
+fn given_corpus_when_big_backends_then_match_exactly() {
+    for entry in exact_corpus() {
+        let n = entry.n;
+
+        // When / Then
+        assert_eq!(
+            fibonacci_big(n).to_string(),
+            entry.value,
+            "fibonacci_big({})",
+            n
+        );
+        for threads in [2, 4] {
+            assert_eq!(
+                fibonacci_big_parallel(n, threads).to_string(),
+                entry.value,
+                "fibonacci_big_parallel({}, {})",
+                n,
+                threads
+            );
+        }
+    }
+}
+
+#[test]
+fn given_corpus_when_approx_backend_then_digit_count_and_leading_digits_match() {
+    for entry in exact_corpus() {
+        let n = entry.n;
+
+        // When / Then
+        assert_eq!(
+            fibonacci_digits(n),
+            entry.value.len() as u128,
+            "fibonacci_digits({})",
+            n
+        );
+        for k in 1..=MAX_LEADING_DIGITS {
+            let prefix = &entry.value[..entry.value.len().min(k as usize)];
+            assert_eq!(
+                fibonacci_leading_digits(n, k),
+                Some(prefix.parse().unwrap()),
+                "fibonacci_leading_digits({}, {})",
+                n,
+                k
+            );
+        }
+    }
+}
+
+#[test]
+fn given_corpus_when_last_digits_backend_then_suffixes_match() {
+    for entry in exact_corpus() {
+        let n = entry.n;
+        for k in 1..=MAX_LAST_DIGITS {
+            // Given
+            let len = entry.value.len();
+            let suffix = &entry.value[len.saturating_sub(k as usize)..];
+
+            // When
+            let tail = fibonacci_tail(n, k).unwrap();
+
+            // Then
+            assert_eq!(
+                fibonacci_last_digits(n, k),
+                Some(suffix.parse().unwrap()),
+                "fibonacci_last_digits({}, {})",
+                n,
+                k
+            );
+            let shown = if len <= k as usize {
+                entry.value.clone()
+            } else {
+                format!("…{}", suffix)
+            };
+            assert_eq!(tail.to_string(), shown, "fibonacci_tail({}, {})", n, k);
+        }
+    }
+}
+
+#[test]
+fn given_modular_corpus_when_modular_algorithms_then_match() {
+    for Modular { n, m, value } in modular_corpus() {
+        let mut out = FibU128::default();
+
+        // When
+        // SAFETY: `out` — локальная переменная, доступная для записи.
+        let status = unsafe { fib_mod(n.into(), m.into(), &mut out) };
+
+        // Then
+        assert_eq!(
+            fibonacci_mod(n, m),
+            Ok(value),
+            "fibonacci_mod({}, {})",
+            n,
+            m
+        );
+        assert_eq!(
+            Mat2::Q.pow_mod(n, m).map(|q| q.0[0][1]),
+            Ok(value),
+            "Q^{} mod {}",
+            n,
+            m
+        );
+        assert_eq!(
+            (status, u128::from(out)),
+            (FibStatus::Ok, value),
+            "fib_mod({}, {})",
+            n,
+            m
+        );
+    }
+}
+
+#[test]
+fn given_modular_corpus_when_power_of_two_and_ten_moduli_then_wrapping_and_last_digits_match() {
+    for Modular { n, m, value } in modular_corpus() {
+        // When / Then: Wrapping<T> считает по модулю 2^bits(T)
+        match m {
+            0x100 => assert_eq!(fibonacci_in::<Wrapping<u8>>(n), Ok(Wrapping(value as u8))),
+            0x1_0000_0000 => {
+                assert_eq!(fibonacci_in::<Wrapping<u32>>(n), Ok(Wrapping(value as u32)))
+            }
+            0x1_0000_0000_0000_0000 => {
+                assert_eq!(fibonacci_in::<Wrapping<u64>>(n), Ok(Wrapping(value as u64)))
+            }
+            _ => {}
+        }
+        if let Some(k) = (1..=MAX_LAST_DIGITS).find(|&k| 10u128.pow(k) == m) {
+            assert_eq!(
+                fibonacci_last_digits(n, k),
+                Some(value),
+                "fibonacci_last_digits({}, {})",
+                n,
+                k
+            );
+        }
+    }
+}
+
+#[test]
+fn given_corpus_when_line_protocol_in_u128_and_big_modes_then_replies_match() {
+    // Given
+    let u128_mode = Options::default();
+    let big_mode = Options {
+        mode: Mode::Big,
+        ..Options::default()
+    };
+
+    for entry in exact_corpus() {
+        // When
+        let request = format!("FIB {}", entry.n);
+        let small = respond(&request, &u128_mode);
+        let big = respond(&request, &big_mode);
+
+        // Then
+        let expected_small = match entry.small() {
+            Some(value) => format!("OK {}", value),
+            None => format!("ERR COMPUTATION_OVERFLOW {}", entry.n),
+        };
+        assert_eq!(small, expected_small);
+        assert_eq!(
+            big,
+            format!("OK {}", entry.value),
+            "FIB {} в режиме Big",
+            entry.n
+        );
+    }
+}
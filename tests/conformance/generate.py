@@ -0,0 +1,110 @@
+"""Генерирует эталонный корпус tests/conformance/*.txt.
+
+Значения считаются только средствами Python (целые произвольной точности) и не
+зависят от кода крейта. Каждое значение получается двумя независимыми способами,
+которые обязаны совпасть:
+
+* точные F(n) — сложением по рекуррентности и быстрым удвоением;
+* F(n) mod m — быстрым удвоением по модулю и возведением Q-матрицы в степень
+  по модулю, а для n ≤ 10 000 ещё и остатком точного значения.
+
+Запуск из корня репозитория: python3 tests/conformance/generate.py
+"""
+
+import pathlib
+import sys
+
+sys.set_int_max_str_digits(0)
+
+ROOT = pathlib.Path(__file__).parent
+U128_MAX = 2**128 - 1
+
+BIG_INDICES = [187, 188, 200, 255, 256, 300, 500, 1000, 1500, 2000, 3000, 4096, 10_000, 50_000]
+
+MOD_INDICES = [0, 1, 2, 10, 93, 186, 187, 1000, 10_000, 10**18, 2**64, 2**127, U128_MAX]
+MODULI = [
+    1,
+    2,
+    3,
+    10,
+    1000,
+    2**8,
+    2**32,
+    10**9 + 7,
+    2**64 - 59,
+    2**64,
+    10**20,
+    2**127 - 1,
+    10**38,
+    U128_MAX,
+]
+
+
+def by_addition(limit):
+    values = [0, 1]
+    while len(values) <= limit:
+        values.append(values[-1] + values[-2])
+    return values
+
+
+def doubling(n, m=None):
+    """(F(n), F(n+1)), по модулю m, если он задан."""
+    a, b = 0, 1
+    for bit in bin(n)[2:]:
+        c = a * (2 * b - a)
+        d = a * a + b * b
+        if m is not None:
+            c, d = c % m, d % m
+        a, b = (d, c + d) if bit == "1" else (c, d)
+        if m is not None:
+            b %= m
+    return a if m is None else a % m
+
+
+def matrix_power(n, m):
+    """F(n) mod m как элемент [0][1] матрицы Q^n."""
+
+    def mul(x, y):
+        return [
+            [(x[0][0] * y[0][0] + x[0][1] * y[1][0]) % m, (x[0][0] * y[0][1] + x[0][1] * y[1][1]) % m],
+            [(x[1][0] * y[0][0] + x[1][1] * y[1][0]) % m, (x[1][0] * y[0][1] + x[1][1] * y[1][1]) % m],
+        ]
+
+    result, base = [[1 % m, 0], [0, 1 % m]], [[1, 1], [1, 0]]
+    while n:
+        if n & 1:
+            result = mul(result, base)
+        base = mul(base, base)
+        n >>= 1
+    return result[0][1]
+
+
+def main():
+    exact = by_addition(max(BIG_INDICES))
+    indices = list(range(187)) + BIG_INDICES
+    for n in indices:
+        assert exact[n] == doubling(n), n
+
+    with open(ROOT / "fibonacci.txt", "w") as out:
+        out.write("# Точные значения F(n): F(0) = 0, F(1) = 1, F(n) = F(n-1) + F(n-2) (OEIS A000045).\n")
+        out.write("# n = 0..=186 — все значения, помещающиеся в u128; дальше — выборочные большие индексы.\n")
+        out.write("# Источник: tests/conformance/generate.py, сложение и быстрое удвоение на целых Python.\n")
+        out.write("# Формат: <n> <F(n)>\n")
+        for n in indices:
+            out.write(f"{n} {exact[n]}\n")
+
+    with open(ROOT / "modular.txt", "w") as out:
+        out.write("# Значения F(n) mod m для больших n и модулей вплоть до u128::MAX.\n")
+        out.write("# Источник: tests/conformance/generate.py, быстрое удвоение и Q-матрица по модулю на целых Python.\n")
+        out.write("# Формат: <n> <m> <F(n) mod m>\n")
+        for n in MOD_INDICES:
+            for m in MODULI:
+                value = doubling(n, m)
+                assert value == matrix_power(n, m), (n, m)
+                if n < len(exact):
+                    assert value == exact[n] % m, (n, m)
+                out.write(f"{n} {m} {value}\n")
+
+
+if __name__ == "__main__":
+    main()
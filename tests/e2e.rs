@@ -1,8 +1,33 @@
+#![cfg(feature = "std")]
+
+use std::fs;
 use std::io::Write;
+use std::path::Path;
 use std::process::{Command, Stdio};
 
+/// Команда запуска бинарника в пустом каталоге без файла конфигурации и
+/// переменных `FIBONACCI_STABLE_*`, чтобы окружение разработчика не влияло на тесты.
+fn isolated_command() -> Command {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("e2e");
+    fs::create_dir_all(&dir).unwrap();
+
+    let mut command = Command::new(env!("CARGO_BIN_EXE_fibonacci_stable"));
+    for (key, _) in std::env::vars_os() {
+        if key.to_string_lossy().starts_with("FIBONACCI_STABLE_") {
+            command.env_remove(key);
+        }
+    }
+    command.current_dir(&dir).env("XDG_CONFIG_HOME", &dir);
+    command
+}
+
 fn run_with_input(input: &str) -> String {
-    let mut child = Command::new(env!("CARGO_BIN_EXE_fibonacci_stable"))
+    run_with_args(&[], input)
+}
+
+fn run_with_args(args: &[&str], input: &str) -> String {
+    let mut child = isolated_command()
+        .args(args)
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .spawn()
@@ -138,3 +163,228 @@ fn given_invalid_input_when_run_then_shows_ru_and_en_error() {
         stdout
     );
 }
+
+#[test]
+fn given_wrapping_policy_when_run_with_overflowing_input_then_outputs_modular_result() {
+    // Given / When
+    let stdout = run_with_args(&["--overflow", "wrapping"], "187\n");
+
+    // Then
+    assert!(
+        stdout.contains("fibonacci(187) = 198239973509362327032045173661212819077"),
+        "stdout: {}",
+        stdout
+    );
+}
+
+#[test]
+fn given_saturating_policy_when_run_with_overflowing_input_then_outputs_max() {
+    // Given / When
+    let stdout = run_with_args(&["--overflow=saturating"], "1000\n");
+
+    // Then
+    assert!(
+        stdout.contains(&format!("fibonacci(1000) = {}", u128::MAX)),
+        "stdout: {}",
+        stdout
+    );
+}
+
+#[test]
+fn given_invalid_policy_when_run_then_exits_with_bilingual_error() {
+    // Given / When
+    let output = isolated_command()
+        .args(["--overflow", "modular"])
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+
+    // Then
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr.contains("RU: Ошибка!"), "stderr: {}", stderr);
+    assert!(stderr.contains("EN: Error!"), "stderr: {}", stderr);
+}
+
+#[test]
+fn given_overflow_when_run_then_error_suggests_alternatives() {
+    // Given / When
+    let stdout = run_with_input("187\nq\n");
+
+    // Then
+    assert!(
+        stdout.contains("the largest index that fits in u128 is 186"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(stdout.contains("--big"), "stdout: {}", stdout);
+}
+
+#[test]
+fn given_big_mode_when_run_with_overflowing_input_then_outputs_exact_result() {
+    // Given / When
+    let stdout = run_with_args(&["--big"], "187\n");
+
+    // Then
+    assert!(
+        stdout.contains("fibonacci(187) = 538522340430300790495419781092981030533"),
+        "stdout: {}",
+        stdout
+    );
+}
+
+#[test]
+fn given_approx_mode_when_run_with_huge_index_then_outputs_leading_digits_and_exponent() {
+    // Given / When
+    let stdout = run_with_args(&["--approx", "--batch"], "1000000000\n10\n");
+
+    // Then
+    assert!(
+        stdout.contains("fibonacci(1000000000) ≈ 7.952317874554683…e208987639"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("fibonacci(10) ≈ 5.5e1"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("Режим вычисления: approx"),
+        "stdout: {}",
+        stdout
+    );
+}
+
+#[test]
+fn given_last_digits_mode_when_run_with_huge_index_then_outputs_trailing_digits() {
+    // Given / When
+    let stdout = run_with_args(
+        &["--last-digits", "20", "--batch"],
+        "1000000000000000000\n10\n",
+    );
+
+    // Then
+    assert!(
+        stdout.contains("fibonacci(1000000000000000000) = …41183788299560546875"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(stdout.contains("fibonacci(10) = 55"), "stdout: {}", stdout);
+}
+
+#[test]
+fn given_digit_budget_when_run_with_large_index_then_refuses_and_recovers() {
+    // Given / When
+    let stdout = run_with_args(&["--big", "--max-digits", "100"], "1000\n10\n");
+
+    // Then
+    assert!(
+        stdout.contains("EN: Error! Computing fibonacci(1000) exceeds the budget: ~209 digits"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(!stdout.contains("fibonacci(1000) ="), "stdout: {}", stdout);
+    assert!(stdout.contains("fibonacci(10) = 55"), "stdout: {}", stdout);
+}
+
+#[test]
+fn given_warn_budget_action_when_run_with_large_index_then_computes_anyway() {
+    // Given / When
+    let stdout = run_with_args(&["--big", "--max-digits=100", "--on-budget=warn"], "1000\n");
+
+    // Then
+    assert!(stdout.contains("Continuing anyway"), "stdout: {}", stdout);
+    assert!(
+        stdout.contains("fibonacci(1000) = 4346655768"),
+        "stdout: {}",
+        stdout
+    );
+}
+
+#[test]
+fn given_big_mode_when_run_with_huge_index_then_default_memory_budget_refuses() {
+    // Given / When
+    let stdout = run_with_args(&["--big"], "1000000000000\nq\n");
+
+    // Then
+    assert!(stdout.contains("bytes of memory"), "stdout: {}", stdout);
+    assert!(stdout.contains("До свидания!"), "stdout: {}", stdout);
+}
+
+#[test]
+fn given_batch_mode_when_run_with_threads_then_outputs_in_input_order() {
+    // Given
+    let input = "30\n5\nabc\n187\n0\n";
+
+    // When
+    let stdout = run_with_args(&["--batch", "--threads", "4"], input);
+
+    // Then
+    let results: Vec<&str> = stdout
+        .lines()
+        .filter(|line| line.starts_with("fibonacci(") || line.starts_with('\''))
+        .collect();
+    assert_eq!(
+        results,
+        [
+            "fibonacci(30) = 832040",
+            "fibonacci(5) = 5",
+            "'abc':",
+            "fibonacci(0) = 0",
+        ],
+        "stdout: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("Overflow when computing fibonacci(187)"),
+        "stdout: {}",
+        stdout
+    );
+}
+
+#[test]
+fn given_big_batch_when_run_with_different_threads_then_output_identical() {
+    // Given
+    let input = "1000\n20000\n187\n";
+
+    // When
+    let single = run_with_args(&["--batch", "--big", "--threads", "1"], input);
+    let multi = run_with_args(&["--batch", "--big", "--threads", "8"], input);
+
+    // Then
+    let results = |stdout: &str| -> Vec<String> {
+        stdout
+            .lines()
+            .filter(|line| line.starts_with("fibonacci("))
+            .map(str::to_string)
+            .collect()
+    };
+    assert_eq!(results(&single).len(), 3);
+    assert_eq!(results(&single), results(&multi));
+}
+
+#[test]
+fn given_verify_subcommand_when_run_then_reports_all_identities_ok() {
+    // Given / When
+    let output = isolated_command()
+        .args(["verify", "--up-to", "40"])
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+
+    // Then
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(output.status.code(), Some(0), "stdout: {}", stdout);
+    for name in [
+        "cassini",
+        "catalan",
+        "d'ocagne",
+        "vajda",
+        "gcd",
+        "square-sum",
+    ] {
+        assert!(stdout.contains(name), "stdout: {}", stdout);
+    }
+    assert!(!stdout.contains("НАРУШЕНО"), "stdout: {}", stdout);
+}
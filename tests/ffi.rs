@@ -0,0 +1,77 @@
+//! Тесты C ABI: актуальность сгенерированного заголовка и программа на C,
+//! собранная со `staticlib`.
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::env;
+use std::fs;
+use std::process::Command;
+
+use common::{build_library, manifest_dir, target_dir};
+
+fn generate_header() -> String {
+    let config = cbindgen::Config::from_file(manifest_dir().join("cbindgen.toml")).unwrap();
+    let mut header = Vec::new();
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(manifest_dir().join("src/ffi.rs"))
+        .generate()
+        .expect("cbindgen не смог разобрать src/ffi.rs")
+        .write(&mut header);
+    String::from_utf8(header).unwrap()
+}
+
+#[test]
+fn given_ffi_module_when_generate_header_then_matches_committed_header() {
+    // Given
+    let path = manifest_dir().join("include/fibonacci_stable.h");
+
+    // When
+    let generated = generate_header();
+    if env::var_os("UPDATE_FFI_HEADER").is_some() {
+        fs::write(&path, &generated).unwrap();
+    }
+
+    // Then
+    let committed = fs::read_to_string(&path).unwrap();
+    assert_eq!(
+        committed, generated,
+        "include/fibonacci_stable.h устарел, обновите: UPDATE_FFI_HEADER=1 cargo test --test ffi"
+    );
+}
+
+#[test]
+fn given_c_program_when_linked_with_staticlib_then_all_checks_pass() {
+    // Given
+    let library = build_library("staticlib", "std", "libfibonacci_stable.a");
+    let program = target_dir().join("ffi_test_c");
+
+    // When
+    let compiled = Command::new("cc")
+        .arg(manifest_dir().join("tests/c/ffi_test.c"))
+        .arg("-I")
+        .arg(manifest_dir().join("include"))
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-o")
+        .arg(&program)
+        .arg(&library)
+        .args(["-lpthread", "-ldl", "-lm"])
+        .output()
+        .expect("не удалось запустить cc");
+    assert!(
+        compiled.status.success(),
+        "cc: {}",
+        String::from_utf8_lossy(&compiled.stderr)
+    );
+    let run = Command::new(&program).output().unwrap();
+
+    // Then
+    assert!(
+        run.status.success(),
+        "C-тест упал:\n{}",
+        String::from_utf8_lossy(&run.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&run.stdout), "ok\n");
+}
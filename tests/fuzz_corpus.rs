@@ -0,0 +1,100 @@
+//! Прогон корпуса фаззинга на stable Rust.
+//!
+//! Проверки таргетов лежат в `fuzz/src/lib.rs` и подключаются сюда как модуль,
+//! поэтому libFuzzer и nightly не нужны. Прогоняются затравочные входы из
+//! `fuzz/seeds/<таргет>/` и, если есть, найденные фаззером падения из
+//! `fuzz/artifacts/<таргет>/` — так падение воспроизводится обычным
+//! `cargo test --test fuzz_corpus`.
+#![cfg(feature = "std")]
+
+#[path = "../fuzz/src/lib.rs"]
+mod harness;
+
+use std::fs;
+use std::path::Path;
+
+/// Проверка одного таргета.
+type Check = fn(&[u8]);
+
+/// Таргеты из `fuzz/fuzz_targets/` и их проверки.
+const TARGETS: &[(&str, Check)] = &[
+    ("fuzz_parse_input", harness::parse_input_str),
+    ("fuzz_fibonacci", harness::fibonacci_u128),
+    ("fuzz_read_input", harness::read_input_stream),
+    ("fuzz_app_run", harness::app_run),
+    ("fuzz_logger", harness::logger_utf8),
+    ("fuzz_error_display", harness::error_display),
+    ("fuzz_differential", harness::fibonacci_differential),
+];
+
+fn manifest_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Прогоняет через `check` все файлы каталога; возвращает их количество.
+fn replay(dir: &Path, check: Check) -> usize {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+    let mut paths: Vec<_> = entries.map(|entry| entry.unwrap().path()).collect();
+    paths.sort();
+    for path in &paths {
+        // Печатается до проверки: при панике видно, на каком входе она случилась.
+        println!("{}", path.display());
+        check(&fs::read(path).unwrap());
+    }
+    paths.len()
+}
+
+#[test]
+fn given_every_fuzz_target_when_listed_then_has_check_and_seeds() {
+    // Given
+    let mut targets: Vec<String> = fs::read_dir(manifest_dir().join("fuzz/fuzz_targets"))
+        .unwrap()
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            path.file_stem().unwrap().to_string_lossy().into_owned()
+        })
+        .collect();
+    targets.sort();
+
+    // When
+    let mut checked: Vec<String> = TARGETS.iter().map(|(name, _)| name.to_string()).collect();
+    checked.sort();
+
+    // Then
+    assert_eq!(targets, checked, "каждому таргету нужна строка в TARGETS");
+    for target in &targets {
+        let seeds = manifest_dir().join("fuzz/seeds").join(target);
+        assert!(
+            fs::read_dir(&seeds).is_ok_and(|mut dir| dir.next().is_some()),
+            "нет затравочного корпуса {}",
+            seeds.display()
+        );
+    }
+}
+
+#[test]
+fn given_seed_corpus_when_replayed_then_no_target_panics() {
+    for &(target, check) in TARGETS {
+        // When
+        let seeds = replay(&manifest_dir().join("fuzz/seeds").join(target), check);
+        replay(&manifest_dir().join("fuzz/artifacts").join(target), check);
+
+        // Then
+        assert!(seeds > 0, "{}: пустой затравочный корпус", target);
+    }
+}
+
+#[test]
+fn given_edge_inputs_when_every_target_runs_then_no_panic() {
+    // Given: входы, которых может не быть в корпусе
+    let inputs: [&[u8]; 5] = [b"", b"\n", b"\xff", &[0xff; 64], &[0; 64]];
+
+    for &(_, check) in TARGETS {
+        for input in inputs {
+            // When / Then
+            check(input);
+        }
+    }
+}
@@ -1,4 +1,13 @@
-use fibonacci_stable::{Logger, fibonacci, parse_input};
+#![cfg(feature = "std")]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::OnceLock;
+use std::thread;
+
+use fibonacci_stable::fibonacci::{FIBONACCI_TABLE, FIBONACCI_TABLE_LEN};
+use fibonacci_stable::server::serve;
+use fibonacci_stable::{Logger, Options, fibonacci, fibonacci_const, line_protocol, parse_input};
 
 #[test]
 fn given_valid_input_when_full_workflow_then_logger_tracks_everything() {
@@ -21,6 +30,7 @@ fn given_valid_input_when_full_workflow_then_logger_tracks_everything() {
 }
 
 #[test]
+#[allow(clippy::useless_vec, clippy::manual_ok_err)]
 fn given_invalid_then_valid_input_when_parse_then_second_succeeds() {
     // Given
     let inputs = vec!["abc", "-5", "3.14", "7"];
@@ -35,6 +45,31 @@ fn given_invalid_then_valid_input_when_parse_then_second_succeeds() {
     assert_eq!(fibonacci(last_result.unwrap()).unwrap(), 13);
 }
 
+#[test]
+fn given_const_context_when_fibonacci_const_then_matches_runtime_fibonacci() {
+    // Given
+    const FIB_90: Option<u128> = fibonacci_const(90);
+
+    // When
+    let runtime = fibonacci(90).unwrap();
+
+    // Then
+    assert_eq!(FIB_90, Some(runtime));
+    assert_eq!(fibonacci_const(187), None);
+}
+
+#[test]
+fn given_fibonacci_table_when_indexed_then_matches_fibonacci_for_every_entry() {
+    // Given
+    let table = FIBONACCI_TABLE;
+
+    // When / Then
+    assert_eq!(table.len(), FIBONACCI_TABLE_LEN);
+    for (n, &value) in table.iter().enumerate() {
+        assert_eq!(value, fibonacci(n as u128).unwrap());
+    }
+}
+
 #[test]
 fn given_zero_input_when_full_workflow_then_returns_zero() {
     // Given
@@ -51,6 +86,143 @@ fn given_zero_input_when_full_workflow_then_returns_zero() {
     assert!(logger.entries()[1].contains("Результат: 0"));
 }
 
+/// Запускает сервер на свободном порту localhost один раз на все тесты.
+fn server_addr() -> SocketAddr {
+    static ADDR: OnceLock<SocketAddr> = OnceLock::new();
+    *ADDR.get_or_init(|| {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || serve(listener, Options::default()));
+        addr
+    })
+}
+
+/// Отправляет HTTP-запрос серверу и возвращает статус и тело ответа.
+fn request(method: &str, path: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(server_addr()).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        method, path
+    )
+    .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+    (status, body.to_string())
+}
+
+#[test]
+fn given_server_when_get_fib_then_returns_json() {
+    // Given / When
+    let (status, body) = request("GET", "/fib/10");
+
+    // Then
+    assert_eq!(status, 200);
+    assert_eq!(body, r#"{"n":"10","value":"55"}"#);
+}
+
+#[test]
+fn given_server_when_get_all_routes_then_each_answers() {
+    // Given / When
+    let range = request("GET", "/fib?from=0&to=3");
+    let index = request("GET", "/index-of/144");
+    let modulo = request("GET", "/mod/10/7");
+
+    // Then
+    assert_eq!(
+        range,
+        (
+            200,
+            r#"{"from":"0","to":"3","values":["0","1","1","2"]}"#.to_string()
+        )
+    );
+    assert_eq!(index, (200, r#"{"value":"144","index":"12"}"#.to_string()));
+    assert_eq!(
+        modulo,
+        (200, r#"{"n":"10","m":"7","value":"6"}"#.to_string())
+    );
+}
+
+#[test]
+fn given_server_when_errors_then_returns_structured_codes() {
+    // Given / When
+    let overflow = request("GET", "/fib/187");
+    let invalid = request("GET", "/fib/abc");
+    let unknown = request("DELETE", "/fib/10");
+
+    // Then
+    assert_eq!(overflow.0, 422);
+    assert!(
+        overflow.1.contains(r#""code":"COMPUTATION_OVERFLOW""#),
+        "{}",
+        overflow.1
+    );
+    assert_eq!(invalid.0, 400);
+    assert!(
+        invalid.1.contains(r#""code":"INVALID_CHARACTERS""#),
+        "{}",
+        invalid.1
+    );
+    assert!(invalid.1.contains(r#"RU: Ошибка!"#) && invalid.1.contains(r#"\nEN: Error!"#));
+    assert_eq!(unknown.0, 405);
+}
+
+#[test]
+fn given_server_when_concurrent_requests_then_all_answered() {
+    // Given
+    let indices: Vec<u128> = (0..20).collect();
+
+    // When
+    let handles: Vec<_> = indices
+        .iter()
+        .map(|&n| thread::spawn(move || request("GET", &format!("/fib/{}", n))))
+        .collect();
+
+    // Then
+    for (n, handle) in indices.iter().zip(handles) {
+        let (status, body) = handle.join().unwrap();
+        assert_eq!(status, 200);
+        assert!(body.contains(&format!(r#""value":"{}""#, fibonacci(*n).unwrap())));
+    }
+}
+
+#[test]
+fn given_line_server_when_pipelined_requests_then_answers_in_order_on_one_connection() {
+    // Given
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || line_protocol::serve(listener, Options::default()));
+    let mut stream = TcpStream::connect(addr).unwrap();
+    let mut requests: String = (0..=186).map(|n| format!("FIB {}\n", n)).collect();
+    requests.push_str("FIB 187\nFIB x\n");
+
+    // When: все запросы отправляются до чтения первого ответа
+    stream.write_all(requests.as_bytes()).unwrap();
+    let mut replies = BufReader::new(stream.try_clone().unwrap()).lines();
+
+    // Then
+    for n in 0..=186 {
+        let expected = format!("OK {}", fibonacci(n).unwrap());
+        assert_eq!(replies.next().unwrap().unwrap(), expected);
+    }
+    assert_eq!(
+        replies.next().unwrap().unwrap(),
+        "ERR COMPUTATION_OVERFLOW 187"
+    );
+    assert_eq!(replies.next().unwrap().unwrap(), "ERR INVALID_CHARACTERS");
+
+    // When: соединение остаётся открытым для следующих запросов
+    stream.write_all(b"FIB 42\nQUIT\n").unwrap();
+
+    // Then
+    assert_eq!(replies.next().unwrap().unwrap(), "OK 267914296");
+    assert_eq!(replies.next().unwrap().unwrap(), "BYE");
+    assert!(replies.next().is_none());
+}
+
 use proptest::prelude::*;
 
 proptest! {
@@ -0,0 +1,37 @@
+//! Запуск тестов модуля Python (`tests/python/`) против собранной `cdylib`.
+//!
+//! Только с feature `python`: `cargo test --features python --test python`.
+#![cfg(feature = "python")]
+
+mod common;
+
+use std::env;
+use std::fs;
+use std::process::Command;
+
+use common::{build_library, manifest_dir, target_dir};
+
+#[test]
+fn given_python_module_when_unittest_runs_then_all_pass() {
+    // Given: cdylib под именем, которое ищет `import fibonacci_stable`
+    let library = build_library("cdylib", "python", "libfibonacci_stable.so");
+    let module_dir = target_dir().join("python");
+    fs::create_dir_all(&module_dir).unwrap();
+    fs::copy(library, module_dir.join("fibonacci_stable.so")).unwrap();
+    let tests_dir = manifest_dir().join("tests/python");
+
+    // When
+    let output = Command::new(env::var("PYO3_PYTHON").unwrap_or_else(|_| "python3".into()))
+        .args(["-m", "unittest", "discover", "-s"])
+        .arg(&tests_dir)
+        .env("PYTHONPATH", &module_dir)
+        .output()
+        .expect("не удалось запустить python3");
+
+    // Then
+    assert!(
+        output.status.success(),
+        "unittest:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
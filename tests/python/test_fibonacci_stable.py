@@ -0,0 +1,116 @@
+"""Тесты модуля Python; запускаются из tests/python.rs (cargo test --features python)."""
+
+import unittest
+
+import fibonacci_stable as fs
+
+
+class FibTest(unittest.TestCase):
+    def test_given_1000_when_fib_then_returns_exact_int(self):
+        value = fs.fib(1000)
+
+        self.assertIsInstance(value, int)
+        self.assertEqual(value % 10**20, 76137795166849228875)
+        self.assertEqual(len(str(value)), 209)
+
+    def test_given_huge_index_when_fib_then_not_limited_by_int_max_str_digits(self):
+        value = fs.fib(100_000)
+
+        self.assertEqual(value.bit_length(), 69424)
+
+    def test_given_index_past_digit_limit_when_fib_then_raises_budget_error(self):
+        for n in [10**30, 2**128 - 1]:
+            with self.subTest(n=n):
+                with self.assertRaises(fs.BudgetExceededError) as ctx:
+                    fs.fib(n)
+                self.assertIsInstance(ctx.exception, fs.FibonacciError)
+                self.assertIn(str(fs.MAX_BIG_DIGITS), str(ctx.exception))
+
+    def test_given_small_indices_when_fib_then_matches_python_loop(self):
+        a, b = 0, 1
+        for n in range(300):
+            self.assertEqual(fs.fib(n), a)
+            a, b = b, a + b
+
+
+class FibonacciTest(unittest.TestCase):
+    def test_given_186_when_fibonacci_then_returns_u128_max_index(self):
+        self.assertEqual(fs.fibonacci(186), 332825110087067562321196029789634457848)
+
+    def test_given_187_when_fibonacci_then_raises_overflow_with_bilingual_message(self):
+        with self.assertRaises(fs.ComputationOverflowError) as ctx:
+            fs.fibonacci(187)
+
+        message = str(ctx.exception)
+        self.assertIn("RU: Ошибка! Переполнение при вычислении fibonacci(187)", message)
+        self.assertIn("EN: Error! Overflow when computing fibonacci(187)", message)
+
+    def test_given_invalid_arguments_when_fibonacci_then_raises_matching_errors(self):
+        cases = [
+            (-1, fs.NegativeNumberError),
+            (2.5, fs.FloatNumberError),
+            (2**128, fs.NumberTooLargeError),
+        ]
+        for argument, error in cases:
+            with self.subTest(argument=argument):
+                with self.assertRaises(error):
+                    fs.fibonacci(argument)
+
+        with self.assertRaises(TypeError):
+            fs.fibonacci("10")
+
+
+class RangeAndIteratorTest(unittest.TestCase):
+    def test_given_range_when_fibonacci_range_then_inclusive_list(self):
+        self.assertEqual(fs.fibonacci_range(5, 8), [5, 8, 13, 21])
+        self.assertEqual(fs.fibonacci_range(8, 5), [])
+
+    def test_given_range_past_186_when_fibonacci_range_then_raises_overflow(self):
+        with self.assertRaises(fs.ComputationOverflowError):
+            fs.fibonacci_range(180, 190)
+
+    def test_given_start_when_fib_iter_then_yields_consecutive_values(self):
+        iterator = fs.FibIter(185)
+
+        values = [next(iterator) for _ in range(4)]
+
+        self.assertEqual(values, [fs.fib(n) for n in range(185, 189)])
+        self.assertIs(iter(iterator), iterator)
+
+    def test_given_u128_max_start_when_fib_iter_then_raises_overflow(self):
+        with self.assertRaises(OverflowError):
+            fs.FibIter(2**128 - 1)
+
+    def test_given_start_past_digit_limit_when_fib_iter_then_raises_budget_error(self):
+        with self.assertRaises(fs.BudgetExceededError):
+            fs.FibIter(10**30)
+
+    def test_given_no_start_when_fib_iter_then_starts_at_zero(self):
+        self.assertEqual([v for _, v in zip(range(6), fs.FibIter())], [0, 1, 1, 2, 3, 5])
+
+
+class ParseInputTest(unittest.TestCase):
+    def test_given_valid_input_when_parse_input_then_returns_int(self):
+        self.assertEqual(fs.parse_input("  42  "), 42)
+
+    def test_given_invalid_inputs_when_parse_input_then_raises_matching_errors(self):
+        cases = [
+            ("", fs.EmptyInputError),
+            ("-5", fs.NegativeNumberError),
+            ("3.14", fs.FloatNumberError),
+            ("abc", fs.InvalidCharactersError),
+            ("1 2", fs.MultipleValuesError),
+            ("9" * 40, fs.NumberTooLargeError),
+        ]
+        for text, error in cases:
+            with self.subTest(text=text):
+                with self.assertRaises(error) as ctx:
+                    fs.parse_input(text)
+                self.assertIsInstance(ctx.exception, fs.FibonacciError)
+                self.assertIsInstance(ctx.exception, ValueError)
+                self.assertIn("RU: Ошибка!", str(ctx.exception))
+                self.assertIn("EN: Error!", str(ctx.exception))
+
+
+if __name__ == "__main__":
+    unittest.main()
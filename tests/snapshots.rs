@@ -0,0 +1,241 @@
+//! Golden-файлы вывода CLI.
+//!
+//! Каждый тест запускает бинарник с аргументами и сценарием stdin и сравнивает
+//! код выхода, stdout и stderr целиком со снимком `tests/snapshots/<имя>.snap`.
+//! Таймстемпы логов и оценки времени заменяются на `<время>`, потому что
+//! зависят от часов и машины.
+//!
+//! Обновить снимки после намеренного изменения вывода:
+//!
+//! ```bash
+//! UPDATE_SNAPSHOTS=1 cargo test --test snapshots
+//! ```
+#![cfg(feature = "clock")]
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+/// Запускает бинарник и сверяет результат со снимком `name`.
+fn assert_snapshot(name: &str, args: &[&str], stdin: &str) {
+    let actual = render(args, stdin, &run(args, stdin));
+    let path = snapshot_path(name);
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some_and(|v| v == "1") {
+        fs::write(&path, &actual)
+            .unwrap_or_else(|e| panic!("не удалось записать {}: {}", path.display(), e));
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "Снимок {} не найден; создайте его через UPDATE_SNAPSHOTS=1 cargo test --test snapshots",
+            path.display()
+        )
+    });
+    if actual != expected {
+        panic!(
+            "Вывод расходится со снимком {}:\n{}\nЕсли изменение намеренное: UPDATE_SNAPSHOTS=1 cargo test --test snapshots",
+            path.display(),
+            diff(&expected, &actual)
+        );
+    }
+}
+
+struct Run {
+    code: Option<i32>,
+    stdout: String,
+    stderr: String,
+}
+
+/// Запускает бинарник в пустом каталоге без файла конфигурации и переменных
+/// `FIBONACCI_STABLE_*`, чтобы окружение разработчика не влияло на вывод.
+/// Пакетные сценарии задают `--threads`: по умолчанию число потоков зависит от машины.
+fn run(args: &[&str], stdin: &str) -> Run {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("snapshots");
+    fs::create_dir_all(&dir).unwrap();
+
+    let mut command = Command::new(env!("CARGO_BIN_EXE_fibonacci_stable"));
+    for (key, _) in std::env::vars_os() {
+        if key.to_string_lossy().starts_with("FIBONACCI_STABLE_") {
+            command.env_remove(key);
+        }
+    }
+    let mut child = command
+        .args(args)
+        .current_dir(&dir)
+        .env("XDG_CONFIG_HOME", &dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Не удалось запустить программу");
+
+    child
+        .stdin
+        .as_mut()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    Run {
+        code: output.status.code(),
+        stdout: normalize(&String::from_utf8(output.stdout).unwrap()),
+        stderr: normalize(&String::from_utf8(output.stderr).unwrap()),
+    }
+}
+
+/// Заменяет таймстемпы `[2024-01-01 12:00:00.000]` и оценки времени `~1.5ms`.
+fn normalize(text: &str) -> String {
+    static TIMESTAMP: OnceLock<Regex> = OnceLock::new();
+    static DURATION: OnceLock<Regex> = OnceLock::new();
+    let timestamp = TIMESTAMP
+        .get_or_init(|| Regex::new(r"\[\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3}\]").unwrap());
+    let duration = DURATION.get_or_init(|| Regex::new(r"~\d+(\.\d+)?(ns|µs|ms|s)\b").unwrap());
+    let text = timestamp.replace_all(text, "[<время>]");
+    duration.replace_all(&text, "~<время>").into_owned()
+}
+
+/// Текст снимка: сценарий и результат запуска по разделам.
+fn render(args: &[&str], stdin: &str, run: &Run) -> String {
+    let code = run
+        .code
+        .map_or_else(|| "сигнал".to_string(), |code| code.to_string());
+    format!(
+        "--- args\n{}\n--- stdin\n{}--- exit code\n{}\n--- stdout\n{}--- stderr\n{}",
+        args.join(" "),
+        stdin,
+        code,
+        run.stdout,
+        run.stderr
+    )
+}
+
+fn snapshot_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{}.snap", name))
+}
+
+/// Построчное сравнение: первая расходящаяся строка и строки вокруг неё.
+fn diff(expected: &str, actual: &str) -> String {
+    let (expected, actual): (Vec<&str>, Vec<&str>) =
+        (expected.lines().collect(), actual.lines().collect());
+    let first = (0..expected.len().max(actual.len()))
+        .find(|&i| expected.get(i) != actual.get(i))
+        .unwrap_or(0);
+    let window = first.saturating_sub(2)..(first + 3);
+    let show = |lines: &[&str]| -> String {
+        window
+            .clone()
+            .filter_map(|i| {
+                lines
+                    .get(i)
+                    .map(|line| format!("  {:>4} | {}\n", i + 1, line))
+            })
+            .collect()
+    };
+    format!(
+        "первое расхождение в строке {}\nожидалось:\n{}получено:\n{}",
+        first + 1,
+        show(&expected),
+        show(&actual)
+    )
+}
+
+#[test]
+fn given_valid_input_when_run_then_matches_snapshot() {
+    assert_snapshot("interactive_valid", &[], "10\n");
+}
+
+#[test]
+fn given_invalid_inputs_then_valid_when_run_then_matches_snapshot() {
+    assert_snapshot("interactive_errors", &[], "abc\n-5\n3.14\n1 2\n\n187\n42\n");
+}
+
+#[test]
+fn given_quit_when_run_then_matches_snapshot() {
+    assert_snapshot("interactive_quit", &[], "q\n");
+}
+
+#[test]
+fn given_batch_big_mode_when_run_then_matches_snapshot() {
+    assert_snapshot(
+        "batch_big",
+        &["--batch", "--big", "--threads", "2"],
+        "10\n187\nabc\n",
+    );
+}
+
+#[test]
+fn given_json_format_in_english_when_run_then_matches_snapshot() {
+    assert_snapshot(
+        "batch_json_en",
+        &[
+            "--batch",
+            "--threads",
+            "2",
+            "--format",
+            "json",
+            "--lang",
+            "en",
+        ],
+        "10\n-1\n187\n",
+    );
+}
+
+#[test]
+fn given_digit_budget_when_run_then_matches_snapshot() {
+    assert_snapshot(
+        "budget_refuse",
+        &["--big", "--max-digits", "100"],
+        "1000\n10\n",
+    );
+}
+
+#[test]
+fn given_log_to_stderr_when_run_then_matches_snapshot() {
+    assert_snapshot(
+        "log_stderr",
+        &["--batch", "--threads", "2", "--log", "stderr"],
+        "5\n8\n",
+    );
+}
+
+#[test]
+fn given_approx_and_last_digits_modes_when_run_then_match_snapshots() {
+    let huge = "1000000000000000000\n";
+    assert_snapshot("approx", &["--batch", "--threads", "2", "--approx"], huge);
+    assert_snapshot(
+        "last_digits",
+        &["--batch", "--threads", "2", "--last-digits", "20"],
+        huge,
+    );
+}
+
+#[test]
+fn given_invalid_argument_when_run_then_matches_snapshot() {
+    assert_snapshot("invalid_argument", &["--overflow", "bogus"], "");
+}
+
+#[test]
+fn given_verify_when_run_then_matches_snapshot() {
+    assert_snapshot("verify", &["verify", "--up-to", "30"], "");
+}
+
+#[test]
+fn given_timestamps_and_durations_when_normalize_then_replaced() {
+    // Given
+    let text = "[2026-10-18 21:43:23.898] Оценка: ~256 байт, ~3.694µs, ~1s\n";
+
+    // When
+    let result = normalize(text);
+
+    // Then
+    assert_eq!(result, "[<время>] Оценка: ~256 байт, ~<время>, ~<время>\n");
+}
@@ -0,0 +1,44 @@
+//! Тесты API для WebAssembly в рантайме wasm (Node.js через wasm-bindgen-test-runner):
+//!
+//! ```text
+//! rustup target add wasm32-unknown-unknown
+//! cargo install wasm-bindgen-cli
+//! cargo test --target wasm32-unknown-unknown --no-default-features --features wasm --test wasm
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use fibonacci_stable::Logger;
+use fibonacci_stable::wasm::{fibonacci, fibonacci_big, fibonacci_mod, parse};
+
+#[wasm_bindgen_test]
+fn given_decimal_strings_when_called_from_wasm_then_returns_decimal_strings() {
+    assert_eq!(
+        fibonacci("186").unwrap(),
+        "332825110087067562321196029789634457848"
+    );
+    assert_eq!(fibonacci_big("1000").unwrap().len(), 209);
+    assert_eq!(
+        fibonacci_mod("1000000000000", "1000000007").unwrap(),
+        "730695249"
+    );
+    assert_eq!(parse(" 42 ").unwrap(), "42");
+}
+
+#[wasm_bindgen_test]
+fn given_invalid_input_when_called_from_wasm_then_throws() {
+    assert!(fibonacci("187").is_err());
+    assert!(fibonacci_big("10000000").is_err());
+    assert!(fibonacci_mod("10", "0").is_err());
+    assert!(parse("-5").is_err());
+}
+
+#[wasm_bindgen_test]
+fn given_no_clock_when_log_then_entry_has_no_timestamp() {
+    let mut logger = Logger::new();
+
+    logger.log("hello");
+
+    assert_eq!(logger.entries(), ["hello"]);
+}